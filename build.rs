@@ -4,16 +4,130 @@ use std::fs::File;
 use std::io::{BufWriter, Write};
 use std::path::{Path, PathBuf};
 
+/// Version of the data files directly under `assets/` (1.6.15.24356).
+const DEFAULT_VERSION: &str = "1.6.15";
+
+/// The tables each data set fills in, in `GameData` field order, and whether
+/// the field borrows the static (the others already are references).
+const TABLES: &[(&str, bool)] = &[
+    ("OBJECTS", true),
+    ("OBJECTS_BY_NAME", false),
+    ("OBJECT_DATA_ORDER", false),
+    ("FISHING_POOLS", true),
+    ("PURE_SEASONAL_ITEMS", true),
+    ("RANDOM_BUNDLE_AREAS", false),
+    ("NPCS", false),
+    ("GEODES", false),
+    ("SPECIAL_ORDERS", false),
+    ("ARTIFACT_SPOTS", false),
+    ("GARBAGE_DEFAULT_BASE_CHANCE", true),
+    ("GARBAGE_BEFORE_ALL", false),
+    ("GARBAGE_AFTER_ALL", false),
+    ("GARBAGE_CANS", false),
+    ("COOKING_RECIPES", false),
+    ("DESERT_TRADE_REQUEST", true),
+];
+
 fn main() {
     let out_path: PathBuf = Path::new(&env::var("OUT_DIR").unwrap()).join("codegen.rs");
     let mut out_file: BufWriter<File> = BufWriter::new(File::create(&out_path).unwrap());
 
-    let objects_path = Path::new("assets/Objects.json");
-    let objects_file = File::open(objects_path).unwrap();
+    let sets = data_sets();
+    let mut versions = String::from("pub static GAME_DATA_SETS: &[GameData] = &[\n");
+    for (index, set) in sets.iter().enumerate() {
+        writeln!(out_file, "pub mod data_{index} {{\nuse super::*;").unwrap();
+        write_data_set(&mut out_file, set);
+        writeln!(out_file, "}}").unwrap();
+
+        let fields: Vec<String> = TABLES
+            .iter()
+            .map(|(table, borrow)| {
+                let borrow = if *borrow { "&" } else { "" };
+                format!("{}: {borrow}data_{index}::{table}", table.to_ascii_lowercase())
+            })
+            .collect();
+        versions.push_str(&format!(
+            "    GameData {{ version: {:?}, {} }},\n",
+            set.version,
+            fields.join(", ")
+        ));
+    }
+    versions.push_str("];");
+    writeln!(out_file, "{versions}").unwrap();
+}
+
+/// One patch's game data. A patch directory `assets/<version>/` only needs
+/// the files that differ from the default set; the rest are read from
+/// `assets/`.
+struct DataSet {
+    version: String,
+    dir: PathBuf,
+}
+
+impl DataSet {
+    fn is_default(&self) -> bool {
+        self.version == DEFAULT_VERSION
+    }
+
+    /// Where this set's copy of `name` is.
+    fn path(&self, name: &str) -> PathBuf {
+        let path = self.dir.join(name);
+        if path.exists() {
+            path
+        } else {
+            Path::new("assets").join(name)
+        }
+    }
+
+    /// The `content` of an optional data file, or `None` with a build warning
+    /// that `disabled`. Patch sets fall back to the default set silently.
+    fn read_optional(&self, name: &str, disabled: &str) -> Option<serde_json::Value> {
+        match File::open(self.path(name)) {
+            Ok(file) => {
+                let json: serde_json::Value = serde_json::from_reader(file).unwrap();
+                Some(json.get("content").unwrap().clone())
+            }
+            Err(_) => {
+                if self.is_default() {
+                    println!("cargo:warning=assets/{name} not found; {disabled}");
+                }
+                None
+            }
+        }
+    }
+}
+
+/// The default set, then every patch directory under `assets/` by name.
+fn data_sets() -> Vec<DataSet> {
+    let mut patches: Vec<DataSet> = std::fs::read_dir("assets")
+        .unwrap()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().is_dir())
+        .filter_map(|entry| {
+            let version = entry.file_name().into_string().ok()?;
+            Some(DataSet {
+                version,
+                dir: entry.path(),
+            })
+        })
+        .filter(|set| !set.is_default())
+        .collect();
+    patches.sort_by(|a, b| a.version.cmp(&b.version));
+
+    let default = DataSet {
+        version: DEFAULT_VERSION.to_string(),
+        dir: Path::new("assets").join(DEFAULT_VERSION),
+    };
+    std::iter::once(default).chain(patches).collect()
+}
+
+/// Writes every table of `set`.
+fn write_data_set(out_file: &mut BufWriter<File>, set: &DataSet) {
+    let objects_file = File::open(set.path("Objects.json")).unwrap();
     let objects_json: serde_json::Value = serde_json::from_reader(objects_file).unwrap();
 
     // Parse content: { "id_str": { "Name": "...", "Price": N, ... }, ... }
-    let mut objects_map: HashMap<u32, (String, u32)> = HashMap::new();
+    let mut objects_map: HashMap<u32, (String, u32, i64, String, bool)> = HashMap::new();
     for (key, value) in objects_json
         .get("content")
         .unwrap()
@@ -37,19 +151,32 @@ fn main() {
             .get("Price")
             .and_then(|v| v.as_u64())
             .unwrap_or(0) as u32;
+        let category = obj.get("Category").and_then(|v| v.as_i64()).unwrap_or(0);
+        let object_type = obj
+            .get("Type")
+            .and_then(|v| v.as_str())
+            .unwrap_or("")
+            .to_string();
+        let random_sale = !obj
+            .get("ExcludeFromRandomSale")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
 
-        objects_map.insert(id, (name, price));
+        objects_map.insert(id, (name, price, category, object_type, random_sale));
     }
 
     // Generate OBJECTS phf::Map<u32, ObjectData>
     let mut objects_builder: phf_codegen::Map<u32> = phf_codegen::Map::new();
-    for (id, (name, price)) in &objects_map {
+    for (id, (name, price, category, object_type, random_sale)) in &objects_map {
         objects_builder.entry(
             *id,
             format!(
-                "ObjectData {{ name: {:?}, price: {:?} }}",
+                "ObjectData {{ name: {:?}, price: {:?}, category: {:?}, object_type: {:?}, random_sale: {:?} }}",
                 name.as_str(),
-                price
+                price,
+                category,
+                object_type.as_str(),
+                random_sale
             )
             .as_str(),
         );
@@ -58,23 +185,478 @@ fn main() {
     // Generate OBJECTS_BY_NAME: [u32; N] sorted by name ascending
     let mut objects_sorted: Vec<(u32, String)> = objects_map
         .iter()
-        .map(|(id, (name, _))| (*id, name.clone()))
+        .map(|(id, (name, ..))| (*id, name.clone()))
         .collect();
     objects_sorted.sort_by(|a, b| a.1.cmp(&b.1).then(a.0.cmp(&b.0)));
     let sorted_ids: Vec<u32> = objects_sorted.iter().map(|(id, _)| *id).collect();
 
     writeln!(
-        &mut out_file,
+        out_file,
         "pub static OBJECTS: phf::Map<u32, ObjectData> = {};",
         objects_builder.build()
     )
     .unwrap();
 
     writeln!(
-        &mut out_file,
-        "pub static OBJECTS_BY_NAME: [u32; {}] = {:?};",
-        sorted_ids.len(),
+        out_file,
+        "pub static OBJECTS_BY_NAME: &[u32] = &{:?};",
         sorted_ids
     )
     .unwrap();
+
+    // Generate OBJECT_DATA_ORDER: every key in file order, `None` if not numeric
+    let data_order: Vec<Option<u32>> = objects_json
+        .get("content")
+        .unwrap()
+        .as_object()
+        .unwrap()
+        .keys()
+        .map(|key| key.parse::<u32>().ok())
+        .collect();
+    writeln!(
+        out_file,
+        "pub static OBJECT_DATA_ORDER: &[Option<u32>] = &{:?};",
+        data_order
+    )
+    .unwrap();
+
+    write_quest_pools(out_file, set, &objects_map);
+    write_random_bundles(out_file, set);
+    write_npcs(out_file, set);
+    write_geodes(out_file, &objects_json);
+    write_special_orders(out_file, set);
+    write_artifact_spots(out_file, &objects_json);
+    write_garbage_cans(out_file, set);
+    write_cooking_recipes(out_file, set);
+    write_desert_trade_request(out_file, set);
+}
+
+/// Generates `FISHING_POOLS` and `PURE_SEASONAL_ITEMS` from
+/// `assets/QuestPools.json`. The game has no data file for these pools; they
+/// are array literals in `FishingQuest` and `Utility.getRandomPureSeasonalItem`,
+/// so the JSON is a transcription that has to be updated by hand when a patch
+/// changes them:
+///
+/// - `Fishing`: for "Demetrius" and "Willy", the fish each season can ask for.
+/// - `PureSeasonal`: the forage and fish of each season (the Trash Bear's
+///   first requests).
+///
+/// Seasons are "Spring", "Summer", "Fall" and "Winter"; order within a pool
+/// is the order the game indexes it in. The build fails if an entry is not
+/// an unsigned integer ID, if an ID is not in `OBJECTS`, or if a fishing pool
+/// asks for something that is not a fish.
+fn write_quest_pools(
+    out_file: &mut BufWriter<File>,
+    set: &DataSet,
+    objects_map: &HashMap<u32, (String, u32, i64, String, bool)>,
+) {
+    const SEASONS: [&str; 4] = ["Spring", "Summer", "Fall", "Winter"];
+    const FISH_CATEGORY: i64 = -4;
+
+    let path = set.path("QuestPools.json");
+    let json: serde_json::Value = serde_json::from_reader(File::open(&path).unwrap()).unwrap();
+    let mut errors: Vec<String> = Vec::new();
+
+    let mut pool = |at: &str, value: Option<&serde_json::Value>, fish: bool| -> Vec<u32> {
+        let Some(ids) = value.and_then(|v| v.as_array()) else {
+            errors.push(format!("{at}: missing"));
+            return Vec::new();
+        };
+        let mut malformed = false;
+        let ids: Vec<u32> = ids
+            .iter()
+            .filter_map(|id| {
+                let parsed = id.as_u64().and_then(|id| u32::try_from(id).ok());
+                if parsed.is_none() {
+                    errors.push(format!("{at}: {id} is not an object ID"));
+                    malformed = true;
+                }
+                parsed
+            })
+            .collect();
+        for id in &ids {
+            match objects_map.get(id) {
+                None => errors.push(format!("{at}: {id} is not in Objects.json")),
+                Some((name, _, category, ..)) if fish && *category != FISH_CATEGORY => {
+                    errors.push(format!("{at}: {id} ({name}) is not a fish"))
+                }
+                Some(_) => {}
+            }
+        }
+        if ids.is_empty() && !malformed {
+            errors.push(format!("{at}: empty"));
+        }
+        ids
+    };
+
+    let mut out = String::from("pub static FISHING_POOLS: [[&[u32]; 2]; 4] = [\n");
+    for season in SEASONS {
+        let pools: Vec<String> = ["Demetrius", "Willy"]
+            .iter()
+            .map(|npc| {
+                let value = json.pointer(&format!("/Fishing/{npc}/{season}"));
+                format!("&{:?}", pool(&format!("Fishing.{npc}.{season}"), value, true))
+            })
+            .collect();
+        out.push_str(&format!("    [{}],\n", pools.join(", ")));
+    }
+    out.push_str("];\n");
+
+    out.push_str("pub static PURE_SEASONAL_ITEMS: [&[u32]; 4] = [\n");
+    for season in SEASONS {
+        let value = json.pointer(&format!("/PureSeasonal/{season}"));
+        let ids = pool(&format!("PureSeasonal.{season}"), value, false);
+        out.push_str(&format!("    &{ids:?},\n"));
+    }
+    out.push_str("];\n");
+
+    if !errors.is_empty() {
+        panic!("{} is inconsistent:\n  {}", path.display(), errors.join("\n  "));
+    }
+    write!(out_file, "{out}").unwrap();
+}
+
+/// Generates `RANDOM_BUNDLE_AREAS` from the game's `Data/RandomBundles`, used
+/// to replay remixed bundle generation. The file is optional: without it the
+/// table is empty and bundle observations report the missing data.
+fn write_random_bundles(out_file: &mut BufWriter<File>, set: &DataSet) {
+    let areas: Vec<serde_json::Value> = set
+        .read_optional("RandomBundles.json", "remixed bundle observations are disabled")
+        .map(|content| content.as_array().unwrap().clone())
+        .unwrap_or_default();
+
+    let mut out = String::from("pub static RANDOM_BUNDLE_AREAS: &[RandomBundleArea] = &[\n");
+    for area in &areas {
+        let name = area.get("AreaName").and_then(|v| v.as_str()).unwrap();
+        let slots = area
+            .get("Keys")
+            .and_then(|v| v.as_str())
+            .unwrap()
+            .split_whitespace()
+            .count();
+        let bundle_sets: Vec<String> = area
+            .get("BundleSets")
+            .and_then(|v| v.as_array())
+            .map(|sets| {
+                sets.iter()
+                    .map(|set| bundle_templates(set.get("Bundles").unwrap()))
+                    .collect()
+            })
+            .unwrap_or_default();
+        let bundles = area
+            .get("Bundles")
+            .map(bundle_templates)
+            .unwrap_or_else(|| "&[]".to_string());
+
+        out.push_str(&format!(
+            "    RandomBundleArea {{ name: {:?}, slots: {}, bundle_sets: &[{}], bundles: {} }},\n",
+            name,
+            slots,
+            bundle_sets.join(", "),
+            bundles
+        ));
+    }
+    out.push_str("];");
+
+    writeln!(out_file, "{out}").unwrap();
+}
+
+fn bundle_templates(bundles: &serde_json::Value) -> String {
+    let templates: Vec<String> = bundles
+        .as_array()
+        .map(|bundles| {
+            bundles
+                .iter()
+                .map(|b| {
+                    let text = |key: &str| b.get(key).and_then(|v| v.as_str()).unwrap_or("");
+                    let number = |key: &str| b.get(key).and_then(|v| v.as_i64()).unwrap_or(-1);
+                    format!(
+                        "BundleTemplate {{ name: {:?}, index: {}, items: {:?}, pick: {} }}",
+                        text("Name"),
+                        number("Index"),
+                        text("Items"),
+                        number("Pick")
+                    )
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    format!("&[{}]", templates.join(", "))
+}
+
+/// Generates `NPCS` from `Data/Characters`, in data order — the order the game
+/// iterates `Game1.characterData` in when it picks a random villager.
+fn write_npcs(out_file: &mut BufWriter<File>, set: &DataSet) {
+    let path = set.path("Characters.json");
+    let json: serde_json::Value = serde_json::from_reader(File::open(path).unwrap()).unwrap();
+
+    // Gift tastes are optional; without them every `gift_tastes` is `None`.
+    let gift_tastes = set
+        .read_optional("NPCGiftTastes.json", "NPC gift tastes are empty")
+        .unwrap_or_default();
+
+    let mut out = String::from("pub static NPCS: &[NpcData] = &[\n");
+    for (name, data) in json.get("content").unwrap().as_object().unwrap() {
+        let text = |key: &str| data.get(key).and_then(|v| v.as_str());
+        // Vanilla display names are `[LocalizedText Strings\NPCNames:<key>]`
+        // tokens whose English text is the internal name.
+        let display_name = text("DisplayName")
+            .filter(|d| !d.starts_with("[LocalizedText"))
+            .unwrap_or(name);
+        let birthday = match (
+            data.get("BirthSeason").and_then(|v| v.as_u64()),
+            data.get("BirthDay").and_then(|v| v.as_u64()).unwrap_or(0),
+        ) {
+            (Some(season), day) if day > 0 => format!("Some(({season}, {day}))"),
+            _ => "None".to_string(),
+        };
+        out.push_str(&format!(
+            "    NpcData {{ name: {:?}, display_name: {:?}, birthday: {}, home_region: {:?}, can_socialize: {:?}, can_receive_gifts: {:?}, item_delivery_quests: {:?}, winter_star_participant: {:?}, unlock_conditions: {:?}, gift_tastes: {:?} }},\n",
+            name,
+            display_name,
+            birthday,
+            text("HomeRegion").unwrap_or("Other"),
+            text("CanSocialize"),
+            data.get("CanReceiveGifts").and_then(|v| v.as_bool()).unwrap_or(true),
+            text("ItemDeliveryQuests"),
+            text("WinterStarParticipant"),
+            text("UnlockConditions"),
+            gift_tastes.get(name).and_then(|v| v.as_str()),
+        ));
+    }
+    out.push_str("];");
+
+    writeln!(out_file, "{out}").unwrap();
+}
+
+/// Generates `GEODES` from the `GeodeDrops` of every object that has them, in
+/// data order. Drops are stably sorted by `Precedence` like the game's `OrderBy`.
+fn write_geodes(out_file: &mut BufWriter<File>, objects_json: &serde_json::Value) {
+    let mut out = String::from("pub static GEODES: &[GeodeData] = &[\n");
+    for (id, data) in objects_json.get("content").unwrap().as_object().unwrap() {
+        let default_items = data
+            .get("GeodeDropsDefaultItems")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+        let mut drops: Vec<&serde_json::Value> = data
+            .get("GeodeDrops")
+            .and_then(|v| v.as_array())
+            .map(|drops| drops.iter().collect())
+            .unwrap_or_default();
+        if drops.is_empty() && !default_items {
+            continue;
+        }
+        drops.sort_by_key(|d| d.get("Precedence").and_then(|v| v.as_i64()).unwrap_or(0));
+
+        let drops: Vec<String> = drops
+            .iter()
+            .map(|d| {
+                let text = |key: &str| d.get(key).and_then(|v| v.as_str());
+                let random_item_ids: Vec<&str> = d
+                    .get("RandomItemId")
+                    .and_then(|v| v.as_array())
+                    .map(|ids| ids.iter().filter_map(|v| v.as_str()).collect())
+                    .unwrap_or_default();
+                format!(
+                    "GeodeDrop {{ item_id: {:?}, random_item_ids: &{:?}, chance: {:?}, condition: {:?} }}",
+                    text("ItemId"),
+                    random_item_ids,
+                    d.get("Chance").and_then(|v| v.as_f64()).unwrap_or(1.0),
+                    text("Condition"),
+                )
+            })
+            .collect();
+
+        out.push_str(&format!(
+            "    GeodeData {{ id: {:?}, name: {:?}, default_items: {}, drops: &[{}] }},\n",
+            id,
+            data.get("Name").and_then(|v| v.as_str()).unwrap_or(id),
+            default_items,
+            drops.join(", ")
+        ));
+    }
+    out.push_str("];");
+
+    writeln!(out_file, "{out}").unwrap();
+}
+
+/// Generates `SPECIAL_ORDERS` from the game's `Data/SpecialOrders`, in data
+/// order — the order the board picks from. Optional like `RandomBundles`.
+fn write_special_orders(out_file: &mut BufWriter<File>, set: &DataSet) {
+    let orders: serde_json::Map<String, serde_json::Value> = set
+        .read_optional("SpecialOrders.json", "special order observations are disabled")
+        .map(|content| content.as_object().unwrap().clone())
+        .unwrap_or_default();
+
+    // `QuestDuration` may be exported by name or by value.
+    const DURATIONS: [&str; 6] = ["Week", "Month", "TwoWeeks", "TwoDays", "ThreeDays", "OneDay"];
+
+    let mut out = String::from("pub static SPECIAL_ORDERS: &[SpecialOrderData] = &[\n");
+    for (key, data) in &orders {
+        let text = |key: &str| data.get(key).and_then(|v| v.as_str());
+        let duration = match data.get("Duration") {
+            Some(serde_json::Value::String(name)) => name.as_str(),
+            Some(serde_json::Value::Number(n)) => DURATIONS[n.as_u64().unwrap() as usize],
+            _ => "Week",
+        };
+        out.push_str(&format!(
+            "    SpecialOrderData {{ key: {:?}, requester: {:?}, order_type: {:?}, duration: {:?}, repeatable: {}, required_tags: {:?}, condition: {:?} }},\n",
+            key,
+            text("Requester").unwrap_or(""),
+            text("OrderType").unwrap_or(""),
+            duration,
+            data.get("Repeatable").and_then(|v| v.as_bool()).unwrap_or(false),
+            text("RequiredTags").unwrap_or(""),
+            text("Condition"),
+        ));
+    }
+    out.push_str("];");
+
+    writeln!(out_file, "{out}").unwrap();
+}
+
+/// Generates `ARTIFACT_SPOTS` from the `ArtifactSpotChances` of every object,
+/// in data order — the order artifact spots roll them in.
+fn write_artifact_spots(out_file: &mut BufWriter<File>, objects_json: &serde_json::Value) {
+    let mut out = String::from("pub static ARTIFACT_SPOTS: &[ArtifactSpotData] = &[\n");
+    for (id, data) in objects_json.get("content").unwrap().as_object().unwrap() {
+        let Some(chances) = data.get("ArtifactSpotChances").and_then(|v| v.as_object()) else {
+            continue;
+        };
+        let Ok(id) = id.parse::<u32>() else {
+            continue;
+        };
+        let chances: Vec<String> = chances
+            .iter()
+            .map(|(location, chance)| format!("({:?}, {:?})", location, chance.as_f64().unwrap()))
+            .collect();
+        out.push_str(&format!(
+            "    ArtifactSpotData {{ item_id: {}, chances: &[{}] }},\n",
+            id,
+            chances.join(", ")
+        ));
+    }
+    out.push_str("];");
+
+    writeln!(out_file, "{out}").unwrap();
+}
+
+/// Generates the `GARBAGE_*` tables from the game's `Data/GarbageCans`: the
+/// default base chance, the entries every can tries before and after its own,
+/// and each can's entries, in data order. Optional like `RandomBundles`.
+fn write_garbage_cans(out_file: &mut BufWriter<File>, set: &DataSet) {
+    let content = set
+        .read_optional("GarbageCans.json", "garbage can observations are disabled")
+        .unwrap_or_default();
+
+    let items = |list: Option<&serde_json::Value>| -> String {
+        let entries: Vec<String> = list
+            .and_then(|v| v.as_array())
+            .into_iter()
+            .flatten()
+            .map(|e| {
+                let text = |key: &str| e.get(key).and_then(|v| v.as_str());
+                let random_item_ids: Vec<&str> = e
+                    .get("RandomItemId")
+                    .and_then(|v| v.as_array())
+                    .map(|ids| ids.iter().filter_map(|v| v.as_str()).collect())
+                    .unwrap_or_default();
+                format!(
+                    "GarbageItem {{ id: {:?}, item_id: {:?}, random_item_ids: &{:?}, condition: {:?}, ignore_base_chance: {} }}",
+                    text("Id").unwrap_or(""),
+                    text("ItemId"),
+                    random_item_ids,
+                    text("Condition"),
+                    e.get("IgnoreBaseChance").and_then(|v| v.as_bool()).unwrap_or(false),
+                )
+            })
+            .collect();
+        format!("&[{}]", entries.join(", "))
+    };
+
+    let default_base_chance = content
+        .get("DefaultBaseChance")
+        .and_then(|v| v.as_f64())
+        .unwrap_or(0.2);
+    let mut out = format!(
+        "pub static GARBAGE_DEFAULT_BASE_CHANCE: f32 = {default_base_chance:?};\n\
+         pub static GARBAGE_BEFORE_ALL: &[GarbageItem] = {};\n\
+         pub static GARBAGE_AFTER_ALL: &[GarbageItem] = {};\n\
+         pub static GARBAGE_CANS: &[GarbageCanData] = &[\n",
+        items(content.get("BeforeAll")),
+        items(content.get("AfterAll")),
+    );
+    if let Some(cans) = content.get("GarbageCans").and_then(|v| v.as_object()) {
+        for (id, data) in cans {
+            out.push_str(&format!(
+                "    GarbageCanData {{ id: {:?}, base_chance: {:?}, items: {} }},\n",
+                id,
+                data.get("BaseChance").and_then(|v| v.as_f64()).unwrap_or(-1.0) as f32,
+                items(data.get("Items")),
+            ));
+        }
+    }
+    out.push_str("];");
+
+    writeln!(out_file, "{out}").unwrap();
+}
+
+/// Generates `COOKING_RECIPES` from the game's `Data/CookingRecipes`: each
+/// recipe's name and the unqualified ID of what it makes, in data order.
+/// Optional like `RandomBundles`.
+fn write_cooking_recipes(out_file: &mut BufWriter<File>, set: &DataSet) {
+    let recipes: serde_json::Map<String, serde_json::Value> = set
+        .read_optional("CookingRecipes.json", "later Trash Bear requests are disabled")
+        .map(|content| content.as_object().unwrap().clone())
+        .unwrap_or_default();
+
+    let mut out = String::from("pub static COOKING_RECIPES: &[(&str, &str)] = &[\n");
+    for (name, data) in &recipes {
+        // "ingredients/unused/yield/unlock/display name"; the yield may carry a count.
+        let output = data
+            .as_str()
+            .and_then(|fields| fields.split('/').nth(2))
+            .and_then(|output| output.split_whitespace().next())
+            .unwrap_or("");
+        out.push_str(&format!("    ({name:?}, {output:?}),\n"));
+    }
+    out.push_str("];");
+
+    writeln!(out_file, "{out}").unwrap();
+}
+
+/// Generates `DESERT_TRADE_REQUEST` from the `DesertTrade` shop in the
+/// optional `assets/Shops.json`: the `TradeItemId` and `TradeItemAmount` of
+/// its `RANDOM_ITEMS (O)` entry, the rotating daily trade. The build fails if
+/// the file is present but has no such entry.
+fn write_desert_trade_request(out_file: &mut BufWriter<File>, set: &DataSet) {
+    let request = set
+        .read_optional("Shops.json", "Desert Trader requests are not checked")
+        .map(|shops| {
+            let entry = shops
+                .pointer("/DesertTrade/Items")
+                .and_then(|items| items.as_array())
+                .and_then(|items| {
+                    items.iter().find(|item| {
+                        item.get("ItemId")
+                            .and_then(|id| id.as_str())
+                            .is_some_and(|id| id.starts_with("RANDOM_ITEMS (O)"))
+                    })
+                })
+                .unwrap_or_else(|| {
+                    panic!("{}: DesertTrade has no RANDOM_ITEMS (O) entry", set.path("Shops.json").display())
+                });
+            let item = entry.get("TradeItemId").and_then(|id| id.as_str()).unwrap_or_else(|| {
+                panic!("{}: the DesertTrade random entry has no TradeItemId", set.path("Shops.json").display())
+            });
+            let amount = entry.get("TradeItemAmount").and_then(|a| a.as_u64()).unwrap_or(1);
+            format!("Some(({item:?}, {amount}))")
+        })
+        .unwrap_or_else(|| "None".to_string());
+
+    writeln!(
+        out_file,
+        "pub static DESERT_TRADE_REQUEST: Option<(&str, u32)> = {request};"
+    )
+    .unwrap();
 }
@@ -1,34 +1,60 @@
 use std::cmp::min;
-use web_sys::{console, window};
+use web_sys::{console, window, HtmlInputElement};
 use yew::html::Scope;
 use yew::prelude::*;
 use yew_agent::worker::WorkerBridge;
 use yew_agent::Spawnable;
 
-use crate::agent::{Agent, AgentInput, AgentOutput, AgentStart, PROGRESS_INCREMENT, STARDEW_EPOCH_UNIX};
-use crate::observation::{Observation, Platform, QuestContent, Season};
-use crate::observation_row::{build_observation, ObservationRow, RowDisplayState};
+use crate::agent::{Agent, AgentInput, AgentOutput};
+use crate::checker::Candidate;
+use crate::codegen::{game_versions, GameData};
+use crate::cracker::{CrackError, Cracker, STARDEW_EPOCH_UNIX};
+use crate::dropdown::DropdownSelect;
+use crate::explain::{explain, explain_group, ObservationReport};
+use crate::forms::FormState;
+use crate::observation::{
+    describe_date, year_first_day, year_last_day, FloatingObservation, Observation,
+    ObservationGroup, Platform, Season, DEFAULT_MAX_GROUP_START,
+};
+use crate::observation_row::{
+    build_observation, group_label, ObservationRow, RowDisplayState, RowObservation,
+};
 use crate::platform_component::PlatformComponent;
+use crate::prng::SeedingMode;
 
 enum CrackStatus {
     NotRun,
     Running,
-    Done(Vec<u64>),
+    Done(Vec<Candidate>),
 }
 
 pub enum Message {
-    PlatformUpdate(Option<Platform>),
+    PlatformUpdate(Option<Vec<Platform>>),
+    GameVersion(String),
+    ToggleLegacyRandom,
     AddObservation,
     UpdateRowState(usize, RowDisplayState),
     RemoveObservation(usize),
+    GroupMinYear(usize, String),
+    GroupMaxYear(usize, String),
+    ExplainId(String),
     Crack,
     WorkerOutput(u8, AgentOutput),
 }
 
 pub struct App {
-    platform: Option<Platform>,
+    /// Platforms to crack against; `None` until the user picks one.
+    platforms: Option<Vec<Platform>>,
+    /// Data set the forms offer choices from and the crack checks against.
+    data: &'static GameData,
+    /// Follows the save's "Use legacy randomization" advanced option.
+    seeding: SeedingMode,
     /// One entry per row; the row component reports its full display state here.
     row_states: Vec<RowDisplayState>,
+    /// Optional year bounds for each unknown-date group, as typed.
+    group_year_values: Vec<GroupYears>,
+    /// ID typed (or clicked) in the explain panel.
+    explain_id_value: String,
     workers: Vec<WorkerBridge<Agent>>,
     running: u8,
     progress: u64,
@@ -58,8 +84,12 @@ impl Component for App {
             .collect();
 
         Self {
-            platform: None,
+            platforms: None,
+            data: GameData::default_set(),
+            seeding: SeedingMode::default(),
             row_states: vec![RowDisplayState::default()],
+            group_year_values: Vec::new(),
+            explain_id_value: String::new(),
             workers,
             running: 0u8,
             progress: 0u64,
@@ -71,7 +101,26 @@ impl Component for App {
     fn update(&mut self, _ctx: &Context<Self>, msg: Self::Message) -> bool {
         match msg {
             Message::PlatformUpdate(p) => {
-                self.platform = p;
+                self.platforms = p;
+                true
+            }
+
+            Message::GameVersion(version) => match GameData::for_version(&version) {
+                Ok(data) => {
+                    self.data = data;
+                    true
+                }
+                Err(e) => {
+                    console::log_2(&"Cannot switch game data:".into(), &e.to_string().into());
+                    false
+                }
+            },
+
+            Message::ToggleLegacyRandom => {
+                self.seeding = match self.seeding {
+                    SeedingMode::Hashed => SeedingMode::Legacy,
+                    SeedingMode::Legacy => SeedingMode::Hashed,
+                };
                 true
             }
 
@@ -94,37 +143,38 @@ impl Component for App {
                 true
             }
 
+            Message::GroupMinYear(group, v) => {
+                self.group_years_mut(group).min = v;
+                true
+            }
+
+            Message::GroupMaxYear(group, v) => {
+                self.group_years_mut(group).max = v;
+                true
+            }
+
+            Message::ExplainId(v) => {
+                self.explain_id_value = v;
+                true
+            }
+
             Message::Crack => {
                 if self.running != 0 || !self.crack_enabled() {
                     return false;
                 }
 
-                // Compute t_max from current time
-                let now_ms = js_sys::Date::now();
-                let now_secs = (now_ms / 1000.0) as u64;
-                self.t_max = now_secs.saturating_sub(STARDEW_EPOCH_UNIX);
-
-                // Collect and sort observations by pass_rate ascending
-                let mut sorted_obs: Vec<Observation> = self
-                    .row_states
-                    .iter()
-                    .filter_map(|s| build_observation(s))
-                    .collect();
-                sorted_obs.sort_by(|a, b| {
-                    a.pass_rate()
-                        .partial_cmp(&b.pass_rate())
-                        .unwrap_or(std::cmp::Ordering::Equal)
-                });
-
-                let add = self.workers.len() as u64;
-                for (i, worker) in self.workers.iter_mut().enumerate() {
-                    worker.send(AgentInput::Start(AgentStart {
-                        start: i as u64,
-                        add,
-                        t_max: self.t_max,
-                        platform: self.platform.unwrap(),
-                        observations: sorted_obs.clone(),
-                    }));
+                let cracker = self.cracker();
+                let slices = match cracker.workers() {
+                    Ok(slices) => slices,
+                    Err(e) => {
+                        console::log_2(&"Cannot crack:".into(), &e.to_string().into());
+                        return false;
+                    }
+                };
+                self.t_max = cracker.range_len();
+
+                for (worker, slice) in self.workers.iter_mut().zip(slices) {
+                    worker.send(AgentInput::Start(slice));
                     self.running += 1;
                 }
 
@@ -140,16 +190,16 @@ impl Component for App {
                     true
                 }
 
-                AgentOutput::Candidates(ids) => {
+                AgentOutput::Candidates(candidates) => {
                     // Accumulate candidates. Don't touch running — that's Progress/Done's job.
                     // Workers are strided so no two workers cover the same ID.
-                    if !ids.is_empty() {
+                    if !candidates.is_empty() {
                         match &mut self.crack_status {
                             CrackStatus::Running => {
-                                self.crack_status = CrackStatus::Done(ids);
+                                self.crack_status = CrackStatus::Done(candidates);
                             }
                             CrackStatus::Done(found) => {
-                                found.extend_from_slice(&ids);
+                                found.extend(candidates);
                             }
                             CrackStatus::NotRun => {}
                         }
@@ -158,11 +208,8 @@ impl Component for App {
                     false
                 }
 
-                AgentOutput::Progress => {
-                    self.progress = min(
-                        self.progress + PROGRESS_INCREMENT,
-                        self.t_max,
-                    );
+                AgentOutput::Progress(tested) => {
+                    self.progress = min(self.progress + tested, self.t_max);
                     // Only send Continue if we haven't already found candidates.
                     // Once Done, we let workers finish their current batch (no Continue).
                     match self.crack_status {
@@ -199,7 +246,13 @@ impl Component for App {
         let all_complete = !self.row_states.is_empty()
             && self.row_states.iter().all(|s| build_observation(s).is_some());
 
-        let confidence_text = if has_complete_obs {
+        let unusable = match self.cracker().validate() {
+            Err(e @ CrackError::Unusable(_)) => Some(e.to_string()),
+            _ => None,
+        };
+        let confidence_text = if let Some(e) = &unusable {
+            format!("Cannot crack: {e}")
+        } else if has_complete_obs {
             if estimated < 2.0 {
                 "~1 candidate estimated — ready to crack!".to_string()
             } else {
@@ -238,13 +291,14 @@ impl Component for App {
 
         let result_html: Html = match &self.crack_status {
             CrackStatus::NotRun | CrackStatus::Running => html! {},
-            CrackStatus::Done(ids) if ids.is_empty() => html! {
+            CrackStatus::Done(candidates) if candidates.is_empty() => html! {
                 <p class="has-text-centered has-text-danger">
                     { "No seed found — add more observations." }
                 </p>
             },
-            CrackStatus::Done(ids) => {
-                let header = match ids.len() {
+            CrackStatus::Done(candidates) => {
+                let undated_groups = self.undated_groups();
+                let header = match candidates.len() {
                     1 => "1 candidate found".to_string(),
                     n => format!("{n} candidates found — add more observations to narrow down."),
                 };
@@ -252,9 +306,27 @@ impl Component for App {
                     <div class="box">
                         <p class="has-text-centered mb-3">{ header }</p>
                         <ul style="list-style:none; padding:0; margin:0">
-                            { for ids.iter().map(|id| html! {
+                            { for candidates.iter().map(|c| html! {
                                 <li class="has-text-centered">
-                                    <code style="font-size:1.1rem">{ id.to_string() }</code>
+                                    <a
+                                        title="Explain this ID"
+                                        onclick={ctx.link().callback({
+                                            let id = c.id.to_string();
+                                            move |_| Message::ExplainId(id.clone())
+                                        })}
+                                    >
+                                        <code style="font-size:1.1rem">{ c.id.to_string() }</code>
+                                    </a>
+                                    <span class="tag is-light ml-2">{ c.platform.name() }</span>
+                                    { for undated_groups.iter().zip(&c.group_starts).map(|(&group, starts)| html! {
+                                        <p class="is-size-7">
+                                            { format!(
+                                                "Group {} starts on {}",
+                                                group_label(group),
+                                                describe_starts(starts),
+                                            ) }
+                                        </p>
+                                    }) }
                                 </li>
                             }) }
                         </ul>
@@ -263,19 +335,46 @@ impl Component for App {
             }
         };
 
+        let group_bounds: Html = html! {
+            { for self.undated_groups().into_iter().map(|group| self.group_bounds_view(ctx, group)) }
+        };
+
+        // Rows can join any group in use or start the next one.
+        let group_count = self
+            .undated_groups()
+            .last()
+            .map_or(1, |&last| last + 2);
+
         html! {
             <section class="section">
                 <h1 class="title has-text-centered">{ "Stardew Seed Cracker" }</h1>
                 <h2 class="subtitle has-text-centered">{ "1.6 — Notice Board Method" }</h2>
                 <div class="container">
 
-                    // Platform selector
+                    // Platform and game version selectors
                     <div class="columns">
                         <div class="column">
                             <PlatformComponent
                                 callback={ctx.link().callback(Message::PlatformUpdate)}
                             />
                         </div>
+                        <div class="column is-narrow">
+                            <DropdownSelect
+                                options={game_versions().map(|v| (v.to_string(), format!("Game version {v}"))).collect::<Vec<_>>()}
+                                selected={Some(self.data.version.to_string())}
+                                placeholder="Game version"
+                                on_select={ctx.link().callback(Message::GameVersion)}
+                            />
+                        </div>
+                        <div class="column is-narrow">
+                            <button
+                                class={ if self.seeding == SeedingMode::Legacy { "button is-info is-light" } else { "button is-light" } }
+                                title="Click if the save was created with \"Use legacy randomization\" in the advanced options"
+                                onclick={ctx.link().callback(|_: MouseEvent| Message::ToggleLegacyRandom)}
+                            >
+                                { if self.seeding == SeedingMode::Legacy { "Legacy randomization" } else { "Default randomization" } }
+                            </button>
+                        </div>
                     </div>
 
                     // Observation rows
@@ -289,13 +388,18 @@ impl Component for App {
                         html! {
                             <ObservationRow
                                 key={i}
+                                data={self.data}
                                 display_state={row_state.clone()}
+                                group_count={group_count}
                                 on_change={on_change}
                                 on_delete={on_delete}
                             />
                         }
                     }) }
 
+                    // Search bounds for unknown-date rows
+                    { group_bounds }
+
                     // Add observation button — disabled until all existing rows are complete
                     <button
                         class="button is-light is-fullwidth mb-3"
@@ -307,7 +411,9 @@ impl Component for App {
 
                     // Confidence estimate
                     if has_complete_obs {
-                        <p class="has-text-centered mb-3">{ confidence_text }</p>
+                        <p class={ if unusable.is_some() { "has-text-centered has-text-danger mb-3" } else { "has-text-centered mb-3" } }>
+                            { confidence_text }
+                        </p>
                     }
 
                     // Crack button (shown when ready)
@@ -319,6 +425,9 @@ impl Component for App {
                     // Result
                     { result_html }
 
+                    // Per-observation verdicts for one ID
+                    { self.explain_view(ctx) }
+
                 </div>
             </section>
         }
@@ -327,51 +436,328 @@ impl Component for App {
 
 impl App {
     fn crack_enabled(&self) -> bool {
-        self.platform.is_some()
+        self.platforms.is_some()
             && !self.row_states.is_empty()
             && self.row_states.iter().all(|s| build_observation(s).is_some())
+            && self
+                .undated_groups()
+                .into_iter()
+                .all(|group| self.group_bounds(group).is_ok())
             && self.estimated_candidates() < 2.0
             && self.running == 0
+            && self.cracker().validate().is_ok()
+    }
+
+    /// Lets the user check any ID (e.g. a seed they know is right) against the
+    /// current observations, showing what the game generated on each day.
+    fn explain_view(&self, ctx: &Context<Self>) -> Html {
+        let Some(platforms) = &self.platforms else {
+            return html! {};
+        };
+
+        let input = html! {
+            <div class="field has-addons has-addons-centered mb-3">
+                <div class="control">
+                    <span class="button is-static">{ "Explain ID" }</span>
+                </div>
+                <div class="control">
+                    <input
+                        class="input"
+                        type="text"
+                        inputmode="numeric"
+                        pattern="[0-9]*"
+                        placeholder="Game ID"
+                        value={self.explain_id_value.clone()}
+                        oninput={ctx.link().callback(|e: InputEvent| {
+                            Message::ExplainId(e.target_unchecked_into::<HtmlInputElement>().value())
+                        })}
+                    />
+                </div>
+            </div>
+        };
+
+        let Ok(id) = self.explain_id_value.parse::<u64>() else {
+            return input;
+        };
+
+        let (observations, groups) = self.observation_set();
+        let seeding = self.seeding;
+
+        html! {
+            <>
+                { input }
+                { for platforms.iter().map(|&platform| {
+                    let mut sections: Vec<(String, Vec<ObservationReport>)> = vec![(
+                        "Dated rows".to_string(),
+                        explain(self.data, platform, seeding, id, &observations),
+                    )];
+                    for (label, group) in self.undated_groups().into_iter().map(group_label).zip(&groups) {
+                        let (start, reports) = explain_group(self.data, platform, seeding, id, group);
+                        sections.push((
+                            format!("Group {label}, best start {}", describe_date(start)),
+                            reports,
+                        ));
+                    }
+                    html! {
+                        <div class="box">
+                            <p class="has-text-weight-semibold mb-2">
+                                { format!("{} — ID {}", platform.name(), id) }
+                            </p>
+                            { for sections.into_iter().filter(|(_, r)| !r.is_empty()).map(|(title, reports)| html! {
+                                <>
+                                    <p class="is-size-7 has-text-grey mb-1">{ title }</p>
+                                    <ul class="mb-2" style="list-style:none; padding:0; margin:0">
+                                        { for reports.iter().map(report_view) }
+                                    </ul>
+                                </>
+                            }) }
+                        </div>
+                    }
+                }) }
+            </>
+        }
     }
 
     fn estimated_candidates(&self) -> f64 {
-        // Approximate t_max based on current time
+        self.cracker().estimated_candidates()
+    }
+
+    /// The crack described by the current UI state. The ID range ends at the
+    /// current time, since IDs are seconds since the Stardew epoch.
+    fn cracker(&self) -> Cracker {
         let now_ms = js_sys::Date::now();
         let now_secs = (now_ms / 1000.0) as u64;
-        let space = now_secs.saturating_sub(STARDEW_EPOCH_UNIX) as f64;
+        let t_max = now_secs.saturating_sub(STARDEW_EPOCH_UNIX);
+
+        let (observations, groups) = self.observation_set();
+        Cracker::new()
+            .platforms(self.platforms.clone().unwrap_or_default())
+            .seeding(self.seeding)
+            .game_data(self.data)
+            .range(0..=t_max)
+            .observations(observations)
+            .groups(groups)
+            .parallelism(self.workers.len() as u64)
+    }
+
+    /// Complete rows split into dated observations and one group per
+    /// unknown-date group in use, in `undated_groups` order.
+    fn observation_set(&self) -> (Vec<Observation>, Vec<ObservationGroup>) {
+        let undated_groups = self.undated_groups();
+        let mut dated: Vec<Observation> = Vec::new();
+        let mut undated: Vec<Vec<FloatingObservation>> = vec![Vec::new(); undated_groups.len()];
+        for row in self.row_states.iter().filter_map(build_observation) {
+            match row {
+                RowObservation::Dated(obs) => dated.push(obs),
+                RowObservation::Undated(group, obs) => {
+                    if let Ok(slot) = undated_groups.binary_search(&group) {
+                        undated[slot].push(obs);
+                    }
+                }
+            }
+        }
+
+        let groups = undated_groups
+            .iter()
+            .zip(undated)
+            .filter(|(_, observations)| !observations.is_empty())
+            .map(|(&group, mut observations)| {
+                // Re-base offsets so the reported start is the earliest row's date.
+                let first = observations.iter().map(|o| o.offset).min().unwrap_or(0);
+                for o in &mut observations {
+                    o.offset -= first;
+                }
 
-        let obs: Vec<Observation> = self
+                // Invalid bounds disable the crack; search nothing until fixed.
+                let (min_start, max_start) = self.group_bounds(group).unwrap_or((1, Some(0)));
+                ObservationGroup {
+                    observations,
+                    min_start,
+                    max_start,
+                }
+            })
+            .collect();
+
+        (dated, groups)
+    }
+
+    /// Indices of the unknown-date groups with at least one row, ascending.
+    fn undated_groups(&self) -> Vec<usize> {
+        let mut groups: Vec<usize> = self
             .row_states
             .iter()
-            .filter_map(|s| build_observation(s))
+            .filter(|s| s.date_unknown && s.form.kind().dated())
+            .map(|s| s.group)
             .collect();
+        groups.sort_unstable();
+        groups.dedup();
+        groups
+    }
 
-        let estimate = obs.iter().fold(space, |acc, ob| acc * ob.pass_rate());
+    fn group_years_mut(&mut self, group: usize) -> &mut GroupYears {
+        if self.group_year_values.len() <= group {
+            self.group_year_values.resize_with(group + 1, GroupYears::default);
+        }
+        &mut self.group_year_values[group]
+    }
 
-        // Due to id/2 truncation: consecutive seeds (2N, 2N+1) always produce identical
-        // quest-type outcomes. Without a fishing or resource observation (which use the
-        // full id for content matching), pairs are indistinguishable, so always ≥ 2.
-        let has_distinguishing = obs.iter().any(|ob| {
-            matches!(
-                ob.quest_content,
-                QuestContent::Fishing(_) | QuestContent::ResourceCollection(_)
-            )
-        });
+    /// The start days `group` may take, from its typed year bounds. Blank
+    /// bounds fall back to the defaults.
+    fn group_bounds(&self, group: usize) -> Result<(u32, Option<u32>), &'static str> {
+        let years = self.group_year_values.get(group).cloned().unwrap_or_default();
+        let min_start = match years.min.trim() {
+            "" => 1,
+            v => v
+                .parse::<u32>()
+                .ok()
+                .and_then(year_first_day)
+                .ok_or("Not a valid first year")?,
+        };
+        let max_start = match years.max.trim() {
+            "" => None,
+            v => Some(
+                v.parse::<u32>()
+                    .ok()
+                    .and_then(year_last_day)
+                    .ok_or("Not a valid last year")?,
+            ),
+        };
+        if min_start > max_start.unwrap_or(DEFAULT_MAX_GROUP_START) {
+            return Err("The first year is after the last");
+        }
+        Ok((min_start, max_start))
+    }
 
-        if has_distinguishing { estimate } else { estimate.max(2.0) }
+    fn group_bounds_view(&self, ctx: &Context<Self>, group: usize) -> Html {
+        let years = self.group_year_values.get(group).cloned().unwrap_or_default();
+        let error: Html = match self.group_bounds(group) {
+            Ok(_) => html! {},
+            Err(e) => html! {
+                <span class="tag is-danger is-light ml-2" style="align-self:center">{ e }</span>
+            },
+        };
+        html! {
+            <div class="field is-grouped is-grouped-centered mb-3">
+                <span class="tag is-light mr-2" style="align-self:center">
+                    { format!("Group {} falls between years", group_label(group)) }
+                </span>
+                <input
+                    class="input mr-2"
+                    type="text"
+                    inputmode="numeric"
+                    pattern="[0-9]*"
+                    placeholder="1"
+                    style="width:4.5rem"
+                    value={years.min}
+                    oninput={ctx.link().callback(move |e: InputEvent| {
+                        Message::GroupMinYear(group, e.target_unchecked_into::<HtmlInputElement>().value())
+                    })}
+                />
+                <input
+                    class="input"
+                    type="text"
+                    inputmode="numeric"
+                    pattern="[0-9]*"
+                    placeholder={(DEFAULT_MAX_GROUP_START / 112).to_string()}
+                    style="width:4.5rem"
+                    value={years.max}
+                    oninput={ctx.link().callback(move |e: InputEvent| {
+                        Message::GroupMaxYear(group, e.target_unchecked_into::<HtmlInputElement>().value())
+                    })}
+                />
+                { error }
+            </div>
+        }
     }
 }
 
+/// Year bounds typed for one unknown-date group.
+#[derive(Clone, Default)]
+struct GroupYears {
+    min: String,
+    max: String,
+}
+
+fn report_view(report: &ObservationReport) -> Html {
+    let (tag_class, tag_text) = if report.passed() {
+        ("tag is-success mr-2", "pass")
+    } else {
+        ("tag is-danger mr-2", "fail")
+    };
+
+    let details: Html = match &report.result {
+        Ok(explanation) => html! {
+            <>
+                <p class="is-size-7">
+                    { format!("Generated: {}", explanation.generated) }
+                </p>
+                { for explanation.alternatives.iter().map(|alt| html! {
+                    <p class="is-size-7">
+                        { format!(
+                            "Observed: {} — {}",
+                            alt.observed,
+                            match alt.mismatch {
+                                None => "matches".to_string(),
+                                Some(field) => format!("{field} mismatch"),
+                            },
+                        ) }
+                    </p>
+                }) }
+            </>
+        },
+        Err(e) => html! { <p class="is-size-7 has-text-danger">{ e.to_string() }</p> },
+    };
+
+    html! {
+        <li class="mb-1">
+            <span class={tag_class}>{ tag_text }</span>
+            <span>{ describe_date(report.days_played) }</span>
+            { details }
+        </li>
+    }
+}
+
+/// "Spring 5, Year 1", or a short list when several start days fit.
+fn describe_starts(starts: &[u32]) -> String {
+    starts
+        .iter()
+        .map(|&start| describe_date(start))
+        .collect::<Vec<_>>()
+        .join(" or ")
+}
+
 /// Build the initial `RowDisplayState` for a newly added row.
 /// Pre-fills the date to the day after the last row that has a valid date.
 fn next_row_state(existing: &[RowDisplayState]) -> RowDisplayState {
+    // New rows observe the same kind of thing as the row above.
+    let form = existing
+        .last()
+        .map(|prev| FormState::new(prev.form.kind()))
+        .unwrap_or_default();
+
+    // Continue an unknown-date run on the following day.
+    if let Some(prev) = existing.last().filter(|s| s.date_unknown) {
+        let offset = prev.offset.map_or(0, |o| o.saturating_add(1));
+        return RowDisplayState {
+            date_unknown: true,
+            group: prev.group,
+            offset_value: offset.to_string(),
+            offset: Some(offset),
+            form,
+            ..RowDisplayState::default()
+        };
+    }
+
     let last_with_date = existing
         .iter()
         .rev()
-        .find(|s| s.day.is_some() && s.season.is_some() && s.year.is_some());
+        .find(|s| !s.date_unknown && s.day.is_some() && s.season.is_some() && s.year.is_some());
 
     let Some(prev) = last_with_date else {
-        return RowDisplayState::default();
+        return RowDisplayState {
+            form,
+            ..RowDisplayState::default()
+        };
     };
 
     let (day, season, year) = advance_one_day(
@@ -386,6 +772,7 @@ fn next_row_state(existing: &[RowDisplayState]) -> RowDisplayState {
         season: Some(season),
         year_value: year.to_string(),
         year: Some(year),
+        form,
         ..RowDisplayState::default()
     }
 }
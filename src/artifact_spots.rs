@@ -0,0 +1,93 @@
+//! Artifact spots (`GameLocation.digUpArtifactSpot`).
+//!
+//! Digging an artifact spot seeds `CreateDaySaveRandom(x * 2000, y)` from the
+//! tile. If the player has seen Mr. Qi's plane a Mystery Box roll comes first.
+//! The artifact itself is `RANDOM_ARTIFACT_FOR_DIG_SPOT`: every object with
+//! `ArtifactSpotChances` for the location is rolled in data order and the
+//! first hit is dug up. If none hits, the spot gives one of the location's
+//! other drops (clay, seeds, ...), which are not modelled and are reported as
+//! "no artifact". The artifact roll is assumed to be the first drop tried.
+
+use crate::checker::CheckError;
+use crate::codegen::GameData;
+use crate::observation::Platform;
+use crate::prng::{create_day_save_random, SeedingMode};
+
+/// A dug tile.
+#[derive(Clone, Copy, PartialEq)]
+pub struct DigSpot<'a> {
+    /// Location name, e.g. "Forest" or "UndergroundMine".
+    pub location: &'a str,
+    pub x: u32,
+    pub y: u32,
+}
+
+/// Location names with artifact chances, in the order they first appear.
+pub fn artifact_locations(data: &'static GameData) -> Vec<&'static str> {
+    let mut locations: Vec<&'static str> = Vec::new();
+    for (location, _) in data.artifact_spots.iter().flat_map(|spot| spot.chances) {
+        if !locations.contains(location) {
+            locations.push(location);
+        }
+    }
+    locations
+}
+
+/// Each artifact that can be dug at `location` with its chance, in roll order.
+pub fn location_artifacts(data: &GameData, location: &str) -> Vec<(u32, f64)> {
+    data.artifact_spots
+        .iter()
+        .filter_map(|spot| {
+            spot.chances
+                .iter()
+                .find(|(l, _)| *l == location)
+                .map(|&(_, chance)| (spot.item_id, chance))
+        })
+        .collect()
+}
+
+/// The artifact dug at `spot` on `days_played`, if any. `saw_qi_plane` is
+/// whether the Mystery Box roll is made first.
+pub fn dig_artifact_spot(
+    data: &GameData,
+    platform: Platform,
+    seeding: SeedingMode,
+    id: u64,
+    days_played: u32,
+    spot: DigSpot,
+    saw_qi_plane: bool,
+) -> Result<Option<u32>, CheckError> {
+    let mut rng = create_day_save_random(
+        platform,
+        seeding,
+        days_played,
+        id,
+        spot.x as f64 * 2000.0,
+        spot.y as f64,
+        0.0,
+    )?;
+    if saw_qi_plane {
+        rng.gen_float()?;
+    }
+    for (item_id, chance) in location_artifacts(data, spot.location) {
+        if rng.gen_float()? < chance {
+            return Ok(Some(item_id));
+        }
+    }
+    Ok(None)
+}
+
+/// Chance that a random ID digs `item` (or no artifact) at `location`.
+pub fn artifact_pass_rate(data: &GameData, location: &str, item: Option<u32>) -> f64 {
+    let mut missed = 1.0;
+    for (item_id, chance) in location_artifacts(data, location) {
+        if Some(item_id) == item {
+            return missed * chance;
+        }
+        missed *= 1.0 - chance;
+    }
+    match item {
+        None => missed,
+        Some(_) => 0.0,
+    }
+}
@@ -0,0 +1,315 @@
+//! Cracks a game ID natively, using every CPU core.
+//!
+//! Run with:
+//!   cargo run --release --bin crack -- search <FILE> [OPTIONS]
+//!   cargo run --release --bin crack -- explain <FILE> --id <N> [OPTIONS]
+//!
+//! `<FILE>` is JSON of the form `{ "observations": [...], "groups": [...] }`,
+//! using the serde representation of `Observation` and `ObservationGroup`.
+//! Either key may be omitted.
+//!
+//! Options:
+//!   --platform <P>    pc | switch | unknown (default unknown)
+//!   --legacy-random   the save uses legacy randomization
+//!   --min-id <N>      first game ID to test (default 0)
+//!   --max-id <N>      last game ID to test (default: now, in seconds since the Stardew epoch)
+//!   --threads <N>     worker threads (default: available parallelism)
+//!   --game-version <V> game patch whose data to use, e.g. 1.6.15 (default: the
+//!                     data directly under assets/)
+//!
+//! `explain` prints, for one ID, what the game generated on each observed day and
+//! which field of the observation disagreed. It accepts `--platform`,
+//! `--legacy-random` and `--game-version` as above.
+
+fn main() {
+    #[cfg(not(target_arch = "wasm32"))]
+    native::run();
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+mod native {
+    use std::path::PathBuf;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    use clap::{Parser, Subcommand};
+    use serde::Deserialize;
+
+    use stardew_seed_cracker::codegen::GameData;
+    use stardew_seed_cracker::cracker::{CrackEvent, Cracker, STARDEW_EPOCH_UNIX};
+    use stardew_seed_cracker::explain::{self, ObservationReport};
+    use stardew_seed_cracker::observation::{
+        describe_date, Observation, ObservationGroup, Platform,
+    };
+    use stardew_seed_cracker::prng::SeedingMode;
+
+    // ── CLI ───────────────────────────────────────────────────────────────────
+
+    #[derive(Parser)]
+    #[command(about = "Crack a Stardew Valley 1.6 game ID from observations")]
+    struct Args {
+        #[command(subcommand)]
+        command: Command,
+
+        /// Game patch whose data to use, e.g. 1.6.15 (default: the data directly under assets/)
+        #[arg(long, global = true, value_parser = parse_game_version)]
+        game_version: Option<&'static GameData>,
+    }
+
+    #[derive(Subcommand)]
+    enum Command {
+        /// Search the ID range for candidates consistent with the observations
+        Search {
+            /// JSON file with `observations` and/or `groups`
+            file: PathBuf,
+
+            /// Platform: pc, switch, or unknown (tries both)
+            #[arg(long, default_value = "unknown", value_parser = parse_platforms)]
+            platform: Platforms,
+
+            /// The save uses the "legacy randomization" advanced option
+            #[arg(long)]
+            legacy_random: bool,
+
+            /// First game ID to test
+            #[arg(long, default_value_t = 0)]
+            min_id: u64,
+
+            /// Last game ID to test (default: now)
+            #[arg(long)]
+            max_id: Option<u64>,
+
+            /// Worker threads (default: available parallelism)
+            #[arg(long)]
+            threads: Option<u64>,
+        },
+
+        /// Show why a single ID passes or fails each observation
+        Explain {
+            /// JSON file with `observations` and/or `groups`
+            file: PathBuf,
+
+            /// The game ID to explain
+            #[arg(long)]
+            id: u64,
+
+            /// Platform: pc, switch, or unknown (explains both)
+            #[arg(long, default_value = "unknown", value_parser = parse_platforms)]
+            platform: Platforms,
+
+            /// The save uses the "legacy randomization" advanced option
+            #[arg(long)]
+            legacy_random: bool,
+        },
+    }
+
+    /// The platforms to crack against: `unknown` expands to every platform.
+    #[derive(Clone)]
+    struct Platforms(Vec<Platform>);
+
+    fn parse_platforms(s: &str) -> Result<Platforms, String> {
+        match s.to_ascii_lowercase().as_str() {
+            "pc" => Ok(Platforms(vec![Platform::PC])),
+            "switch" => Ok(Platforms(vec![Platform::Switch])),
+            "unknown" => Ok(Platforms(Platform::ALL.to_vec())),
+            _ => Err(format!("unknown platform '{s}' — use 'pc', 'switch' or 'unknown'")),
+        }
+    }
+
+    fn parse_game_version(s: &str) -> Result<&'static GameData, String> {
+        GameData::for_version(s).map_err(|e| e.to_string())
+    }
+
+    #[derive(Deserialize)]
+    struct ObservationFile {
+        #[serde(default)]
+        observations: Vec<Observation>,
+        #[serde(default)]
+        groups: Vec<ObservationGroup>,
+    }
+
+    // ── Entry point ───────────────────────────────────────────────────────────
+
+    pub fn run() {
+        let args = Args::parse();
+        let data = args.game_version.unwrap_or_else(GameData::default_set);
+
+        match args.command {
+            Command::Search {
+                file,
+                platform,
+                legacy_random,
+                min_id,
+                max_id,
+                threads,
+            } => search(
+                file,
+                platform.0,
+                legacy_random,
+                data,
+                min_id,
+                max_id,
+                threads,
+            ),
+            Command::Explain {
+                file,
+                id,
+                platform,
+                legacy_random,
+            } => explain(data, file, id, platform.0, legacy_random),
+        }
+    }
+
+    fn seeding_mode(legacy_random: bool) -> SeedingMode {
+        if legacy_random {
+            SeedingMode::Legacy
+        } else {
+            SeedingMode::Hashed
+        }
+    }
+
+    fn search(
+        file: PathBuf,
+        platforms: Vec<Platform>,
+        legacy_random: bool,
+        data: &'static GameData,
+        min_id: u64,
+        max_id: Option<u64>,
+        threads: Option<u64>,
+    ) {
+        let observations = read_observations(&file);
+
+        let max_id = max_id.unwrap_or_else(|| {
+            let now = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or(0);
+            now.saturating_sub(STARDEW_EPOCH_UNIX)
+        });
+
+        let threads = threads.unwrap_or_else(|| {
+            std::thread::available_parallelism().map_or(1, |n| n.get() as u64)
+        });
+
+        let cracker = Cracker::new()
+            .platforms(platforms)
+            .seeding(seeding_mode(legacy_random))
+            .game_data(data)
+            .range(min_id..=max_id)
+            .observations(observations.observations)
+            .groups(observations.groups)
+            .parallelism(threads);
+
+        let stream = match cracker.run() {
+            Ok(stream) => stream,
+            Err(e) => {
+                eprintln!("error: {e}");
+                std::process::exit(1);
+            }
+        };
+
+        let total = cracker.range_len();
+        eprintln!(
+            "Searching {} IDs on {} thread(s), ~{:.1} candidate(s) expected",
+            total,
+            threads,
+            cracker.estimated_candidates(),
+        );
+
+        let mut tested = 0u64;
+        let mut found = 0u64;
+        for event in stream {
+            match event {
+                CrackEvent::Candidate(candidate) => {
+                    found += 1;
+                    let mut line = format!("{}  {}", candidate.id, candidate.platform.name());
+                    for (i, starts) in candidate.group_starts.iter().enumerate() {
+                        let dates: Vec<String> =
+                            starts.iter().map(|&s| describe_date(s)).collect();
+                        line.push_str(&format!("  [group {} starts {}]", i + 1, dates.join(" or ")));
+                    }
+                    // Clear the progress indicator before printing the result line.
+                    eprint!("\r       \r");
+                    println!("{line}");
+                }
+                CrackEvent::Progress { tested: batch } => {
+                    tested = (tested + batch).min(total);
+                    eprint!("\r{:>5.1}%", tested as f64 * 100.0 / total as f64);
+                }
+                CrackEvent::Done => eprintln!("\rDone — {found} candidate(s)."),
+            }
+        }
+    }
+
+    fn explain(
+        data: &'static GameData,
+        file: PathBuf,
+        id: u64,
+        platforms: Vec<Platform>,
+        legacy_random: bool,
+    ) {
+        let observations = read_observations(&file);
+        let seeding = seeding_mode(legacy_random);
+
+        for platform in platforms {
+            println!();
+            println!("  {} — ID {}", platform.name(), id);
+            println!("  {}", "─".repeat(75));
+
+            let reports = explain::explain(data, platform, seeding, id, &observations.observations);
+            print_reports(&reports);
+
+            for (i, group) in observations.groups.iter().enumerate() {
+                let (start, reports) = explain::explain_group(data, platform, seeding, id, group);
+                let verdict = if reports.iter().all(|r| r.passed()) {
+                    "consistent"
+                } else {
+                    "closest miss"
+                };
+                println!("  Group {} starting {} ({verdict}):", i + 1, describe_date(start));
+                print_reports(&reports);
+            }
+
+            let passed = reports.iter().filter(|r| r.passed()).count();
+            println!("  {}", "─".repeat(75));
+            println!("  {passed}/{} dated observation(s) pass", reports.len());
+        }
+        println!();
+    }
+
+    fn print_reports(reports: &[ObservationReport]) {
+        for report in reports {
+            let mark = if report.passed() { "PASS" } else { "FAIL" };
+            println!("  {mark}  {}", describe_date(report.days_played));
+            match &report.result {
+                Ok(explanation) => {
+                    println!("          generated: {}", explanation.generated);
+                    for alt in &explanation.alternatives {
+                        let verdict = match alt.mismatch {
+                            None => "matches".to_string(),
+                            Some(field) => format!("{field} mismatch"),
+                        };
+                        println!("          observed:  {}  — {verdict}", alt.observed);
+                    }
+                }
+                Err(e) => println!("          error: {e}"),
+            }
+        }
+    }
+
+    fn read_observations(file: &PathBuf) -> ObservationFile {
+        let text = match std::fs::read_to_string(file) {
+            Ok(t) => t,
+            Err(e) => {
+                eprintln!("error: cannot read {}: {e}", file.display());
+                std::process::exit(1);
+            }
+        };
+        match serde_json::from_str(&text) {
+            Ok(o) => o,
+            Err(e) => {
+                eprintln!("error: invalid observation file {}: {e}", file.display());
+                std::process::exit(1);
+            }
+        }
+    }
+}
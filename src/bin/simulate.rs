@@ -10,6 +10,32 @@
 //!   --season <S>      spring | summer | fall | winter (default spring)
 //!   --year <N>        starting year, ≥1 (default 1)
 //!   --count <N>       number of days to generate (default 28)
+//!   --legacy-random   the save uses legacy randomization
+//!   --game-version <V>  game patch whose data to use, e.g. 1.6.15 (default: the data directly under assets/)
+//!   --bundles         also print the remixed Community Center bundles
+//!   --winter-star <N> also print the Winter Star secret gifts of N years from --year
+//!   --dish            also print each day's Saloon Dish of the Day
+//!   --luck            also print each day's luck and TV fortune
+//!   --steps-taken <N,..>  stepsTaken at the start of each day, for --dish, --luck and --garbage
+//!   --desert-trader   also print each day's rotating Desert Trader offer
+//!   --weather         also print each day's weather
+//!   --night-events    also print overnight farm events (from a save without the capsule)
+//!   --garbage         also print what each town garbage can holds each day
+//!   --trash-book      the player has read The Alleyway Buffet, for --garbage
+//!   --mines           also print each day's special Mines floors
+//!   --quarry          the save can get quarry floors, for --mines
+//!   --special-orders  also print the town Special Orders board each Monday (board unlocked)
+//!   --qi-orders       also print Mr. Qi's board each Monday (Qi's Walnut Room unlocked)
+//!   --island          Ginger Island is unlocked, for the order boards
+//!   --completed-orders <K,..>  keys of special orders already completed
+//!   --bookseller <N>  also print the Bookseller's visit days for N seasons from --season
+//!   --trash-bear      also print the Trash Bear's requests in each season
+//!   --geodes <N>      also print the next N geodes of each type
+//!   --geodes-cracked <N>  geodes already cracked on the save (default 0)
+//!   --mystery-boxes <N>  also print the next N Mystery Boxes and Golden Mystery Boxes
+//!   --boxes-opened <N>  Mystery Boxes of either kind already opened on the save (default 0)
+//!   --artifact-spot <LOC:X,Y>  also print what digging this artifact spot gives each day (repeatable)
+//!   --qi-plane        the player has seen Mr. Qi's plane, for --artifact-spot and --garbage
 
 fn main() {
     #[cfg(not(target_arch = "wasm32"))]
@@ -22,10 +48,29 @@ fn main() {
 mod native {
     use clap::Parser;
 
-    use stardew_seed_cracker::codegen::OBJECTS;
-    use stardew_seed_cracker::game_data::{resource_type_to_items, FISHING_POOLS};
-    use stardew_seed_cracker::observation::{Platform, Season};
-    use stardew_seed_cracker::prng::{create_day_save_random, create_initialisation_random};
+    use stardew_seed_cracker::artifact_spots::{dig_artifact_spot, DigSpot};
+    use stardew_seed_cracker::bookseller::bookseller_days;
+    use stardew_seed_cracker::checker::CheckError;
+    use stardew_seed_cracker::codegen::GameData;
+    use stardew_seed_cracker::desert_trader::desert_trade;
+    use stardew_seed_cracker::explain::item_label;
+    use stardew_seed_cracker::game_data::resource_type_to_items;
+    use stardew_seed_cracker::garbage_cans::{can_label, garbage_loot, GarbageLoot, GarbageState};
+    use stardew_seed_cracker::geodes::{geode_treasure, geodes, qualified_item_label};
+    use stardew_seed_cracker::luck::{daily_luck, Fortune};
+    use stardew_seed_cracker::mines::special_floors;
+    use stardew_seed_cracker::mystery_boxes::{box_name, mystery_box_reward};
+    use stardew_seed_cracker::night_events::{night_event, NightEvent};
+    use stardew_seed_cracker::observation::{calendar_date, Platform, Season};
+    use stardew_seed_cracker::prng::{
+        create_day_save_random, create_initialisation_random, SeedingMode,
+    };
+    use stardew_seed_cracker::remixed_bundles::remixed_bundles;
+    use stardew_seed_cracker::saloon::dish_of_the_day;
+    use stardew_seed_cracker::special_orders::{board_orders, OrderBoard, SpecialOrderState};
+    use stardew_seed_cracker::trash_bear::{trash_bear_request, TRASH_BEAR_REQUESTS};
+    use stardew_seed_cracker::weather::weather;
+    use stardew_seed_cracker::winter_star::secret_gift;
 
     // ── CLI ───────────────────────────────────────────────────────────────────
 
@@ -55,6 +100,112 @@ mod native {
         /// Number of days to generate
         #[arg(long, default_value_t = 28)]
         count: u32,
+
+        /// The save uses the "legacy randomization" advanced option
+        #[arg(long)]
+        legacy_random: bool,
+
+        /// Game patch whose data to use, e.g. 1.6.15 (default: the data directly under assets/)
+        #[arg(long, value_parser = parse_game_version)]
+        game_version: Option<&'static GameData>,
+
+        /// Also print the bundles a remixed-bundles save gets
+        #[arg(long)]
+        bundles: bool,
+
+        /// Also print the Winter Star secret gifts of this many years, from --year
+        #[arg(long, default_value_t = 0)]
+        winter_star: u32,
+
+        /// Also print each day's Saloon Dish of the Day
+        #[arg(long)]
+        dish: bool,
+
+        /// Also print each day's luck and TV fortune
+        #[arg(long)]
+        luck: bool,
+
+        /// The save's stepsTaken at the start of each simulated day, comma-separated;
+        /// seeds --dish and --luck, and the luck and dish --garbage uses
+        #[arg(long, value_delimiter = ',')]
+        steps_taken: Vec<u32>,
+
+        /// Also print each day's rotating Desert Trader offer
+        #[arg(long)]
+        desert_trader: bool,
+
+        /// Also print each day's weather
+        #[arg(long)]
+        weather: bool,
+
+        /// Also print overnight farm events, assuming the capsule has not landed yet
+        #[arg(long)]
+        night_events: bool,
+
+        /// Also print what each town garbage can holds each day
+        #[arg(long)]
+        garbage: bool,
+
+        /// The player has read The Alleyway Buffet, for --garbage
+        #[arg(long)]
+        trash_book: bool,
+
+        /// Also print each day's infested, quarry and mushroom floors in the Mines
+        #[arg(long)]
+        mines: bool,
+
+        /// The save can get quarry floors (Crafts Room done, Quarry Mine visited)
+        #[arg(long)]
+        quarry: bool,
+
+        /// Also print the town Special Orders board each Monday; the board is unlocked
+        #[arg(long)]
+        special_orders: bool,
+
+        /// Also print Mr. Qi's Special Orders board each Monday; the Walnut Room is unlocked
+        #[arg(long)]
+        qi_orders: bool,
+
+        /// Ginger Island is unlocked, for --special-orders and --qi-orders
+        #[arg(long)]
+        island: bool,
+
+        /// Keys of special orders already completed, comma-separated
+        #[arg(long, value_delimiter = ',')]
+        completed_orders: Vec<String>,
+
+        /// Also print the Bookseller's visit days for this many seasons, from --season
+        #[arg(long, default_value_t = 0)]
+        bookseller: u32,
+
+        /// Also print what the Trash Bear asks for in each season
+        #[arg(long)]
+        trash_bear: bool,
+
+        /// Also print what the next this-many geodes of each type would give
+        #[arg(long, default_value_t = 0)]
+        geodes: u32,
+
+        /// Geodes already cracked on the save, for --geodes
+        #[arg(long, default_value_t = 0)]
+        geodes_cracked: u32,
+
+        /// Also print what the next this-many Mystery Boxes of each kind would give
+        #[arg(long, default_value_t = 0)]
+        mystery_boxes: u32,
+
+        /// Mystery Boxes of either kind already opened on the save, for --mystery-boxes
+        #[arg(long, default_value_t = 0)]
+        boxes_opened: u32,
+
+        /// Also print what digging an artifact spot on this tile gives each day,
+        /// as Location:X,Y (e.g. Forest:32,71); repeatable
+        #[arg(long, value_parser = parse_artifact_spot)]
+        artifact_spot: Vec<(String, u32, u32)>,
+
+        /// The player has seen Mr. Qi's plane, for --artifact-spot and --garbage
+        #[arg(long)]
+        qi_plane: bool,
     }
 
     fn parse_platform(s: &str) -> Result<Platform, String> {
@@ -65,11 +216,24 @@ mod native {
         }
     }
 
+    fn parse_game_version(s: &str) -> Result<&'static GameData, String> {
+        GameData::for_version(s).map_err(|e| e.to_string())
+    }
+
     fn parse_season(s: &str) -> Result<Season, String> {
         Season::from_key(&s.to_ascii_lowercase())
             .ok_or_else(|| format!("unknown season '{s}' — use spring, summer, fall, or winter"))
     }
 
+    fn parse_artifact_spot(s: &str) -> Result<(String, u32, u32), String> {
+        let err = || format!("bad artifact spot '{s}' — use Location:X,Y");
+        let (location, tile) = s.split_once(':').ok_or_else(err)?;
+        let (x, y) = tile.split_once(',').ok_or_else(err)?;
+        let x = x.trim().parse().map_err(|_| err())?;
+        let y = y.trim().parse().map_err(|_| err())?;
+        Ok((location.to_string(), x, y))
+    }
+
     // ── Entry point ───────────────────────────────────────────────────────────
 
     pub fn run() {
@@ -83,36 +247,60 @@ mod native {
             eprintln!("error: --year must be ≥1");
             std::process::exit(1);
         }
+        let data = args.game_version.unwrap_or_else(GameData::default_set);
 
-        let platform_label = match args.platform {
-            Platform::PC => "PC",
-            Platform::Switch => "Switch",
+        let seeding = if args.legacy_random {
+            SeedingMode::Legacy
+        } else {
+            SeedingMode::Hashed
         };
 
+        if args.bundles {
+            print_bundles(data, args.platform, seeding, args.seed);
+        }
+        if args.winter_star > 0 {
+            print_secret_gifts(data, args.platform, seeding, args.seed, args.year, args.winter_star);
+        }
+        if args.bookseller > 0 {
+            print_bookseller(args.platform, seeding, args.seed, args.season, args.year, args.bookseller);
+        }
+        if args.trash_bear {
+            print_trash_bear(data, args.platform, seeding, args.seed);
+        }
+        if args.geodes > 0 {
+            print_geodes(data, args.platform, seeding, args.seed, args.geodes_cracked, args.geodes);
+        }
+        if args.mystery_boxes > 0 {
+            print_mystery_boxes(data, args.platform, seeding, args.seed, args.boxes_opened, args.mystery_boxes);
+        }
+
         println!();
         println!(
             "  {} seed {} — starting Year {} {} Day {} — {} day(s)",
-            platform_label,
+            args.platform.name(),
             args.seed,
             args.year,
-            season_name(args.season),
+            args.season.name(),
             args.day,
             args.count,
         );
         println!("  {}", "─".repeat(75));
-        println!("  {:<14}  {:<10}  {}", "Day", "d-roll", "Quest");
+        println!("  {:<14}  {:<10}  Quest", "Day", "d-roll");
         println!("  {}", "─".repeat(75));
 
         let mut day = args.day;
         let mut season = args.season;
         let mut year = args.year;
+        let mut capsule_seen = false;
 
-        for _ in 0..args.count {
+        for index in 0..args.count {
+            let steps_taken = args.steps_taken.get(index as usize).copied();
             let days_played = (year - 1) * 112 + season.index() * 28 + day as u32;
             let is_monday = matches!(day, 1 | 8 | 15 | 22);
 
             let mut type_rng = create_day_save_random(
                 args.platform,
+                seeding,
                 days_played,
                 args.seed,
                 100.0,
@@ -126,7 +314,7 @@ mod native {
             let day_label = format!(
                 "Y{} {} {:>2}{}",
                 year,
-                &season_name(season)[..3],
+                &season.name()[..3],
                 day,
                 if is_monday { " Mon" } else { "    " },
             );
@@ -134,11 +322,122 @@ mod native {
             let quest = if days_played <= 1 {
                 "(no quest — DaysPlayed ≤ 1)".to_string()
             } else {
-                describe_quest(args.platform, args.seed, days_played, season, d, is_monday)
+                describe_quest(data, args.platform, seeding, args.seed, days_played, d, is_monday)
             };
 
             println!("  {day_label}  {d:.6}  {quest}");
 
+            if args.dish {
+                let dish = match steps_taken
+                    .map(|steps| dish_of_the_day(args.platform, seeding, args.seed, days_played, steps))
+                {
+                    Some(Ok((id, quantity))) => format!("{} ×{quantity}", item_label(data, id)),
+                    Some(Err(e)) => e.to_string(),
+                    None => "needs this day's --steps-taken".to_string(),
+                };
+                println!("  {:<14}  {:<8}  Dish of the Day: {dish}", "", "");
+            }
+
+            if args.luck {
+                let luck = match steps_taken
+                    .map(|steps| daily_luck(args.platform, seeding, args.seed, days_played, steps))
+                {
+                    Some(Ok(luck)) => format!("{luck:+.3} — {}", Fortune::from_luck(luck).label()),
+                    Some(Err(e)) => e.to_string(),
+                    None => "needs this day's --steps-taken".to_string(),
+                };
+                println!("  {:<14}  {:<8}  Luck: {luck}", "", "");
+            }
+
+            if args.desert_trader {
+                let trade = match desert_trade(data, args.platform, seeding, args.seed, days_played) {
+                    Ok(trade) => match trade.request {
+                        Some((requested, amount)) => format!(
+                            "{} for {} ×{amount}",
+                            item_label(data, trade.offered),
+                            qualified_item_label(data, requested)
+                        ),
+                        None => item_label(data, trade.offered),
+                    },
+                    Err(e) => e.to_string(),
+                };
+                println!("  {:<14}  {:<8}  Desert Trader: {trade}", "", "");
+            }
+
+            if args.weather {
+                let weather = match weather(args.platform, seeding, args.seed, days_played) {
+                    Ok(weather) => weather.label(),
+                    Err(e) => e.to_string(),
+                };
+                println!("  {:<14}  {:<8}  Weather: {weather}", "", "");
+            }
+
+            if args.night_events {
+                match night_event(args.platform, seeding, args.seed, days_played, capsule_seen) {
+                    Ok(NightEvent::None) => {}
+                    Ok(event) => {
+                        capsule_seen |= event == NightEvent::Capsule;
+                        println!("  {:<14}  {:<8}  Overnight: {}", "", "", event.label());
+                    }
+                    Err(e) => println!("  {:<14}  {:<8}  Overnight: {e}", "", ""),
+                }
+            }
+
+            if args.garbage {
+                let state = GarbageState {
+                    read_trash_book: args.trash_book,
+                    saw_qi_plane: args.qi_plane,
+                    steps_taken,
+                };
+                let finds = match garbage_finds(data, args.platform, seeding, args.seed, days_played, state) {
+                    Ok(finds) if finds.is_empty() => "nothing".to_string(),
+                    Ok(finds) => finds.join("; "),
+                    Err(e) => e.to_string(),
+                };
+                println!("  {:<14}  {:<8}  Garbage: {finds}", "", "");
+            }
+
+            if args.mines {
+                let floors = match special_floors(args.platform, seeding, args.seed, days_played, args.quarry) {
+                    Ok(floors) if floors.is_empty() => "none".to_string(),
+                    Ok(floors) => floors
+                        .iter()
+                        .map(|(floor, feature)| format!("{floor} {}", feature.label()))
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                    Err(e) => e.to_string(),
+                };
+                println!("  {:<14}  {:<8}  Mines: {floors}", "", "");
+            }
+
+            for (location, x, y) in &args.artifact_spot {
+                let spot = DigSpot { location, x: *x, y: *y };
+                let dug = match dig_artifact_spot(data, args.platform, seeding, args.seed, days_played, spot, args.qi_plane) {
+                    Ok(Some(id)) => item_label(data, id),
+                    Ok(None) => "no artifact".to_string(),
+                    Err(e) => e.to_string(),
+                };
+                println!("  {:<14}  {:<8}  Artifact spot {location} {x},{y}: {dug}", "", "");
+            }
+
+            if is_monday {
+                let state = SpecialOrderState {
+                    island_unlocked: args.island,
+                    completed: args.completed_orders.clone(),
+                };
+                let boards = [
+                    (args.special_orders, OrderBoard::Town),
+                    (args.qi_orders, OrderBoard::Qi),
+                ];
+                for (_, board) in boards.iter().filter(|(shown, _)| *shown) {
+                    let orders = match board_orders(data, args.platform, seeding, args.seed, *board, days_played, &state) {
+                        Ok(orders) => orders.join(", "),
+                        Err(e) => e.to_string(),
+                    };
+                    println!("  {:<14}  {:<8}  {}: {orders}", "", "", board.label());
+                }
+            }
+
             // Advance one calendar day.
             if day < 28 {
                 day += 1;
@@ -159,18 +458,172 @@ mod native {
         println!();
     }
 
+    // ── Remixed bundles ───────────────────────────────────────────────────────
+
+    fn print_bundles(data: &'static GameData, platform: Platform, seeding: SeedingMode, seed: u64) {
+        println!();
+        println!("  {} seed {} — remixed bundles", platform.name(), seed);
+        println!("  {}", "─".repeat(75));
+        match remixed_bundles(data, platform, seeding, seed) {
+            Ok(rooms) => {
+                for room in rooms {
+                    println!("  {:<14}  {}", room.room, room.bundles.join(", "));
+                }
+            }
+            Err(e) => println!("  {e}"),
+        }
+    }
+
+    // ── Winter Star ───────────────────────────────────────────────────────────
+
+    fn print_secret_gifts(
+        data: &'static GameData,
+        platform: Platform,
+        seeding: SeedingMode,
+        seed: u64,
+        first_year: u32,
+        years: u32,
+    ) {
+        println!();
+        println!("  {} seed {} — Winter Star secret gifts", platform.name(), seed);
+        println!("  {}", "─".repeat(75));
+        println!("  {:<8}  {:<12}  You give to", "Year", "Gift from");
+        println!("  {}", "─".repeat(75));
+        for year in first_year..first_year + years {
+            match secret_gift(data, platform, seeding, seed, year) {
+                Ok(gift) => println!("  {year:<8}  {:<12}  {}", gift.giver, gift.recipient),
+                Err(e) => println!("  {year:<8}  {e}"),
+            }
+        }
+    }
+
+    // ── Bookseller ────────────────────────────────────────────────────────────
+
+    fn print_bookseller(
+        platform: Platform,
+        seeding: SeedingMode,
+        seed: u64,
+        first_season: Season,
+        first_year: u32,
+        seasons: u32,
+    ) {
+        println!();
+        println!("  {} seed {} — Bookseller visits", platform.name(), seed);
+        println!("  {}", "─".repeat(75));
+        let first = (first_year - 1) * 4 + first_season.index();
+        for i in first..first + seasons {
+            let (season, year) = (Season::from_index(i), i / 4 + 1);
+            let label = format!("Y{year} {}", season.name());
+            match bookseller_days(platform, seeding, seed, season, year) {
+                Ok(mut days) => {
+                    days.sort_unstable();
+                    println!("  {label:<14}  days {} and {}", days[0], days[1]);
+                }
+                Err(e) => println!("  {label:<14}  {e}"),
+            }
+        }
+    }
+
+    // ── Garbage cans ──────────────────────────────────────────────────────────
+
+    /// "Can: loot" for every can that is not empty on `days_played`.
+    fn garbage_finds(
+        data: &'static GameData,
+        platform: Platform,
+        seeding: SeedingMode,
+        seed: u64,
+        days_played: u32,
+        state: GarbageState,
+    ) -> Result<Vec<String>, CheckError> {
+        if data.garbage_cans.is_empty() {
+            return Err(CheckError::MissingGameData("GarbageCans"));
+        }
+        let mut finds = Vec::new();
+        for can in data.garbage_cans.iter() {
+            let loot = garbage_loot(data, platform, seeding, seed, days_played, can.id, state)?;
+            if loot != GarbageLoot::Found(None) {
+                finds.push(format!("{}: {}", can_label(can.id), loot.label(data)));
+            }
+        }
+        Ok(finds)
+    }
+
+    // ── Trash Bear ────────────────────────────────────────────────────────────
+
+    fn print_trash_bear(data: &'static GameData, platform: Platform, seeding: SeedingMode, seed: u64) {
+        let describe = |season: Season, requests: std::ops::Range<u32>| -> String {
+            let items: Result<Vec<String>, CheckError> = requests
+                .map(|done| trash_bear_request(data, platform, seeding, seed, season, done))
+                .map(|item| item.map(|item| qualified_item_label(data, &item)))
+                .collect();
+            match items {
+                Ok(items) => items.join(", "),
+                Err(e) => e.to_string(),
+            }
+        };
+
+        println!();
+        println!("  {} seed {} — Trash Bear requests", platform.name(), seed);
+        println!("  {}", "─".repeat(75));
+        // The first two requests depend on the season, the dishes do not.
+        for season in (0..4).map(Season::from_index) {
+            println!("  {:<14}  {}", season.name(), describe(season, 0..2));
+        }
+        println!("  {:<14}  {}", "Any season", describe(Season::Spring, 2..TRASH_BEAR_REQUESTS));
+    }
+
+    // ── Geodes ────────────────────────────────────────────────────────────────
+
+    fn print_geodes(
+        data: &'static GameData,
+        platform: Platform, seeding: SeedingMode, seed: u64, cracked: u32, count: u32) {
+        println!();
+        println!("  {} seed {} — next {} geodes", platform.name(), seed, count);
+        for geode in geodes(data) {
+            println!("  {}", "─".repeat(75));
+            println!("  {}", geode.name);
+            for index in cracked + 1..=cracked + count {
+                match geode_treasure(data, platform, seeding, seed, geode.id, index) {
+                    Ok(Some(treasure)) => println!("  #{index:<7}  {}", treasure.label(data)),
+                    Ok(None) => {}
+                    Err(e) => println!("  #{index:<7}  {e}"),
+                }
+            }
+        }
+    }
+
+    // ── Mystery Boxes ─────────────────────────────────────────────────────────
+
+    fn print_mystery_boxes(
+        data: &'static GameData,
+        platform: Platform, seeding: SeedingMode, seed: u64, opened: u32, count: u32) {
+        println!();
+        println!("  {} seed {} — next {} Mystery Boxes", platform.name(), seed, count);
+        for golden in [false, true] {
+            println!("  {}", "─".repeat(75));
+            println!("  {}", box_name(golden));
+            for index in opened + 1..=opened + count {
+                match mystery_box_reward(platform, seeding, seed, golden, index) {
+                    Ok(reward) => println!("  #{index:<7}  {}", reward.label(data)),
+                    Err(e) => println!("  #{index:<7}  {e}"),
+                }
+            }
+        }
+    }
+
     // ── Quest description ─────────────────────────────────────────────────────
 
     fn describe_quest(
+        data: &'static GameData,
         platform: Platform,
+        seeding: SeedingMode,
         seed: u64,
         days_played: u32,
-        season: Season,
         d: f64,
         is_monday: bool,
     ) -> String {
         if d < 0.08 {
-            describe_resource(platform, seed, days_played)
+            describe_resource(data, platform, seeding, seed, days_played)
         } else if d < 0.2 {
             if days_played > 5 {
                 "SlayMonster  (or None if mine not yet entered)".to_string()
@@ -180,7 +633,7 @@ mod native {
         } else if d < 0.5 {
             "None".to_string()
         } else if d < 0.6 {
-            describe_fishing(platform, seed, days_played, season)
+            describe_fishing(data, platform, seeding, seed, days_played)
         } else if d < 0.66 && is_monday {
             "Socialize  (or ItemDelivery if already active)".to_string()
         } else {
@@ -188,8 +641,14 @@ mod native {
         }
     }
 
-    fn describe_resource(platform: Platform, seed: u64, days_played: u32) -> String {
-        let mut rng = create_initialisation_random(platform, seed, days_played)
+    fn describe_resource(
+        data: &'static GameData,
+        platform: Platform,
+        seeding: SeedingMode,
+        seed: u64,
+        days_played: u32,
+    ) -> String {
+        let mut rng = create_initialisation_random(platform, seeding, seed, days_played)
             .expect("create_initialisation_random failed");
 
         let resource_type = rng.gen_range(0..6).expect("gen_range failed") * 2;
@@ -198,7 +657,7 @@ mod native {
         let item_strs: Vec<String> = items
             .iter()
             .map(|&id| {
-                OBJECTS
+                data.objects
                     .get(&id)
                     .map(|o| format!("{} ({})", o.name, id))
                     .unwrap_or_else(|| format!("ID {id}"))
@@ -215,35 +674,29 @@ mod native {
     }
 
     fn describe_fishing(
+        data: &'static GameData,
         platform: Platform,
+        seeding: SeedingMode,
         seed: u64,
         days_played: u32,
-        season: Season,
     ) -> String {
-        let mut rng = create_initialisation_random(platform, seed, days_played)
+        let (_, season, _) = calendar_date(days_played);
+        let mut rng = create_initialisation_random(platform, seeding, seed, days_played)
             .expect("create_initialisation_random failed");
 
         let sub_pool = rng.next_bool().expect("next_bool failed");
         let npc = if sub_pool { "Willy" } else { "Demetrius" };
 
-        let pool = FISHING_POOLS[season.index() as usize][sub_pool as usize];
+        let pool = data.fishing_pools[season.index() as usize][sub_pool as usize];
         let fish_idx = rng.gen_range(0..pool.len() as i32).expect("gen_range failed") as usize;
         let fish_id = pool[fish_idx];
 
-        let fish_name = OBJECTS
+        let fish_name = data
+            .objects
             .get(&fish_id)
             .map(|o| o.name)
             .unwrap_or("(unknown)");
 
         format!("Fishing  [{npc}] → {fish_name} ({fish_id})")
     }
-
-    fn season_name(s: Season) -> &'static str {
-        match s {
-            Season::Spring => "Spring",
-            Season::Summer => "Summer",
-            Season::Fall => "Fall",
-            Season::Winter => "Winter",
-        }
-    }
 }
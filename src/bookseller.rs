@@ -0,0 +1,56 @@
+//! The Bookseller's visits (`Utility.getDaysOfBooksellerThisSeason`).
+//!
+//! Each season the game seeds `CreateRandom(year * 11, uniqueIDForThisGame,
+//! seasonIndex)`, picks one of the season's possible days, and adds the day
+//! half the list further on.
+//!
+//! His stock is not predicted. It comes from the `Bookseller` shop in
+//! `Data/Shops`, and the random slots skip books the player has already
+//! read, which the game ID cannot tell.
+
+use crate::checker::CheckError;
+use crate::observation::{Platform, Season};
+use crate::prng::{create_random, SeedingMode};
+
+/// Days of the month the Bookseller can come in `season`.
+pub fn possible_days(season: Season) -> &'static [u8] {
+    match season {
+        Season::Spring => &[11, 12, 21, 22, 25],
+        Season::Summer => &[9, 12, 18, 25, 27],
+        Season::Fall => &[4, 7, 8, 9, 12, 19, 22, 25],
+        Season::Winter => &[5, 11, 12, 19, 22, 24],
+    }
+}
+
+/// The two days of `season` in `year` the Bookseller is in town, in draw order.
+pub fn bookseller_days(
+    platform: Platform,
+    seeding: SeedingMode,
+    id: u64,
+    season: Season,
+    year: u32,
+) -> Result<[u8; 2], CheckError> {
+    let days = possible_days(season);
+    let mut rng = create_random(
+        platform,
+        seeding,
+        &[year as f64 * 11.0, id as f64, season.index() as f64],
+    )?;
+    let first = rng.gen_range(0..days.len() as i32)? as usize;
+    Ok([days[first], days[(first + days.len() / 2) % days.len()]])
+}
+
+/// Chance that a random ID has the Bookseller in town (or not) on `day`.
+pub fn bookseller_pass_rate(season: Season, day: u8, present: bool) -> f64 {
+    let days = possible_days(season);
+    let rate = if days.contains(&day) {
+        2.0 / days.len() as f64
+    } else {
+        0.0
+    };
+    if present {
+        rate
+    } else {
+        1.0 - rate
+    }
+}
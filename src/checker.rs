@@ -0,0 +1,134 @@
+use serde::{Deserialize, Serialize};
+
+use crate::codegen::GameData;
+use crate::observation::{Observation, ObservationGroup, Platform};
+use crate::prng::SeedingMode;
+use crate::sources::ObservationSource;
+
+/// A game ID that passed every observation, with the dates inferred for any
+/// observation groups.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Candidate {
+    pub id: u64,
+    /// The platform whose RNG produced the observations.
+    pub platform: Platform,
+    /// One entry per observation group, in the order given: every start
+    /// `days_played` on which the group is consistent with `id`.
+    pub group_starts: Vec<Vec<u32>>,
+}
+
+/// Why a check could not be evaluated.
+#[derive(Clone, Debug, PartialEq)]
+pub enum CheckError {
+    /// The platform RNG could not be created or rejected a call.
+    Prng(String),
+    /// `Next(6) * 2` produced a resource type with no known items.
+    UnknownResourceType(i32),
+    /// The named `assets/` data file was not available at build time.
+    MissingGameData(&'static str),
+    /// Fewer villagers than a draw needs, e.g. a secret gift with one participant.
+    TooFewNpcs { needed: usize, found: usize },
+}
+
+impl std::fmt::Display for CheckError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CheckError::Prng(e) => write!(f, "PRNG error: {e}"),
+            CheckError::UnknownResourceType(t) => write!(f, "unknown resource type {t}"),
+            CheckError::MissingGameData(name) => {
+                write!(f, "assets/{name}.json was missing when this build was made")
+            }
+            CheckError::TooFewNpcs { needed, found } => {
+                write!(f, "needs {needed} villagers, but the NPC table has {found}")
+            }
+        }
+    }
+}
+
+impl From<anyhow::Error> for CheckError {
+    fn from(e: anyhow::Error) -> Self {
+        CheckError::Prng(e.to_string())
+    }
+}
+
+/// Checks `id` against the fixed-date observations first (cheap rejection),
+/// then searches each group's start day.
+pub fn check_candidate(
+    data: &'static GameData,
+    platform: Platform,
+    seeding: SeedingMode,
+    id: u64,
+    observations: &[Observation],
+    groups: &[ObservationGroup],
+) -> Option<Candidate> {
+    if !check_all(data, platform, seeding, id, observations) {
+        return None;
+    }
+
+    let mut group_starts = Vec::with_capacity(groups.len());
+    for group in groups {
+        let starts = matching_starts(data, platform, seeding, id, group);
+        if starts.is_empty() {
+            return None;
+        }
+        group_starts.push(starts);
+    }
+
+    Some(Candidate {
+        id,
+        platform,
+        group_starts,
+    })
+}
+
+/// Returns true if `id` is consistent with every observation in the slice.
+/// Observations should be pre-sorted by `pass_rate()` ascending for best performance.
+pub fn check_all(
+    data: &'static GameData,
+    platform: Platform,
+    seeding: SeedingMode,
+    id: u64,
+    observations: &[Observation],
+) -> bool {
+    observations
+        .iter()
+        .all(|obs| check_observation(data, platform, seeding, id, obs))
+}
+
+/// Every start day on which `id` is consistent with the whole group.
+/// The group's observations should be pre-sorted by `pass_rate()` ascending.
+pub fn matching_starts(
+    data: &'static GameData,
+    platform: Platform,
+    seeding: SeedingMode,
+    id: u64,
+    group: &ObservationGroup,
+) -> Vec<u32> {
+    group
+        .starts()
+        .filter(|&start| {
+            group.observations.iter().all(|obs| {
+                start.checked_add(obs.offset).is_some_and(|day| {
+                    obs.source
+                        .check(data, platform, seeding, id, day)
+                        .unwrap_or(false)
+                })
+            })
+        })
+        .collect()
+}
+
+/// Returns true if candidate `id` is consistent with a single observation.
+/// A check that fails with an error counts as a mismatch; `Cracker::validate`
+/// rejects observations that cannot be checked before a crack starts.
+pub fn check_observation(
+    data: &'static GameData,
+    platform: Platform,
+    seeding: SeedingMode,
+    id: u64,
+    obs: &Observation,
+) -> bool {
+    obs.source
+        .check(data, platform, seeding, id, obs.days_played)
+        .unwrap_or(false)
+}
@@ -2,6 +2,227 @@
 pub struct ObjectData {
     pub name: &'static str,
     pub price: u32,
+    /// Negative category number, e.g. -75 for vegetables; 0 if none.
+    pub category: i64,
+    /// "Basic", "Minerals", "Arch", ...
+    pub object_type: &'static str,
+    /// False if `ExcludeFromRandomSale` keeps it out of random shop stock.
+    pub random_sale: bool,
+}
+
+/// One Community Center room in `Data/RandomBundles`.
+#[derive(Debug)]
+pub struct RandomBundleArea {
+    pub name: &'static str,
+    /// Number of bundle slots (the area's `Keys`).
+    pub slots: usize,
+    /// Fixed sets, one of which is chosen whole.
+    pub bundle_sets: &'static [&'static [BundleTemplate]],
+    /// Pool the remaining slots are filled from.
+    pub bundles: &'static [BundleTemplate],
+}
+
+#[derive(Debug)]
+pub struct BundleTemplate {
+    pub name: &'static str,
+    /// Slot this bundle fills, or -1 for any free slot.
+    pub index: i64,
+    /// Comma-separated item list, possibly with `[a|b]` random tags.
+    pub items: &'static str,
+    /// How many items the bundle keeps, or -1 for all.
+    pub pick: i64,
+}
+
+/// One entry of `Data/Characters`. Conditions are game state queries, kept
+/// as text for the caller to interpret.
+#[derive(Debug)]
+pub struct NpcData {
+    /// Internal name, e.g. "Abigail".
+    pub name: &'static str,
+    /// English display name, e.g. "Mister Qi".
+    pub display_name: &'static str,
+    /// Season index and day of month, if the NPC has a birthday.
+    pub birthday: Option<(u8, u8)>,
+    /// "Town", "Desert" or "Other".
+    pub home_region: &'static str,
+    pub can_socialize: Option<&'static str>,
+    pub can_receive_gifts: bool,
+    /// Whether the NPC can be an item-delivery quest target.
+    pub item_delivery_quests: Option<&'static str>,
+    pub winter_star_participant: Option<&'static str>,
+    pub unlock_conditions: Option<&'static str>,
+    /// The NPC's raw `Data/NPCGiftTastes` entry, if that file is present.
+    pub gift_tastes: Option<&'static str>,
+}
+
+/// A geode-like item from `Data/Objects` with its `GeodeDrops`.
+#[derive(Debug)]
+pub struct GeodeData {
+    /// Unqualified object ID, e.g. "535".
+    pub id: &'static str,
+    pub name: &'static str,
+    /// `GeodeDropsDefaultItems`: a coin flip picks between `drops` and the
+    /// hardcoded ore/stone table.
+    pub default_items: bool,
+    /// Sorted by precedence, as the game tries them.
+    pub drops: &'static [GeodeDrop],
+}
+
+#[derive(Debug)]
+pub struct GeodeDrop {
+    /// Qualified item ID, if the drop is a single item.
+    pub item_id: Option<&'static str>,
+    /// Qualified item IDs one is picked from, if any.
+    pub random_item_ids: &'static [&'static str],
+    pub chance: f64,
+    pub condition: Option<&'static str>,
+}
+
+/// One entry of `Data/SpecialOrders`, with what decides whether it can be
+/// offered. Tags and conditions are kept as text.
+#[derive(Debug)]
+pub struct SpecialOrderData {
+    /// The order's key, e.g. "Willy".
+    pub key: &'static str,
+    pub requester: &'static str,
+    /// "" for the town board, "Qi" for Mr. Qi's.
+    pub order_type: &'static str,
+    /// "Week", "Month", "TwoWeeks", ...
+    pub duration: &'static str,
+    pub repeatable: bool,
+    /// Comma-separated, each optionally negated with `!`.
+    pub required_tags: &'static str,
+    pub condition: Option<&'static str>,
+}
+
+/// An object's `ArtifactSpotChances`: the chance per location name that
+/// digging an artifact spot there gives it.
+#[derive(Debug)]
+pub struct ArtifactSpotData {
+    pub item_id: u32,
+    pub chances: &'static [(&'static str, f64)],
+}
+
+/// One can in `Data/GarbageCans`.
+#[derive(Debug)]
+pub struct GarbageCanData {
+    /// The can's ID in the map, e.g. "Saloon".
+    pub id: &'static str,
+    /// Chance that the can has something, or a negative value for
+    /// `GARBAGE_DEFAULT_BASE_CHANCE`.
+    pub base_chance: f32,
+    pub items: &'static [GarbageItem],
+}
+
+/// One possible find in a garbage can, tried in data order.
+#[derive(Debug)]
+pub struct GarbageItem {
+    pub id: &'static str,
+    /// Qualified item ID or item query, if the entry is a single item.
+    pub item_id: Option<&'static str>,
+    /// Qualified item IDs one is picked from, if any.
+    pub random_item_ids: &'static [&'static str],
+    pub condition: Option<&'static str>,
+    /// Tried even when the can's base chance fails.
+    pub ignore_base_chance: bool,
+}
+
+/// Every table generated from one patch's data files. `build.rs` emits one
+/// per data set into `GAME_DATA_SETS`, the default set first.
+pub struct GameData {
+    /// Patch the data comes from, e.g. "1.6.15".
+    pub version: &'static str,
+    pub objects: &'static phf::Map<u32, ObjectData>,
+    /// Object IDs sorted by name.
+    pub objects_by_name: &'static [u32],
+    /// Every `Data/Objects` key in file order: its ID, or `None` if the key
+    /// is not a number (e.g. "MysteryBox").
+    pub object_data_order: &'static [Option<u32>],
+    pub fishing_pools: &'static [[&'static [u32]; 2]; 4],
+    /// `getRandomPureSeasonalItem`'s forage and fish, by season index.
+    pub pure_seasonal_items: &'static [&'static [u32]; 4],
+    pub random_bundle_areas: &'static [RandomBundleArea],
+    pub npcs: &'static [NpcData],
+    pub geodes: &'static [GeodeData],
+    pub special_orders: &'static [SpecialOrderData],
+    pub artifact_spots: &'static [ArtifactSpotData],
+    pub garbage_default_base_chance: &'static f32,
+    pub garbage_before_all: &'static [GarbageItem],
+    pub garbage_after_all: &'static [GarbageItem],
+    pub garbage_cans: &'static [GarbageCanData],
+    /// Each recipe's name and the unqualified ID of what it makes.
+    pub cooking_recipes: &'static [(&'static str, &'static str)],
+    /// Qualified item and amount the Desert Trader asks for her rotating
+    /// trade, from the optional `Data/Shops`.
+    pub desert_trade_request: &'static Option<(&'static str, u32)>,
+}
+
+impl GameData {
+    /// The data set for patch `version`.
+    pub fn for_version(version: &str) -> Result<&'static GameData, UnknownGameVersion> {
+        GAME_DATA_SETS
+            .iter()
+            .find(|set| set.version == version)
+            .ok_or_else(|| UnknownGameVersion(version.to_string()))
+    }
+
+    /// The data set of the files directly under `assets/`.
+    pub fn default_set() -> &'static GameData {
+        &GAME_DATA_SETS[0]
+    }
+}
+
+/// Data sets are identified by their patch.
+impl PartialEq for GameData {
+    fn eq(&self, other: &Self) -> bool {
+        self.version == other.version
+    }
+}
+
+impl std::fmt::Debug for GameData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("GameData").field(&self.version).finish()
+    }
+}
+
+/// Every patch there is a data set for, the default first.
+pub fn game_versions() -> impl Iterator<Item = &'static str> {
+    GAME_DATA_SETS.iter().map(|set| set.version)
+}
+
+/// A game version with no data set in this build.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnknownGameVersion(pub String);
+
+impl std::fmt::Display for UnknownGameVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let known: Vec<&str> = game_versions().collect();
+        write!(
+            f,
+            "no game data for version {} (known: {})",
+            self.0,
+            known.join(", ")
+        )
+    }
+}
+
+impl std::error::Error for UnknownGameVersion {}
+
+/// (De)serializes a `&'static GameData` as its version, so cracks sent to
+/// web workers name the data set they use.
+pub mod game_data_version {
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    use super::GameData;
+
+    pub fn serialize<S: Serializer>(data: &&'static GameData, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_str(data.version)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<&'static GameData, D::Error> {
+        let version = String::deserialize(d)?;
+        GameData::for_version(&version).map_err(D::Error::custom)
+    }
 }
 
 include!(concat!(env!("OUT_DIR"), "/codegen.rs"));
@@ -0,0 +1,545 @@
+//! Platform-neutral crack orchestration.
+//!
+//! `Cracker` is a builder over everything a crack needs. It hands out one
+//! `CrackWorker` per unit of parallelism; each worker is a serializable,
+//! resumable iterator of `CrackEvent`s, so the same code drives the Yew web
+//! worker (one batch per message) and native threads (see `Cracker::run`).
+
+use std::cmp::Ordering;
+use std::collections::VecDeque;
+use std::ops::RangeInclusive;
+
+use serde::{Deserialize, Serialize};
+
+use crate::checker::{check_candidate, Candidate, CheckError};
+use crate::codegen::{game_data_version, GameData};
+use crate::observation::{Observation, ObservationGroup, Platform, MAX_GROUP_DAY, MAX_YEAR};
+use crate::prng::SeedingMode;
+use crate::sources::ObservationSource;
+
+/// 2012-06-22T00:00:00 UTC as a Unix timestamp (seconds since 1970-01-01).
+pub const STARDEW_EPOCH_UNIX: u64 = 1340323200;
+
+/// How many candidates to test per batch before yielding a progress update.
+pub const PROGRESS_INCREMENT: u64 = 1 << 20; // ~1M
+
+/// Why a crack cannot start.
+#[derive(Clone, Debug, PartialEq)]
+pub enum CrackError {
+    /// The ID range is empty (its start is after its end).
+    EmptyRange,
+    /// The observation group at this index has no start day to try: its
+    /// earliest start is after its latest.
+    EmptyGroupStarts(usize),
+    /// The observation group at this index reaches past the end of `MAX_YEAR`.
+    GroupPastMaxYear(usize),
+    /// An observation cannot be checked on this build, e.g. its data file is
+    /// missing, so it would reject every ID.
+    Unusable(CheckError),
+}
+
+impl std::fmt::Display for CrackError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CrackError::EmptyRange => write!(f, "the first ID to test is after the last"),
+            CrackError::EmptyGroupStarts(index) => write!(
+                f,
+                "unknown-date group {} has no start day: its earliest start is after its latest",
+                index + 1
+            ),
+            CrackError::GroupPastMaxYear(index) => write!(
+                f,
+                "unknown-date group {} reaches past year {MAX_YEAR}",
+                index + 1
+            ),
+            CrackError::Unusable(e) => write!(f, "an observation cannot be checked: {e}"),
+        }
+    }
+}
+
+/// Builder for a crack. Defaults: every platform, hashed seeding, the default
+/// game data, IDs `0..=u32::MAX`, no observations, one worker.
+#[derive(Clone)]
+pub struct Cracker {
+    platforms: Vec<Platform>,
+    seeding: SeedingMode,
+    data: &'static GameData,
+    range: RangeInclusive<u64>,
+    observations: Vec<Observation>,
+    groups: Vec<ObservationGroup>,
+    parallelism: u64,
+}
+
+impl Default for Cracker {
+    fn default() -> Self {
+        Self {
+            platforms: Platform::ALL.to_vec(),
+            seeding: SeedingMode::default(),
+            data: GameData::default_set(),
+            range: 0..=u32::MAX as u64,
+            observations: Vec::new(),
+            groups: Vec::new(),
+            parallelism: 1,
+        }
+    }
+}
+
+impl Cracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Platforms to check every ID against. More than one doubles the search.
+    pub fn platforms(mut self, platforms: Vec<Platform>) -> Self {
+        self.platforms = platforms;
+        self
+    }
+
+    pub fn seeding(mut self, seeding: SeedingMode) -> Self {
+        self.seeding = seeding;
+        self
+    }
+
+    /// Data set the workers check against; see `GameData::for_version`.
+    pub fn game_data(mut self, data: &'static GameData) -> Self {
+        self.data = data;
+        self
+    }
+
+    /// Candidate game IDs to test (inclusive).
+    pub fn range(mut self, range: RangeInclusive<u64>) -> Self {
+        self.range = range;
+        self
+    }
+
+    pub fn observations(mut self, observations: Vec<Observation>) -> Self {
+        self.observations = observations;
+        self
+    }
+
+    pub fn groups(mut self, groups: Vec<ObservationGroup>) -> Self {
+        self.groups = groups;
+        self
+    }
+
+    /// Number of workers the range is strided across (≥1).
+    pub fn parallelism(mut self, parallelism: u64) -> Self {
+        self.parallelism = parallelism.max(1);
+        self
+    }
+
+    /// Number of IDs in the range, i.e. the total progress of a full crack.
+    pub fn range_len(&self) -> u64 {
+        self.range
+            .end()
+            .checked_sub(*self.range.start())
+            .map_or(0, |len| len.saturating_add(1))
+    }
+
+    /// Expected number of candidates the crack will report.
+    pub fn estimated_candidates(&self) -> f64 {
+        // Every platform searched is another full copy of the ID space.
+        let space = self.range_len() as f64 * self.platforms.len().max(1) as f64;
+
+        let estimate = self
+            .observations
+            .iter()
+            .fold(space, |acc, ob| acc * ob.pass_rate(self.data));
+        let estimate = self
+            .groups
+            .iter()
+            .fold(estimate, |acc, g| acc * g.pass_rate(self.data));
+
+        // Due to id/2 truncation: consecutive seeds (2N, 2N+1) produce identical
+        // day-save randoms. Without an observation seeded from the full id, pairs
+        // are indistinguishable, so always ≥ 2.
+        let has_distinguishing = self
+            .observations
+            .iter()
+            .map(|ob| &ob.source)
+            .chain(
+                self.groups
+                    .iter()
+                    .flat_map(|g| g.observations.iter().map(|ob| &ob.source)),
+            )
+            .any(|source| source.distinguishes_pairs());
+
+        if has_distinguishing {
+            estimate
+        } else {
+            estimate.max(2.0)
+        }
+    }
+
+    /// Checks that there is something to search: a non-empty ID range and at
+    /// least one start day for every group, with no group reaching past
+    /// `MAX_YEAR`. Every observation is also checked once against ID 0, so one
+    /// this build cannot evaluate fails here instead of rejecting every ID.
+    pub fn validate(&self) -> Result<(), CrackError> {
+        if self.range.is_empty() {
+            return Err(CrackError::EmptyRange);
+        }
+        for (index, group) in self.groups.iter().enumerate() {
+            if group.last_day().is_none_or(|day| day > MAX_GROUP_DAY) {
+                return Err(CrackError::GroupPastMaxYear(index));
+            }
+            if group.starts().is_empty() {
+                return Err(CrackError::EmptyGroupStarts(index));
+            }
+        }
+
+        let dated = self.observations.iter().map(|o| (&o.source, o.days_played));
+        let floating = self.groups.iter().flat_map(|g| {
+            let start = *g.starts().start();
+            g.observations.iter().map(move |o| (&o.source, start + o.offset))
+        });
+        for (source, days_played) in dated.chain(floating) {
+            for &platform in &self.platforms {
+                source
+                    .check(self.data, platform, self.seeding, 0, days_played)
+                    .map_err(CrackError::Unusable)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Splits the crack into `parallelism` strided workers, with observations
+    /// sorted by pass_rate ascending (most discriminating first). Groups keep
+    /// their order so `Candidate::group_starts` lines up with `groups`.
+    pub fn workers(&self) -> Result<Vec<CrackWorker>, CrackError> {
+        self.validate()?;
+
+        let mut observations = self.observations.clone();
+        sort_by_pass_rate(&mut observations, |o| o.pass_rate(self.data));
+
+        let mut groups = self.groups.clone();
+        for group in &mut groups {
+            sort_by_pass_rate(&mut group.observations, |o| {
+                o.source.pass_rate(self.data, 1 + o.offset)
+            });
+        }
+
+        Ok((0..self.parallelism)
+            .map(|i| CrackWorker {
+                platforms: self.platforms.clone(),
+                seeding: self.seeding,
+                data: self.data,
+                next: self.range.start().checked_add(i),
+                end: *self.range.end(),
+                stride: self.parallelism,
+                observations: observations.clone(),
+                groups: groups.clone(),
+                batch_tested: 0,
+                pending: VecDeque::new(),
+                finished: false,
+            })
+            .collect())
+    }
+
+    /// Runs every worker on its own thread and streams their events.
+    /// The stream ends after a single `CrackEvent::Done`.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn run(&self) -> Result<CrackStream, CrackError> {
+        let workers = self.workers()?;
+        let (sender, receiver) = std::sync::mpsc::channel();
+        let running = workers.len();
+        for worker in workers {
+            let sender = sender.clone();
+            std::thread::spawn(move || {
+                for event in worker {
+                    if sender.send(event).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+
+        Ok(CrackStream { receiver, running })
+    }
+}
+
+/// Events produced while cracking.
+#[derive(Clone, Serialize, Deserialize)]
+pub enum CrackEvent {
+    Candidate(Candidate),
+    /// `tested` more IDs have been checked since the previous progress event.
+    Progress { tested: u64 },
+    /// The worker (or, from `CrackStream`, every worker) has exhausted its range.
+    Done,
+}
+
+/// One strided slice of a crack. Iterating yields candidates as they are found,
+/// a `Progress` event every `PROGRESS_INCREMENT` IDs and one for the rest of
+/// the range, then `Done`.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct CrackWorker {
+    platforms: Vec<Platform>,
+    seeding: SeedingMode,
+    #[serde(with = "game_data_version")]
+    data: &'static GameData,
+    /// Next ID to test; `None` once the stride has overflowed past `u64::MAX`.
+    next: Option<u64>,
+    end: u64,
+    stride: u64,
+    observations: Vec<Observation>,
+    groups: Vec<ObservationGroup>,
+    /// IDs tested since the last `Progress` event.
+    batch_tested: u64,
+    /// Candidates found for the current ID (one per matching platform), not yet yielded.
+    pending: VecDeque<Candidate>,
+    finished: bool,
+}
+
+impl CrackWorker {
+    /// Tests up to `PROGRESS_INCREMENT` IDs, returning the candidates found and
+    /// the event that ended the batch (`Progress` or `Done`).
+    pub fn next_batch(&mut self) -> (Vec<Candidate>, CrackEvent) {
+        let mut found = Vec::new();
+        for event in self.by_ref() {
+            match event {
+                CrackEvent::Candidate(candidate) => found.push(candidate),
+                other => return (found, other),
+            }
+        }
+        (found, CrackEvent::Done)
+    }
+}
+
+impl Iterator for CrackWorker {
+    type Item = CrackEvent;
+
+    fn next(&mut self) -> Option<CrackEvent> {
+        loop {
+            if let Some(candidate) = self.pending.pop_front() {
+                return Some(CrackEvent::Candidate(candidate));
+            }
+            if self.finished {
+                return None;
+            }
+
+            let Some(current) = self.next.filter(|&id| id <= self.end) else {
+                // Report the last, partial batch before finishing.
+                if self.batch_tested > 0 {
+                    let tested = std::mem::take(&mut self.batch_tested);
+                    return Some(CrackEvent::Progress { tested });
+                }
+                self.finished = true;
+                return Some(CrackEvent::Done);
+            };
+
+            if self.batch_tested == PROGRESS_INCREMENT {
+                self.batch_tested = 0;
+                return Some(CrackEvent::Progress {
+                    tested: PROGRESS_INCREMENT,
+                });
+            }
+
+            // Overflow means we've wrapped past u64::MAX — range exhausted.
+            self.next = current.checked_add(self.stride);
+            self.batch_tested += 1;
+
+            for &platform in &self.platforms {
+                if let Some(candidate) = check_candidate(
+                    self.data,
+                    platform,
+                    self.seeding,
+                    current,
+                    &self.observations,
+                    &self.groups,
+                ) {
+                    self.pending.push_back(candidate);
+                }
+            }
+        }
+    }
+}
+
+/// Merged events from every thread started by `Cracker::run`.
+#[cfg(not(target_arch = "wasm32"))]
+pub struct CrackStream {
+    receiver: std::sync::mpsc::Receiver<CrackEvent>,
+    running: usize,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Iterator for CrackStream {
+    type Item = CrackEvent;
+
+    fn next(&mut self) -> Option<CrackEvent> {
+        while self.running > 0 {
+            match self.receiver.recv() {
+                Ok(CrackEvent::Done) => {
+                    self.running -= 1;
+                    if self.running == 0 {
+                        return Some(CrackEvent::Done);
+                    }
+                }
+                Ok(event) => return Some(event),
+                // Every sender is gone, so no thread can still be running.
+                Err(_) => {
+                    self.running = 0;
+                    return Some(CrackEvent::Done);
+                }
+            }
+        }
+        None
+    }
+}
+
+fn sort_by_pass_rate<T>(items: &mut [T], pass_rate: impl Fn(&T) -> f64) {
+    items.sort_by(|a, b| {
+        pass_rate(a)
+            .partial_cmp(&pass_rate(b))
+            .unwrap_or(Ordering::Equal)
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::observation::{days_played, year_last_day, FloatingObservation, Season};
+    use crate::sources::DesertTradeObservation;
+    use crate::sources::{SecretGiftObservation, SourceObservation};
+
+    const ID: u64 = 123_456_789;
+
+    /// The Desert Trader's offers for `ID` on days 2–11 and 30–35.
+    const OFFERS: [(u32, u32); 16] = [
+        (2, 707),
+        (3, 139),
+        (4, 241),
+        (5, 247),
+        (6, 573),
+        (7, 108),
+        (8, 325),
+        (9, 108),
+        (10, 560),
+        (11, 585),
+        (30, 709),
+        (31, 403),
+        (32, 230),
+        (33, 271),
+        (34, 634),
+        (35, 613),
+    ];
+
+    fn observed_trades(days: std::ops::Range<u32>) -> Vec<Observation> {
+        OFFERS
+            .iter()
+            .filter(|(day, _)| days.contains(day))
+            .map(|&(day, offered)| {
+                let seen = DesertTradeObservation {
+                    offered,
+                    requested: None,
+                    amount: None,
+                };
+                Observation::on_day(day, seen.into())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn finds_the_seed_that_made_the_observations() {
+        let cracker = Cracker::new()
+            .platforms(vec![Platform::PC])
+            .range(ID - 100..=ID + 100)
+            .observations(observed_trades(2..12))
+            .parallelism(3);
+
+        let found: Vec<u64> = cracker
+            .run()
+            .unwrap()
+            .filter_map(|event| match event {
+                CrackEvent::Candidate(candidate) => Some(candidate.id),
+                _ => None,
+            })
+            .collect();
+        assert!(found.contains(&ID));
+        // Day-save randoms only see `id / 2`, so the pair partner may match too.
+        assert!(found.iter().all(|&id| id / 2 == ID / 2));
+    }
+
+    #[test]
+    fn group_start_is_recovered() {
+        let observations = observed_trades(30..36)
+            .into_iter()
+            .map(|o| FloatingObservation {
+                offset: o.days_played - 30,
+                source: o.source,
+            })
+            .collect();
+        let group = ObservationGroup {
+            observations,
+            min_start: 20,
+            max_start: Some(40),
+        };
+        let mut worker = Cracker::new()
+            .platforms(vec![Platform::PC])
+            .range(ID..=ID)
+            .groups(vec![group])
+            .workers()
+            .unwrap()
+            .remove(0);
+
+        let (found, _) = worker.next_batch();
+        assert_eq!(found.len(), 1);
+        assert!(found[0].group_starts[0].contains(&30));
+    }
+
+    #[test]
+    fn rejects_empty_searches() {
+        assert_eq!(
+            Cracker::new().range(RangeInclusive::new(5, 4)).validate(),
+            Err(CrackError::EmptyRange)
+        );
+        let group = ObservationGroup {
+            observations: Vec::new(),
+            min_start: 10,
+            max_start: Some(9),
+        };
+        assert_eq!(
+            Cracker::new().groups(vec![group]).validate(),
+            Err(CrackError::EmptyGroupStarts(0))
+        );
+    }
+
+    #[test]
+    fn rejects_groups_past_the_last_year() {
+        // Year 38,000,000 fits in a u32 day count but would scan billions of starts.
+        let group = ObservationGroup {
+            observations: Vec::new(),
+            min_start: 1,
+            max_start: Some(38_000_000 * 112),
+        };
+        assert_eq!(
+            Cracker::new().groups(vec![group]).validate(),
+            Err(CrackError::GroupPastMaxYear(0))
+        );
+        assert_eq!(year_last_day(MAX_YEAR + 1), None);
+    }
+
+    #[test]
+    fn rejects_observations_this_build_cannot_check() {
+        // A secret gift needs two participants; an empty NPC table has none.
+        let data: &'static GameData = Box::leak(Box::new(GameData {
+            npcs: &[],
+            ..*GameData::default_set()
+        }));
+        let gift = Observation::on_day(
+            days_played(25, Season::Winter, 1),
+            SourceObservation::SecretGift(SecretGiftObservation {
+                givers: vec!["Abigail".to_string()],
+            }),
+        );
+        assert_eq!(
+            Cracker::new()
+                .game_data(data)
+                .observations(vec![gift])
+                .validate(),
+            Err(CrackError::Unusable(CheckError::TooFewNpcs {
+                needed: 2,
+                found: 0
+            }))
+        );
+    }
+}
@@ -0,0 +1,87 @@
+//! The Desert Trader's rotating daily trade.
+//!
+//! Besides her fixed trades, the `DesertTrade` shop in `Data/Shops` has a
+//! `RANDOM_ITEMS (O) 2 789 @requirePrice @isRandomSale` entry: one random
+//! object a day. The shop resolves its item queries with
+//! `CreateDaySaveRandom()`. `RANDOM_ITEMS` orders every entry of
+//! `Data/Objects` by one `Next()` each, drawn in data order (a stable
+//! `OrderBy`), and the shop keeps the first with a numeric ID in 2–789, a
+//! price, and no `ExcludeFromRandomSale`.
+//!
+//! What she asks in return is that entry's `TradeItemId` and
+//! `TradeItemAmount`, read from `assets/Shops.json` when `Data/Shops` is
+//! extracted there; see `build.rs`.
+//!
+//! The shop random and the shuffle are recalled from the game's shop and
+//! item query code and have not been checked against a save.
+
+use crate::checker::CheckError;
+use crate::codegen::GameData;
+use crate::observation::Platform;
+use crate::prng::{create_day_save_random, SeedingMode};
+
+/// The ID range of the shop's `RANDOM_ITEMS` query.
+const OFFER_IDS: std::ops::RangeInclusive<u32> = 2..=789;
+
+/// One day's rotating trade.
+#[derive(Clone, Copy, PartialEq)]
+pub struct DesertTrade {
+    /// Object ID on offer.
+    pub offered: u32,
+    /// Qualified item asked for in return and how many, if `Data/Shops` was
+    /// shipped with this build.
+    pub request: Option<(&'static str, u32)>,
+}
+
+/// The rotating trade on `days_played`.
+pub fn desert_trade(
+    data: &'static GameData,
+    platform: Platform,
+    seeding: SeedingMode,
+    id: u64,
+    days_played: u32,
+) -> Result<DesertTrade, CheckError> {
+    let mut rng = create_day_save_random(platform, seeding, days_played, id, 0.0, 0.0, 0.0)?;
+
+    // Every entry takes a key; the lowest key among those that may be
+    // offered wins, the earliest entry on a tie.
+    let mut best: Option<(i32, u32)> = None;
+    for &entry in data.object_data_order {
+        let key = rng.next_sample()?;
+        if entry.is_some_and(|id| can_be_offered(data, id))
+            && best.is_none_or(|(best_key, _)| key < best_key)
+        {
+            best = entry.map(|id| (key, id));
+        }
+    }
+
+    let (_, offered) = best.ok_or(CheckError::MissingGameData("Objects"))?;
+    Ok(DesertTrade {
+        offered,
+        request: *data.desert_trade_request,
+    })
+}
+
+/// `@requirePrice @isRandomSale` within the query's ID range.
+fn can_be_offered(data: &GameData, id: u32) -> bool {
+    OFFER_IDS.contains(&id)
+        && data
+            .objects
+            .get(&id)
+            .is_some_and(|object| object.random_sale && object.price > 0)
+}
+
+/// Every object that can be on offer, in ID order.
+pub fn possible_offers(data: &GameData) -> Vec<u32> {
+    OFFER_IDS.filter(|&id| can_be_offered(data, id)).collect()
+}
+
+/// Chance that a random ID offers `offered`: the shuffle makes every object
+/// that can be offered equally likely.
+pub fn desert_trade_pass_rate(data: &GameData, offered: u32) -> f64 {
+    if can_be_offered(data, offered) {
+        1.0 / possible_offers(data).len() as f64
+    } else {
+        0.0
+    }
+}
@@ -15,7 +15,7 @@ use yew::prelude::*;
 /// Render a string that may contain `\n` as a sequence of text nodes separated
 /// by `<br/>` elements.  Single-line strings render as plain text with no
 /// extra DOM nodes.
-fn multiline(text: &str) -> Html {
+pub(crate) fn multiline(text: &str) -> Html {
     let mut parts = text.split('\n');
     let first = parts.next().unwrap_or("");
     let mut out = html! { {first} };
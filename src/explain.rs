@@ -0,0 +1,81 @@
+//! Per-observation verdicts for a single candidate ID.
+//!
+//! The checker only answers "does this ID pass?". When a seed the player knows
+//! to be correct is rejected, `explain` shows what the game would actually have
+//! generated on each observed day and which field disagreed.
+
+use crate::checker::CheckError;
+use crate::codegen::GameData;
+use crate::observation::{Observation, ObservationGroup, Platform};
+use crate::prng::SeedingMode;
+use crate::sources::{Explanation, ObservationSource};
+
+#[derive(Clone)]
+pub struct ObservationReport {
+    pub days_played: u32,
+    /// The explanation, or why the observation could not be evaluated.
+    pub result: Result<Explanation, CheckError>,
+}
+
+impl ObservationReport {
+    /// True if any alternative matches, i.e. the checker accepts this observation.
+    pub fn passed(&self) -> bool {
+        match &self.result {
+            Ok(explanation) => explanation.passed(),
+            Err(_) => false,
+        }
+    }
+}
+
+/// One report per observation, in the order given.
+pub fn explain(
+    data: &'static GameData,
+    platform: Platform,
+    seeding: SeedingMode,
+    id: u64,
+    observations: &[Observation],
+) -> Vec<ObservationReport> {
+    observations
+        .iter()
+        .map(|obs| ObservationReport {
+            days_played: obs.days_played,
+            result: obs.source.explain(data, platform, seeding, id, obs.days_played),
+        })
+        .collect()
+}
+
+/// Explains a group at the start day where the most observations pass: the
+/// first consistent start if there is one, otherwise the closest miss.
+pub fn explain_group(
+    data: &'static GameData,
+    platform: Platform,
+    seeding: SeedingMode,
+    id: u64,
+    group: &ObservationGroup,
+) -> (u32, Vec<ObservationReport>) {
+    let mut best: Option<(usize, u32, Vec<ObservationReport>)> = None;
+    for start in group.starts() {
+        let reports = explain(data, platform, seeding, id, &group.at(start));
+        let passed = reports.iter().filter(|r| r.passed()).count();
+        if best.as_ref().is_none_or(|(most, _, _)| passed > *most) {
+            let all = passed == reports.len();
+            best = Some((passed, start, reports));
+            if all {
+                break;
+            }
+        }
+    }
+
+    match best {
+        Some((_, start, reports)) => (start, reports),
+        None => (group.min_start, Vec::new()),
+    }
+}
+
+/// Item name with its ID, e.g. "Carp (142)".
+pub fn item_label(data: &GameData, id: u32) -> String {
+    match data.objects.get(&id) {
+        Some(o) => format!("{} ({id})", o.name),
+        None => format!("ID {id}"),
+    }
+}
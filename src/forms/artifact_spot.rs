@@ -0,0 +1,179 @@
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+use crate::artifact_spots::{artifact_locations, location_artifacts};
+use crate::codegen::GameData;
+use crate::dropdown::DropdownSelect;
+use crate::sources::ArtifactSpotObservation;
+
+/// Key of the "no artifact" choice in the item picker.
+const NO_ARTIFACT: &str = "none";
+
+/// Form state for an artifact spot observation.
+#[derive(Clone, PartialEq, Default)]
+pub struct ArtifactSpotFormState {
+    pub location: Option<String>,
+    pub x_value: String,
+    pub y_value: String,
+    /// `None` until picked; `Some(None)` for "no artifact".
+    pub item: Option<Option<u32>>,
+    pub saw_qi_plane: bool,
+}
+
+impl ArtifactSpotFormState {
+    pub fn build(&self) -> Option<ArtifactSpotObservation> {
+        Some(ArtifactSpotObservation {
+            location: self.location.clone()?,
+            x: self.x_value.parse().ok()?,
+            y: self.y_value.parse().ok()?,
+            item: self.item?,
+            saw_qi_plane: self.saw_qi_plane,
+        })
+    }
+}
+
+fn location_options(data: &'static GameData) -> Vec<(String, String)> {
+    artifact_locations(data)
+        .into_iter()
+        .map(|location| (location.to_string(), location.to_string()))
+        .collect()
+}
+
+fn item_options(data: &'static GameData, location: &str) -> Vec<(String, String)> {
+    let mut opts: Vec<(String, String)> = location_artifacts(data, location)
+        .into_iter()
+        .filter_map(|(id, _)| {
+            data.objects
+                .get(&id)
+                .map(|obj| (id.to_string(), obj.name.to_string()))
+        })
+        .collect();
+    opts.sort_by(|a, b| a.1.cmp(&b.1));
+    opts.insert(0, (NO_ARTIFACT.to_string(), "No artifact".to_string()));
+    opts
+}
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct ArtifactSpotFormProps {
+    pub data: &'static GameData,
+    pub state: ArtifactSpotFormState,
+    pub on_change: Callback<ArtifactSpotFormState>,
+}
+
+#[component]
+pub fn ArtifactSpotForm(props: &ArtifactSpotFormProps) -> Html {
+    let s = &props.state;
+    let on_change = props.on_change.clone();
+
+    // ---- Location ----
+    let on_location = {
+        let s = s.clone();
+        let on_change = on_change.clone();
+        Callback::from(move |location: String| {
+            let mut ns = s.clone();
+            if ns.location.as_deref() != Some(location.as_str()) {
+                ns.item = None;
+            }
+            ns.location = Some(location);
+            on_change.emit(ns);
+        })
+    };
+
+    // ---- Tile ----
+    let on_x_input = {
+        let s = s.clone();
+        let on_change = on_change.clone();
+        Callback::from(move |e: InputEvent| {
+            let mut ns = s.clone();
+            ns.x_value = e.target_unchecked_into::<HtmlInputElement>().value();
+            on_change.emit(ns);
+        })
+    };
+    let on_y_input = {
+        let s = s.clone();
+        let on_change = on_change.clone();
+        Callback::from(move |e: InputEvent| {
+            let mut ns = s.clone();
+            ns.y_value = e.target_unchecked_into::<HtmlInputElement>().value();
+            on_change.emit(ns);
+        })
+    };
+
+    // ---- Item ----
+    let on_item = {
+        let s = s.clone();
+        let on_change = on_change.clone();
+        Callback::from(move |key: String| {
+            let mut ns = s.clone();
+            ns.item = if key == NO_ARTIFACT {
+                Some(None)
+            } else {
+                key.parse::<u32>().ok().map(Some)
+            };
+            on_change.emit(ns);
+        })
+    };
+
+    let on_toggle_plane = {
+        let s = s.clone();
+        let on_change = on_change.clone();
+        Callback::from(move |_: MouseEvent| {
+            let mut ns = s.clone();
+            ns.saw_qi_plane = !ns.saw_qi_plane;
+            on_change.emit(ns);
+        })
+    };
+
+    let item_section: Html = match &s.location {
+        Some(location) => html! {
+            <DropdownSelect
+                options={item_options(props.data, location)}
+                selected={s.item.map(|item| item.map_or(NO_ARTIFACT.to_string(), |id| id.to_string()))}
+                placeholder="Dug up"
+                on_select={on_item}
+            />
+        },
+        None => html! {},
+    };
+
+    html! {
+        <>
+            <DropdownSelect
+                options={location_options(props.data)}
+                selected={s.location.clone()}
+                placeholder="Location"
+                on_select={on_location}
+            />
+            <input
+                class="input"
+                type="text"
+                inputmode="numeric"
+                pattern="[0-9]*"
+                placeholder="X"
+                title="Tile X coordinate of the spot"
+                style="width:4.5rem"
+                value={s.x_value.clone()}
+                oninput={on_x_input}
+            />
+            <input
+                class="input"
+                type="text"
+                inputmode="numeric"
+                pattern="[0-9]*"
+                placeholder="Y"
+                title="Tile Y coordinate of the spot"
+                style="width:4.5rem"
+                value={s.y_value.clone()}
+                oninput={on_y_input}
+            />
+            { item_section }
+            <button
+                class={ if s.saw_qi_plane { "button is-info is-light" } else { "button is-light" } }
+                title="Click if the player had seen Mr. Qi's plane (Mystery Boxes unlocked)"
+                onclick={on_toggle_plane}
+            >
+                { if s.saw_qi_plane { "Qi plane seen" } else { "No Qi plane" } }
+            </button>
+        </>
+    }
+}
@@ -0,0 +1,61 @@
+use yew::prelude::*;
+
+use crate::codegen::GameData;
+use crate::dropdown::DropdownSelect;
+use crate::sources::BooksellerObservation;
+
+/// Form state for a Bookseller observation.
+#[derive(Clone, PartialEq, Default)]
+pub struct BooksellerFormState {
+    pub present: Option<bool>,
+}
+
+impl BooksellerFormState {
+    pub fn build(&self) -> Option<BooksellerObservation> {
+        Some(BooksellerObservation {
+            present: self.present?,
+        })
+    }
+}
+
+fn presence_options() -> Vec<(String, String)> {
+    vec![
+        ("present".to_string(), "In town".to_string()),
+        ("absent".to_string(), "Not in town".to_string()),
+    ]
+}
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct BooksellerFormProps {
+    pub data: &'static GameData,
+    pub state: BooksellerFormState,
+    pub on_change: Callback<BooksellerFormState>,
+}
+
+#[component]
+pub fn BooksellerForm(props: &BooksellerFormProps) -> Html {
+    let s = &props.state;
+
+    let on_presence = {
+        let s = s.clone();
+        let on_change = props.on_change.clone();
+        Callback::from(move |key: String| {
+            let mut ns = s.clone();
+            ns.present = match key.as_str() {
+                "present" => Some(true),
+                "absent" => Some(false),
+                _ => None,
+            };
+            on_change.emit(ns);
+        })
+    };
+
+    html! {
+        <DropdownSelect
+            options={presence_options()}
+            selected={s.present.map(|p| if p { "present" } else { "absent" }.to_string())}
+            placeholder="Bookseller"
+            on_select={on_presence}
+        />
+    }
+}
@@ -0,0 +1,108 @@
+use yew::prelude::*;
+
+use crate::codegen::GameData;
+use crate::dropdown::DropdownSelect;
+use crate::multi_select::MultiSelect;
+use crate::remixed_bundles::{room_bundles, rooms};
+use crate::sources::BundleObservation;
+
+/// Form state for a remixed-bundles observation.
+#[derive(Clone, PartialEq, Default)]
+pub struct BundleFormState {
+    pub room: Option<String>,
+    /// Bundles seen in the room.
+    pub bundles: Vec<String>,
+}
+
+impl BundleFormState {
+    pub fn build(&self) -> Option<BundleObservation> {
+        if self.bundles.is_empty() {
+            return None;
+        }
+        Some(BundleObservation {
+            room: self.room.clone()?,
+            bundles: self.bundles.clone(),
+        })
+    }
+}
+
+fn room_options(data: &'static GameData) -> Vec<(String, String)> {
+    rooms(data).map(|room| (room.to_string(), room.to_string())).collect()
+}
+
+fn bundle_options(data: &'static GameData, room: &str) -> Vec<(String, String)> {
+    room_bundles(data, room)
+        .into_iter()
+        .map(|name| (name.to_string(), name.to_string()))
+        .collect()
+}
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct BundleFormProps {
+    pub data: &'static GameData,
+    pub state: BundleFormState,
+    pub on_change: Callback<BundleFormState>,
+}
+
+#[component]
+pub fn BundleForm(props: &BundleFormProps) -> Html {
+    let s = &props.state;
+    let on_change = props.on_change.clone();
+
+    if rooms(props.data).next().is_none() {
+        return html! {
+            <span class="tag is-warning is-light">
+                { "This build has no bundle data (assets/RandomBundles.json)" }
+            </span>
+        };
+    }
+
+    // ---- Room ----
+    let on_room = {
+        let s = s.clone();
+        let on_change = on_change.clone();
+        Callback::from(move |room: String| {
+            let mut ns = s.clone();
+            if ns.room.as_deref() != Some(room.as_str()) {
+                ns.bundles.clear();
+            }
+            ns.room = Some(room);
+            on_change.emit(ns);
+        })
+    };
+
+    // ---- Bundles ----
+    let on_bundles = {
+        let s = s.clone();
+        let on_change = on_change.clone();
+        Callback::from(move |bundles: Vec<String>| {
+            let mut ns = s.clone();
+            ns.bundles = bundles;
+            on_change.emit(ns);
+        })
+    };
+
+    let bundle_section: Html = match &s.room {
+        Some(room) => html! {
+            <MultiSelect
+                options={bundle_options(props.data, room)}
+                selected={s.bundles.clone()}
+                placeholder="Bundles"
+                on_change={on_bundles}
+            />
+        },
+        None => html! {},
+    };
+
+    html! {
+        <>
+            <DropdownSelect
+                options={room_options(props.data)}
+                selected={s.room.clone()}
+                placeholder="Room"
+                on_select={on_room}
+            />
+            { bundle_section }
+        </>
+    }
+}
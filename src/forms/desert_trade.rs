@@ -0,0 +1,142 @@
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+use crate::codegen::GameData;
+use crate::desert_trader::possible_offers;
+use crate::dropdown::DropdownSelect;
+use crate::sources::DesertTradeObservation;
+
+/// Form state for a Desert Trader observation.
+#[derive(Clone, PartialEq, Default)]
+pub struct DesertTradeFormState {
+    pub offered: Option<u32>,
+    /// Qualified item ID; `None` if the request was not noted.
+    pub requested: Option<String>,
+    pub amount_value: String,
+    /// `None` while the amount field is empty or invalid.
+    pub amount: Option<u32>,
+}
+
+impl DesertTradeFormState {
+    pub fn build(&self) -> Option<DesertTradeObservation> {
+        // A non-empty but invalid amount keeps the form incomplete.
+        if !self.amount_value.is_empty() && self.amount.is_none() {
+            return None;
+        }
+        Some(DesertTradeObservation {
+            offered: self.offered?,
+            requested: self.requested.clone(),
+            amount: self.amount,
+        })
+    }
+}
+
+fn offer_options(data: &'static GameData) -> Vec<(String, String)> {
+    let mut opts: Vec<(String, String)> = possible_offers(data)
+        .into_iter()
+        .filter_map(|id| {
+            data.objects
+                .get(&id)
+                .map(|obj| (id.to_string(), obj.name.to_string()))
+        })
+        .collect();
+    opts.sort_by(|a, b| a.1.cmp(&b.1));
+    opts
+}
+
+fn requested_options(data: &'static GameData) -> Vec<(String, String)> {
+    let mut opts = vec![(String::new(), "Not noted".to_string())];
+    opts.extend(data.objects_by_name.iter().filter_map(|&id| {
+        data.objects
+            .get(&id)
+            .map(|obj| (format!("(O){id}"), obj.name.to_string()))
+    }));
+    opts
+}
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct DesertTradeFormProps {
+    pub data: &'static GameData,
+    pub state: DesertTradeFormState,
+    pub on_change: Callback<DesertTradeFormState>,
+}
+
+#[component]
+pub fn DesertTradeForm(props: &DesertTradeFormProps) -> Html {
+    let s = &props.state;
+    let on_change = props.on_change.clone();
+
+    // ---- Offered item ----
+    let on_offered = {
+        let s = s.clone();
+        let on_change = on_change.clone();
+        Callback::from(move |key: String| {
+            let mut ns = s.clone();
+            ns.offered = key.parse::<u32>().ok();
+            on_change.emit(ns);
+        })
+    };
+
+    // ---- Requested item ----
+    let on_requested = {
+        let s = s.clone();
+        let on_change = on_change.clone();
+        Callback::from(move |key: String| {
+            let mut ns = s.clone();
+            ns.requested = Some(key).filter(|key| !key.is_empty());
+            on_change.emit(ns);
+        })
+    };
+
+    // ---- Amount ----
+    let on_amount_input = {
+        let s = s.clone();
+        let on_change = on_change.clone();
+        Callback::from(move |e: InputEvent| {
+            let v = e.target_unchecked_into::<HtmlInputElement>().value();
+            let mut ns = s.clone();
+            ns.amount = v.parse::<u32>().ok().filter(|&a| a >= 1);
+            ns.amount_value = v;
+            on_change.emit(ns);
+        })
+    };
+
+    // The request can only be checked with `Data/Shops`.
+    let request_section: Html = if props.data.desert_trade_request.is_some() {
+        html! {
+            <>
+                <DropdownSelect
+                    options={requested_options(props.data)}
+                    selected={Some(s.requested.clone().unwrap_or_default())}
+                    placeholder="Asks for"
+                    on_select={on_requested}
+                />
+                <input
+                    class="input"
+                    type="text"
+                    inputmode="numeric"
+                    pattern="[0-9]*"
+                    placeholder="Amount"
+                    title="How many she asks for (leave empty if unknown)"
+                    style="width:4.5rem"
+                    value={s.amount_value.clone()}
+                    oninput={on_amount_input}
+                />
+            </>
+        }
+    } else {
+        html! {}
+    };
+
+    html! {
+        <>
+            <DropdownSelect
+                options={offer_options(props.data)}
+                selected={s.offered.map(|id| id.to_string())}
+                placeholder="Offered"
+                on_select={on_offered}
+            />
+            { request_section }
+        </>
+    }
+}
@@ -0,0 +1,184 @@
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+use crate::codegen::GameData;
+use crate::dropdown::DropdownSelect;
+use crate::garbage_cans::{can_label, possible_loot, GarbageState};
+use crate::geodes::qualified_item_label;
+use crate::sources::GarbageCanObservation;
+
+/// Key of the "nothing" choice in the item picker.
+const NOTHING: &str = "none";
+
+/// Form state for a garbage can observation.
+#[derive(Clone, PartialEq, Default)]
+pub struct GarbageCanFormState {
+    pub can: Option<String>,
+    /// `None` until picked; `Some(None)` for an empty can.
+    pub item: Option<Option<String>>,
+    pub state: GarbageState,
+    pub steps_value: String,
+}
+
+impl GarbageCanFormState {
+    pub fn build(&self) -> Option<GarbageCanObservation> {
+        // A non-empty but invalid step count keeps the form incomplete.
+        if !self.steps_value.is_empty() && self.state.steps_taken.is_none() {
+            return None;
+        }
+        Some(GarbageCanObservation {
+            can: self.can.clone()?,
+            item: self.item.clone()?,
+            state: self.state,
+        })
+    }
+}
+
+fn can_options(data: &'static GameData) -> Vec<(String, String)> {
+    data.garbage_cans
+        .iter()
+        .map(|can| (can.id.to_string(), can_label(can.id).to_string()))
+        .collect()
+}
+
+fn item_options(data: &'static GameData, can: &str) -> Vec<(String, String)> {
+    // Item queries are resolved at runtime and cannot be picked.
+    let mut opts: Vec<(String, String)> = possible_loot(data, can)
+        .into_iter()
+        .filter(|item| item.starts_with('('))
+        .map(|item| {
+            let label = qualified_item_label(data, &item);
+            (item, label)
+        })
+        .collect();
+    opts.sort_by(|a, b| a.1.cmp(&b.1));
+    opts.insert(0, (NOTHING.to_string(), "Nothing".to_string()));
+    opts
+}
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct GarbageCanFormProps {
+    pub data: &'static GameData,
+    pub state: GarbageCanFormState,
+    pub on_change: Callback<GarbageCanFormState>,
+}
+
+#[component]
+pub fn GarbageCanForm(props: &GarbageCanFormProps) -> Html {
+    let s = &props.state;
+    let on_change = props.on_change.clone();
+
+    if props.data.garbage_cans.is_empty() {
+        return html! {
+            <span class="tag is-warning is-light">
+                { "This build has no garbage can data (assets/GarbageCans.json)" }
+            </span>
+        };
+    }
+
+    // ---- Can ----
+    let on_can = {
+        let s = s.clone();
+        let on_change = on_change.clone();
+        Callback::from(move |key: String| {
+            let mut ns = s.clone();
+            if ns.can.as_deref() != Some(key.as_str()) {
+                ns.item = None;
+            }
+            ns.can = Some(key);
+            on_change.emit(ns);
+        })
+    };
+
+    // ---- Item ----
+    let on_item = {
+        let s = s.clone();
+        let on_change = on_change.clone();
+        Callback::from(move |key: String| {
+            let mut ns = s.clone();
+            ns.item = Some((key != NOTHING).then_some(key));
+            on_change.emit(ns);
+        })
+    };
+
+    // ---- Save state ----
+    let on_toggle_book = {
+        let s = s.clone();
+        let on_change = on_change.clone();
+        Callback::from(move |_: MouseEvent| {
+            let mut ns = s.clone();
+            ns.state.read_trash_book = !ns.state.read_trash_book;
+            on_change.emit(ns);
+        })
+    };
+    let on_toggle_plane = {
+        let s = s.clone();
+        let on_change = on_change.clone();
+        Callback::from(move |_: MouseEvent| {
+            let mut ns = s.clone();
+            ns.state.saw_qi_plane = !ns.state.saw_qi_plane;
+            on_change.emit(ns);
+        })
+    };
+
+    let on_steps_input = {
+        let s = s.clone();
+        let on_change = on_change.clone();
+        Callback::from(move |e: InputEvent| {
+            let v = e.target_unchecked_into::<HtmlInputElement>().value();
+            let mut ns = s.clone();
+            ns.state.steps_taken = v.parse::<u32>().ok();
+            ns.steps_value = v;
+            on_change.emit(ns);
+        })
+    };
+
+    let item_section: Html = match &s.can {
+        Some(can) => html! {
+            <DropdownSelect
+                options={item_options(props.data, can)}
+                selected={s.item.clone().map(|item| item.unwrap_or_else(|| NOTHING.to_string()))}
+                placeholder="Found"
+                on_select={on_item}
+            />
+        },
+        None => html! {},
+    };
+
+    html! {
+        <>
+            <DropdownSelect
+                options={can_options(props.data)}
+                selected={s.can.clone()}
+                placeholder="Can"
+                on_select={on_can}
+            />
+            { item_section }
+            <button
+                class={ if s.state.read_trash_book { "button is-info is-light" } else { "button is-light" } }
+                title="Click if the player had read The Alleyway Buffet"
+                onclick={on_toggle_book}
+            >
+                { if s.state.read_trash_book { "Read Alleyway Buffet" } else { "No Alleyway Buffet" } }
+            </button>
+            <button
+                class={ if s.state.saw_qi_plane { "button is-info is-light" } else { "button is-light" } }
+                title="Click if the player had seen Mr. Qi's plane (Mystery Boxes unlocked)"
+                onclick={on_toggle_plane}
+            >
+                { if s.state.saw_qi_plane { "Qi plane seen" } else { "No Qi plane" } }
+            </button>
+            <input
+                class="input"
+                type="text"
+                inputmode="numeric"
+                pattern="[0-9]*"
+                placeholder="Steps"
+                title="Steps taken at the start of the day (stepsTaken in the save); leave empty if unknown"
+                style="width:6rem"
+                value={s.steps_value.clone()}
+                oninput={on_steps_input}
+            />
+        </>
+    }
+}
@@ -0,0 +1,129 @@
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+use crate::codegen::GameData;
+use crate::dropdown::DropdownSelect;
+use crate::geodes::{geodes, possible_treasures, qualified_item_label};
+use crate::sources::GeodeObservation;
+
+/// Form state for a geode observation.
+#[derive(Clone, PartialEq, Default)]
+pub struct GeodeFormState {
+    pub geode: Option<String>,
+    pub index_value: String,
+    /// `None` while the index field is empty or invalid.
+    pub index: Option<u32>,
+    pub item: Option<String>,
+}
+
+impl GeodeFormState {
+    pub fn build(&self) -> Option<GeodeObservation> {
+        Some(GeodeObservation {
+            geode: self.geode.clone()?,
+            index: self.index?,
+            item: self.item.clone()?,
+        })
+    }
+}
+
+fn geode_options(data: &'static GameData) -> Vec<(String, String)> {
+    geodes(data)
+        .iter()
+        .map(|geode| (geode.id.to_string(), geode.name.to_string()))
+        .collect()
+}
+
+fn item_options(data: &'static GameData, geode: &str) -> Vec<(String, String)> {
+    let mut opts: Vec<(String, String)> = possible_treasures(data, geode)
+        .into_iter()
+        .map(|item| (item.to_string(), qualified_item_label(data, item)))
+        .collect();
+    opts.sort_by(|a, b| a.1.cmp(&b.1));
+    opts
+}
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct GeodeFormProps {
+    pub data: &'static GameData,
+    pub state: GeodeFormState,
+    pub on_change: Callback<GeodeFormState>,
+}
+
+#[component]
+pub fn GeodeForm(props: &GeodeFormProps) -> Html {
+    let s = &props.state;
+    let on_change = props.on_change.clone();
+
+    // ---- Geode ----
+    let on_geode = {
+        let s = s.clone();
+        let on_change = on_change.clone();
+        Callback::from(move |key: String| {
+            let mut ns = s.clone();
+            if ns.geode.as_deref() != Some(key.as_str()) {
+                ns.item = None;
+            }
+            ns.geode = Some(key);
+            on_change.emit(ns);
+        })
+    };
+
+    // ---- Index ----
+    let on_index_input = {
+        let s = s.clone();
+        let on_change = on_change.clone();
+        Callback::from(move |e: InputEvent| {
+            let v = e.target_unchecked_into::<HtmlInputElement>().value();
+            let mut ns = s.clone();
+            ns.index = v.parse::<u32>().ok().filter(|&i| i >= 1);
+            ns.index_value = v;
+            on_change.emit(ns);
+        })
+    };
+
+    // ---- Item ----
+    let on_item = {
+        let s = s.clone();
+        let on_change = on_change.clone();
+        Callback::from(move |key: String| {
+            let mut ns = s.clone();
+            ns.item = Some(key);
+            on_change.emit(ns);
+        })
+    };
+
+    let item_section: Html = match &s.geode {
+        Some(geode) => html! {
+            <DropdownSelect
+                options={item_options(props.data, geode)}
+                selected={s.item.clone()}
+                placeholder="Item"
+                on_select={on_item}
+            />
+        },
+        None => html! {},
+    };
+
+    html! {
+        <>
+            <DropdownSelect
+                options={geode_options(props.data)}
+                selected={s.geode.clone()}
+                placeholder="Geode"
+                on_select={on_geode}
+            />
+            <input
+                class="input"
+                type="text"
+                inputmode="numeric"
+                pattern="[0-9]*"
+                placeholder="#"
+                title="Which geode this was, counting every geode cracked on the save from 1"
+                style="width:4.5rem"
+                value={s.index_value.clone()}
+                oninput={on_index_input}
+            />
+            { item_section }
+        </>
+    }
+}
@@ -0,0 +1,113 @@
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+use crate::codegen::GameData;
+use crate::dropdown::DropdownSelect;
+use crate::mines::{MineFeature, MINE_FLOORS};
+use crate::sources::MineFloorObservation;
+
+/// Form state for a Mines floor observation.
+#[derive(Clone, PartialEq, Default)]
+pub struct MineFloorFormState {
+    pub floor_value: String,
+    /// `None` while the floor field is empty or invalid.
+    pub floor: Option<u32>,
+    pub feature: Option<MineFeature>,
+    pub quarry_unlocked: bool,
+}
+
+impl MineFloorFormState {
+    pub fn build(&self) -> Option<MineFloorObservation> {
+        Some(MineFloorObservation {
+            floor: self.floor?,
+            feature: self.feature?,
+            quarry_unlocked: self.quarry_unlocked,
+        })
+    }
+}
+
+fn feature_options() -> Vec<(String, String)> {
+    MineFeature::ALL
+        .iter()
+        .map(|feature| (feature.key().to_string(), feature.label().to_string()))
+        .collect()
+}
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct MineFloorFormProps {
+    pub data: &'static GameData,
+    pub state: MineFloorFormState,
+    pub on_change: Callback<MineFloorFormState>,
+}
+
+#[component]
+pub fn MineFloorForm(props: &MineFloorFormProps) -> Html {
+    let s = &props.state;
+    let on_change = props.on_change.clone();
+
+    // ---- Floor ----
+    let on_floor_input = {
+        let s = s.clone();
+        let on_change = on_change.clone();
+        Callback::from(move |e: InputEvent| {
+            let v = e.target_unchecked_into::<HtmlInputElement>().value();
+            let mut ns = s.clone();
+            ns.floor = v
+                .parse::<u32>()
+                .ok()
+                .filter(|f| (1..=MINE_FLOORS).contains(f));
+            ns.floor_value = v;
+            on_change.emit(ns);
+        })
+    };
+
+    // ---- Feature ----
+    let on_feature = {
+        let s = s.clone();
+        let on_change = on_change.clone();
+        Callback::from(move |key: String| {
+            let mut ns = s.clone();
+            ns.feature = MineFeature::from_key(&key);
+            on_change.emit(ns);
+        })
+    };
+
+    let on_toggle_quarry = {
+        let s = s.clone();
+        let on_change = on_change.clone();
+        Callback::from(move |_: MouseEvent| {
+            let mut ns = s.clone();
+            ns.quarry_unlocked = !ns.quarry_unlocked;
+            on_change.emit(ns);
+        })
+    };
+
+    html! {
+        <>
+            <input
+                class="input"
+                type="text"
+                inputmode="numeric"
+                pattern="[0-9]*"
+                placeholder="Floor"
+                title="Mines floor, 1–119"
+                style="width:4.5rem"
+                value={s.floor_value.clone()}
+                oninput={on_floor_input}
+            />
+            <DropdownSelect
+                options={feature_options()}
+                selected={s.feature.map(|f| f.key().to_string())}
+                placeholder="Floor type"
+                on_select={on_feature}
+            />
+            <button
+                class={ if s.quarry_unlocked { "button is-info is-light" } else { "button is-light" } }
+                title="Click if the save can get quarry floors (Crafts Room done and the Quarry Mine visited)"
+                onclick={on_toggle_quarry}
+            >
+                { if s.quarry_unlocked { "Quarry floors on" } else { "No quarry floors" } }
+            </button>
+        </>
+    }
+}
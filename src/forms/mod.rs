@@ -0,0 +1,158 @@
+//! UI forms for observation sources, mirroring the registry in `sources`.
+//!
+//! Each form module provides a state type with `build()` returning its source's
+//! observation, and a component editing that state. List it in `source_forms!`
+//! below to make it selectable in an observation row. Kinds that are not tied
+//! to a day (`dated: false`) hide the row's date fields.
+
+use yew::prelude::*;
+
+use crate::codegen::GameData;
+use crate::sources::SourceObservation;
+
+pub mod artifact_spot;
+pub mod bookseller;
+pub mod bundles;
+pub mod desert_trade;
+pub mod garbage_can;
+pub mod geode;
+pub mod mine_floor;
+pub mod mystery_box;
+pub mod night_event;
+pub mod quest;
+pub mod secret_gift;
+pub mod special_orders;
+pub mod trash_bear;
+pub mod weather;
+
+use artifact_spot::{ArtifactSpotForm, ArtifactSpotFormState};
+use bookseller::{BooksellerForm, BooksellerFormState};
+use bundles::{BundleForm, BundleFormState};
+use desert_trade::{DesertTradeForm, DesertTradeFormState};
+use garbage_can::{GarbageCanForm, GarbageCanFormState};
+use geode::{GeodeForm, GeodeFormState};
+use mine_floor::{MineFloorForm, MineFloorFormState};
+use mystery_box::{MysteryBoxForm, MysteryBoxFormState};
+use night_event::{NightEventForm, NightEventFormState};
+use quest::{QuestForm, QuestFormState};
+use secret_gift::{SecretGiftForm, SecretGiftFormState};
+use special_orders::{SpecialOrdersForm, SpecialOrdersFormState};
+use trash_bear::{TrashBearForm, TrashBearFormState};
+use weather::{WeatherForm, WeatherFormState};
+
+/// Declares the form registry: `SourceKind` (what the row's kind picker offers),
+/// `FormState` (the per-kind state a row keeps) and the `SourceForm` component.
+macro_rules! source_forms {
+    ($($variant:ident($state:ty, $form:ident, $key:literal, $label:literal, dated: $dated:literal)),* $(,)?) => {
+        #[derive(Clone, Copy, PartialEq)]
+        pub enum SourceKind {
+            $($variant,)*
+        }
+
+        impl SourceKind {
+            pub const ALL: &'static [SourceKind] = &[$(SourceKind::$variant,)*];
+
+            pub fn key(self) -> &'static str {
+                match self {
+                    $(SourceKind::$variant => $key,)*
+                }
+            }
+
+            pub fn from_key(s: &str) -> Option<Self> {
+                match s {
+                    $($key => Some(SourceKind::$variant),)*
+                    _ => None,
+                }
+            }
+
+            pub fn label(self) -> &'static str {
+                match self {
+                    $(SourceKind::$variant => $label,)*
+                }
+            }
+
+            /// False if the observation is the same whatever day it was made.
+            pub fn dated(self) -> bool {
+                match self {
+                    $(SourceKind::$variant => $dated,)*
+                }
+            }
+        }
+
+        /// The form state of one row, for whichever kind it observes.
+        #[derive(Clone, PartialEq)]
+        pub enum FormState {
+            $($variant($state),)*
+        }
+
+        impl FormState {
+            /// An empty form of the given kind.
+            pub fn new(kind: SourceKind) -> Self {
+                match kind {
+                    $(SourceKind::$variant => FormState::$variant(<$state>::default()),)*
+                }
+            }
+
+            pub fn kind(&self) -> SourceKind {
+                match self {
+                    $(FormState::$variant(_) => SourceKind::$variant,)*
+                }
+            }
+
+            /// The observation, or `None` while the form is incomplete.
+            pub fn build(&self) -> Option<SourceObservation> {
+                match self {
+                    $(FormState::$variant(state) => state.build().map(Into::into),)*
+                }
+            }
+        }
+
+        #[derive(Clone, PartialEq, Properties)]
+        pub struct SourceFormProps {
+            pub data: &'static GameData,
+            pub state: FormState,
+            pub on_change: Callback<FormState>,
+        }
+
+        /// Renders the form matching the state's kind.
+        #[component]
+        pub fn SourceForm(props: &SourceFormProps) -> Html {
+            match &props.state {
+                $(FormState::$variant(state) => {
+                    let on_change = props.on_change.reform(FormState::$variant);
+                    html! { <$form data={props.data} state={state.clone()} {on_change} /> }
+                })*
+            }
+        }
+    };
+}
+
+source_forms! {
+    Quest(QuestFormState, QuestForm, "quest", "Quest board", dated: true),
+    Bundles(BundleFormState, BundleForm, "bundles", "Remixed bundles", dated: false),
+    SecretGift(SecretGiftFormState, SecretGiftForm, "secret-gift", "Winter Star gift", dated: true),
+    Weather(WeatherFormState, WeatherForm, "weather", "Weather", dated: true),
+    NightEvent(NightEventFormState, NightEventForm, "night-event", "Overnight event", dated: true),
+    Geode(GeodeFormState, GeodeForm, "geode", "Geode", dated: false),
+    MineFloor(MineFloorFormState, MineFloorForm, "mine-floor", "Mines floor", dated: true),
+    SpecialOrders(SpecialOrdersFormState, SpecialOrdersForm, "special-orders", "Special Orders", dated: true),
+    DesertTrade(DesertTradeFormState, DesertTradeForm, "desert-trade", "Desert Trader", dated: true),
+    Bookseller(BooksellerFormState, BooksellerForm, "bookseller", "Bookseller", dated: true),
+    ArtifactSpot(ArtifactSpotFormState, ArtifactSpotForm, "artifact-spot", "Artifact spot", dated: true),
+    MysteryBox(MysteryBoxFormState, MysteryBoxForm, "mystery-box", "Mystery Box", dated: false),
+    GarbageCan(GarbageCanFormState, GarbageCanForm, "garbage-can", "Garbage can", dated: true),
+    TrashBear(TrashBearFormState, TrashBearForm, "trash-bear", "Trash Bear", dated: true),
+}
+
+impl Default for FormState {
+    fn default() -> Self {
+        FormState::new(SourceKind::Quest)
+    }
+}
+
+pub fn source_kind_options() -> Vec<(String, String)> {
+    SourceKind::ALL
+        .iter()
+        .map(|kind| (kind.key().to_string(), kind.label().to_string()))
+        .collect()
+}
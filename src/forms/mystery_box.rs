@@ -0,0 +1,135 @@
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+use crate::codegen::GameData;
+use crate::dropdown::DropdownSelect;
+use crate::geodes::qualified_item_label;
+use crate::mystery_boxes::{box_name, possible_rewards};
+use crate::sources::MysteryBoxObservation;
+
+/// Form state for a Mystery Box observation.
+#[derive(Clone, PartialEq, Default)]
+pub struct MysteryBoxFormState {
+    /// `None` until the kind of box is picked.
+    pub golden: Option<bool>,
+    pub index_value: String,
+    /// `None` while the index field is empty or invalid.
+    pub index: Option<u32>,
+    pub item: Option<String>,
+}
+
+impl MysteryBoxFormState {
+    pub fn build(&self) -> Option<MysteryBoxObservation> {
+        Some(MysteryBoxObservation {
+            golden: self.golden?,
+            index: self.index?,
+            item: self.item.clone()?,
+        })
+    }
+}
+
+fn kind_options() -> Vec<(String, String)> {
+    [false, true]
+        .into_iter()
+        .map(|golden| (golden.to_string(), box_name(golden).to_string()))
+        .collect()
+}
+
+fn item_options(data: &'static GameData, golden: bool) -> Vec<(String, String)> {
+    let mut opts: Vec<(String, String)> = possible_rewards(golden)
+        .into_iter()
+        .map(|item| {
+            let label = qualified_item_label(data, &item);
+            (item, label)
+        })
+        .collect();
+    opts.sort_by(|a, b| a.1.cmp(&b.1));
+    opts
+}
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct MysteryBoxFormProps {
+    pub data: &'static GameData,
+    pub state: MysteryBoxFormState,
+    pub on_change: Callback<MysteryBoxFormState>,
+}
+
+#[component]
+pub fn MysteryBoxForm(props: &MysteryBoxFormProps) -> Html {
+    let s = &props.state;
+    let on_change = props.on_change.clone();
+
+    // ---- Kind ----
+    let on_kind = {
+        let s = s.clone();
+        let on_change = on_change.clone();
+        Callback::from(move |key: String| {
+            let mut ns = s.clone();
+            let golden = key == "true";
+            if ns.golden != Some(golden) {
+                ns.item = None;
+            }
+            ns.golden = Some(golden);
+            on_change.emit(ns);
+        })
+    };
+
+    // ---- Index ----
+    let on_index_input = {
+        let s = s.clone();
+        let on_change = on_change.clone();
+        Callback::from(move |e: InputEvent| {
+            let v = e.target_unchecked_into::<HtmlInputElement>().value();
+            let mut ns = s.clone();
+            ns.index = v.parse::<u32>().ok().filter(|&i| i >= 1);
+            ns.index_value = v;
+            on_change.emit(ns);
+        })
+    };
+
+    // ---- Item ----
+    let on_item = {
+        let s = s.clone();
+        let on_change = on_change.clone();
+        Callback::from(move |key: String| {
+            let mut ns = s.clone();
+            ns.item = Some(key);
+            on_change.emit(ns);
+        })
+    };
+
+    let item_section: Html = match s.golden {
+        Some(golden) => html! {
+            <DropdownSelect
+                options={item_options(props.data, golden)}
+                selected={s.item.clone()}
+                placeholder="Item"
+                on_select={on_item}
+            />
+        },
+        None => html! {},
+    };
+
+    html! {
+        <>
+            <DropdownSelect
+                options={kind_options()}
+                selected={s.golden.map(|golden| golden.to_string())}
+                placeholder="Box"
+                on_select={on_kind}
+            />
+            <input
+                class="input"
+                type="text"
+                inputmode="numeric"
+                pattern="[0-9]*"
+                placeholder="#"
+                title="Which box this was, counting every Mystery Box and Golden Mystery Box opened on the save from 1"
+                style="width:4.5rem"
+                value={s.index_value.clone()}
+                oninput={on_index_input}
+            />
+            { item_section }
+        </>
+    }
+}
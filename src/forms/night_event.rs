@@ -0,0 +1,79 @@
+use yew::prelude::*;
+
+use crate::codegen::GameData;
+use crate::dropdown::DropdownSelect;
+use crate::night_events::NightEvent;
+use crate::sources::NightEventObservation;
+
+/// Form state for an overnight-event observation.
+#[derive(Clone, PartialEq, Default)]
+pub struct NightEventFormState {
+    pub event: Option<NightEvent>,
+    pub capsule_seen: bool,
+}
+
+impl NightEventFormState {
+    pub fn build(&self) -> Option<NightEventObservation> {
+        Some(NightEventObservation {
+            event: self.event?,
+            capsule_seen: self.capsule_seen,
+        })
+    }
+}
+
+fn event_options() -> Vec<(String, String)> {
+    NightEvent::ALL
+        .iter()
+        .map(|event| (event.key().to_string(), event.label().to_string()))
+        .collect()
+}
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct NightEventFormProps {
+    pub data: &'static GameData,
+    pub state: NightEventFormState,
+    pub on_change: Callback<NightEventFormState>,
+}
+
+#[component]
+pub fn NightEventForm(props: &NightEventFormProps) -> Html {
+    let s = &props.state;
+
+    let on_event = {
+        let s = s.clone();
+        let on_change = props.on_change.clone();
+        Callback::from(move |key: String| {
+            let mut ns = s.clone();
+            ns.event = NightEvent::from_key(&key);
+            on_change.emit(ns);
+        })
+    };
+
+    let on_toggle_capsule = {
+        let s = s.clone();
+        let on_change = props.on_change.clone();
+        Callback::from(move |_: MouseEvent| {
+            let mut ns = s.clone();
+            ns.capsule_seen = !ns.capsule_seen;
+            on_change.emit(ns);
+        })
+    };
+
+    html! {
+        <>
+            <DropdownSelect
+                options={event_options()}
+                selected={s.event.map(|e| e.key().to_string())}
+                placeholder="Overnight"
+                on_select={on_event}
+            />
+            <button
+                class={ if s.capsule_seen { "button is-info is-light" } else { "button is-light" } }
+                title="Click if the strange capsule had already landed before this night"
+                onclick={on_toggle_capsule}
+            >
+                { if s.capsule_seen { "Capsule seen" } else { "No capsule yet" } }
+            </button>
+        </>
+    }
+}
@@ -0,0 +1,321 @@
+use yew::prelude::*;
+
+use crate::codegen::GameData;
+use crate::multi_select::MultiSelect;
+use crate::npcs::npc_label;
+use crate::observation::{FishingContent, FishingNpc, QuestContent, ResourceContent};
+use crate::sources::QuestObservation;
+
+// All fish IDs that appear in any season/NPC pool.
+const FISH_IDS: &[u32] = &[
+    128, 129, 130, 131, 136, 137, 138, 139, 141, 142, 143, 144, 145, 146, 147, 149, 150, 151, 699,
+    702, 705,
+];
+
+// Ore items requested by Clint: "– [X]g on delivery  – Keep the ores after Clint inspects them."
+const ORE_ITEMS: &[(u32, &str)] = &[
+    (378, "Copper Ore"),
+    (380, "Iron Ore"),
+    (382, "Coal"),
+    (384, "Gold Ore"),
+];
+
+// Wood/stone items requested by Robin: "– [X]g on delivery  (only)"
+const WOOD_STONE_ITEMS: &[(u32, &str)] = &[(388, "Wood"), (390, "Stone")];
+
+/// UI-facing quest type — named after the in-game bullet-point patterns the
+/// user sees on the notice board, not the internal quest class names.
+#[derive(Clone, Copy, PartialEq)]
+pub enum QuestTypeUI {
+    NoQuest,
+    Fishing,
+    MonsterHunt,
+    OreGathering,
+    WoodStoneGathering,
+    ItemDelivery,
+    GreetEveryone,
+}
+
+impl QuestTypeUI {
+    fn label(self) -> &'static str {
+        match self {
+            QuestTypeUI::NoQuest => "No quest",
+            QuestTypeUI::Fishing => "- [..]g reward\n- You get to keep the fish.",
+            QuestTypeUI::MonsterHunt => "- [...]g reward.",
+            QuestTypeUI::OreGathering => "- [...]g on delivery.\n- You can keep the ores [...].",
+            QuestTypeUI::WoodStoneGathering => "- [...]g on delivery.",
+            QuestTypeUI::ItemDelivery => "- [...]g on delivery.\n- [...] happy/thankful/pleased.",
+            QuestTypeUI::GreetEveryone => "- Everyone will like you a little more.",
+        }
+    }
+
+    fn key(self) -> &'static str {
+        match self {
+            QuestTypeUI::NoQuest => "no-quest",
+            QuestTypeUI::Fishing => "fishing",
+            QuestTypeUI::MonsterHunt => "monster-hunt",
+            QuestTypeUI::OreGathering => "ore-gathering",
+            QuestTypeUI::WoodStoneGathering => "wood-stone",
+            QuestTypeUI::ItemDelivery => "item-delivery",
+            QuestTypeUI::GreetEveryone => "greet-everyone",
+        }
+    }
+
+    fn from_key(s: &str) -> Option<Self> {
+        match s {
+            "no-quest" => Some(QuestTypeUI::NoQuest),
+            "fishing" => Some(QuestTypeUI::Fishing),
+            "monster-hunt" => Some(QuestTypeUI::MonsterHunt),
+            "ore-gathering" => Some(QuestTypeUI::OreGathering),
+            "wood-stone" => Some(QuestTypeUI::WoodStoneGathering),
+            "item-delivery" => Some(QuestTypeUI::ItemDelivery),
+            "greet-everyone" => Some(QuestTypeUI::GreetEveryone),
+            _ => None,
+        }
+    }
+}
+
+/// Form state for a notice-board quest observation.
+#[derive(Clone, PartialEq, Default)]
+pub struct QuestFormState {
+    /// Every quest type the user thinks they may have seen.
+    pub quest_types: Vec<QuestTypeUI>,
+    pub fish_npcs: Vec<FishingNpc>,
+    pub fish_ids: Vec<u32>,
+    /// Shared by ore and wood/stone gathering — both are ResourceCollection quests.
+    pub resource_item_ids: Vec<u32>,
+}
+
+impl QuestFormState {
+    pub fn build(&self) -> Option<QuestObservation> {
+        if self.quest_types.is_empty() {
+            return None;
+        }
+
+        let mut quest_contents: Vec<QuestContent> = Vec::new();
+        let mut has_resource = false;
+        for quest_type in &self.quest_types {
+            match quest_type {
+                QuestTypeUI::NoQuest => quest_contents.push(QuestContent::None),
+                QuestTypeUI::ItemDelivery => quest_contents.push(QuestContent::ItemDelivery),
+                QuestTypeUI::GreetEveryone => quest_contents.push(QuestContent::Socialize),
+                QuestTypeUI::MonsterHunt => quest_contents.push(QuestContent::SlayMonster),
+                QuestTypeUI::Fishing => {
+                    if self.fish_npcs.is_empty() || self.fish_ids.is_empty() {
+                        return None;
+                    }
+                    quest_contents.push(QuestContent::Fishing(FishingContent {
+                        npcs: self.fish_npcs.clone(),
+                        fish_ids: self.fish_ids.clone(),
+                    }));
+                }
+                QuestTypeUI::OreGathering | QuestTypeUI::WoodStoneGathering => {
+                    // Ore and wood/stone share one picker and one ResourceCollection content.
+                    if self.resource_item_ids.is_empty() {
+                        return None;
+                    }
+                    if !has_resource {
+                        has_resource = true;
+                        quest_contents.push(QuestContent::ResourceCollection(ResourceContent {
+                            item_ids: self.resource_item_ids.clone(),
+                        }));
+                    }
+                }
+            }
+        }
+
+        Some(QuestObservation { quest_contents })
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Static option lists
+// ---------------------------------------------------------------------------
+
+fn quest_type_options() -> Vec<(String, String)> {
+    [
+        QuestTypeUI::NoQuest,
+        QuestTypeUI::Fishing,
+        QuestTypeUI::OreGathering,
+        QuestTypeUI::ItemDelivery,
+        QuestTypeUI::WoodStoneGathering,
+        QuestTypeUI::MonsterHunt,
+        QuestTypeUI::GreetEveryone,
+    ]
+    .iter()
+    .map(|qt| (qt.key().to_string(), qt.label().to_string()))
+    .collect()
+}
+
+fn fish_npc_options(data: &'static GameData) -> Vec<(String, String)> {
+    [FishingNpc::Demetrius, FishingNpc::Willy]
+        .iter()
+        .map(|npc| (npc.key().to_string(), npc_label(data, npc.name()).to_string()))
+        .collect()
+}
+
+fn fish_options(data: &'static GameData) -> Vec<(String, String)> {
+    let mut opts: Vec<(String, String)> = FISH_IDS
+        .iter()
+        .filter_map(|&id| {
+            data.objects
+                .get(&id)
+                .map(|obj| (id.to_string(), obj.name.to_string()))
+        })
+        .collect();
+    opts.sort_by(|a, b| a.1.cmp(&b.1));
+    opts
+}
+
+/// Resource items offered by whichever gathering quest types are selected.
+fn resource_items(quest_types: &[QuestTypeUI]) -> Vec<(u32, &'static str)> {
+    let mut items: Vec<(u32, &'static str)> = Vec::new();
+    if quest_types.contains(&QuestTypeUI::OreGathering) {
+        items.extend_from_slice(ORE_ITEMS);
+    }
+    if quest_types.contains(&QuestTypeUI::WoodStoneGathering) {
+        items.extend_from_slice(WOOD_STONE_ITEMS);
+    }
+    items
+}
+
+fn resource_options(quest_types: &[QuestTypeUI]) -> Vec<(String, String)> {
+    resource_items(quest_types)
+        .iter()
+        .map(|&(id, label)| (id.to_string(), label.to_string()))
+        .collect()
+}
+
+fn resource_placeholder(quest_types: &[QuestTypeUI]) -> &'static str {
+    match (
+        quest_types.contains(&QuestTypeUI::OreGathering),
+        quest_types.contains(&QuestTypeUI::WoodStoneGathering),
+    ) {
+        (true, false) => "Ore",
+        (false, true) => "Material",
+        _ => "Resource",
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Component
+// ---------------------------------------------------------------------------
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct QuestFormProps {
+    pub data: &'static GameData,
+    pub state: QuestFormState,
+    pub on_change: Callback<QuestFormState>,
+}
+
+#[component]
+pub fn QuestForm(props: &QuestFormProps) -> Html {
+    let s = &props.state;
+    let on_change = props.on_change.clone();
+
+    // ---- Quest types ----
+    let on_quest_types = {
+        let s = s.clone();
+        let on_change = on_change.clone();
+        Callback::from(move |keys: Vec<String>| {
+            let mut ns = s.clone();
+            ns.quest_types = keys
+                .iter()
+                .filter_map(|k| QuestTypeUI::from_key(k))
+                .collect();
+            // Drop content that no selected quest type can produce any more.
+            if !ns.quest_types.contains(&QuestTypeUI::Fishing) {
+                ns.fish_npcs.clear();
+                ns.fish_ids.clear();
+            }
+            let allowed = resource_items(&ns.quest_types);
+            ns.resource_item_ids
+                .retain(|id| allowed.iter().any(|&(allowed_id, _)| allowed_id == *id));
+            on_change.emit(ns);
+        })
+    };
+
+    // ---- Fish NPCs ----
+    let on_fish_npcs = {
+        let s = s.clone();
+        let on_change = on_change.clone();
+        Callback::from(move |keys: Vec<String>| {
+            let mut ns = s.clone();
+            ns.fish_npcs = keys
+                .iter()
+                .filter_map(|k| FishingNpc::from_key(k))
+                .collect();
+            on_change.emit(ns);
+        })
+    };
+
+    // ---- Fish ----
+    let on_fish = {
+        let s = s.clone();
+        let on_change = on_change.clone();
+        Callback::from(move |keys: Vec<String>| {
+            let mut ns = s.clone();
+            ns.fish_ids = keys.iter().filter_map(|k| k.parse::<u32>().ok()).collect();
+            on_change.emit(ns);
+        })
+    };
+
+    // ---- Resource items ----
+    let on_resource = {
+        let s = s.clone();
+        let on_change = on_change.clone();
+        Callback::from(move |keys: Vec<String>| {
+            let mut ns = s.clone();
+            ns.resource_item_ids = keys.iter().filter_map(|k| k.parse::<u32>().ok()).collect();
+            on_change.emit(ns);
+        })
+    };
+
+    // ---- Content section (quest-type-specific fields) ----
+    let fishing_section: Html = if s.quest_types.contains(&QuestTypeUI::Fishing) {
+        html! {
+            <>
+                <MultiSelect
+                    options={fish_npc_options(props.data)}
+                    selected={s.fish_npcs.iter().map(|npc| npc.key().to_string()).collect::<Vec<_>>()}
+                    placeholder="NPC"
+                    on_change={on_fish_npcs}
+                />
+                <MultiSelect
+                    options={fish_options(props.data)}
+                    selected={s.fish_ids.iter().map(|id| id.to_string()).collect::<Vec<_>>()}
+                    placeholder="Fish"
+                    on_change={on_fish}
+                />
+            </>
+        }
+    } else {
+        html! {}
+    };
+
+    let resource_section: Html = if resource_items(&s.quest_types).is_empty() {
+        html! {}
+    } else {
+        html! {
+            <MultiSelect
+                options={resource_options(&s.quest_types)}
+                selected={s.resource_item_ids.iter().map(|id| id.to_string()).collect::<Vec<_>>()}
+                placeholder={resource_placeholder(&s.quest_types)}
+                on_change={on_resource}
+            />
+        }
+    };
+
+    html! {
+        <>
+            <MultiSelect
+                options={quest_type_options()}
+                selected={s.quest_types.iter().map(|qt| qt.key().to_string()).collect::<Vec<_>>()}
+                placeholder="Select quest..."
+                on_change={on_quest_types}
+            />
+            { fishing_section }
+            { resource_section }
+        </>
+    }
+}
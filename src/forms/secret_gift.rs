@@ -0,0 +1,64 @@
+use yew::prelude::*;
+
+use crate::codegen::GameData;
+use crate::multi_select::MultiSelect;
+use crate::npcs::npc_label;
+use crate::sources::SecretGiftObservation;
+use crate::winter_star::participants;
+
+/// Form state for a Winter Star secret-gift observation.
+#[derive(Clone, PartialEq, Default)]
+pub struct SecretGiftFormState {
+    /// Every villager the user thinks may have given the gift.
+    pub givers: Vec<String>,
+}
+
+impl SecretGiftFormState {
+    pub fn build(&self) -> Option<SecretGiftObservation> {
+        if self.givers.is_empty() {
+            return None;
+        }
+        Some(SecretGiftObservation {
+            givers: self.givers.clone(),
+        })
+    }
+}
+
+/// Everyone who can take part from year 2 on, when the last villager arrives.
+fn giver_options(data: &'static GameData) -> Vec<(String, String)> {
+    let mut opts: Vec<(String, String)> = participants(data, 2)
+        .into_iter()
+        .map(|name| (name.to_string(), npc_label(data, name).to_string()))
+        .collect();
+    opts.sort_by(|a, b| a.1.cmp(&b.1));
+    opts
+}
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct SecretGiftFormProps {
+    pub data: &'static GameData,
+    pub state: SecretGiftFormState,
+    pub on_change: Callback<SecretGiftFormState>,
+}
+
+#[component]
+pub fn SecretGiftForm(props: &SecretGiftFormProps) -> Html {
+    let on_givers = {
+        let s = props.state.clone();
+        let on_change = props.on_change.clone();
+        Callback::from(move |givers: Vec<String>| {
+            let mut ns = s.clone();
+            ns.givers = givers;
+            on_change.emit(ns);
+        })
+    };
+
+    html! {
+        <MultiSelect
+            options={giver_options(props.data)}
+            selected={props.state.givers.clone()}
+            placeholder="Gift from..."
+            on_change={on_givers}
+        />
+    }
+}
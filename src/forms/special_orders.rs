@@ -0,0 +1,164 @@
+use yew::prelude::*;
+
+use crate::codegen::GameData;
+use crate::dropdown::DropdownSelect;
+use crate::multi_select::MultiSelect;
+use crate::sources::SpecialOrdersObservation;
+use crate::special_orders::{board_order_keys, OrderBoard, SpecialOrderState};
+
+/// Form state for a Special Orders board observation.
+#[derive(Clone, PartialEq, Default)]
+pub struct SpecialOrdersFormState {
+    pub board: Option<OrderBoard>,
+    /// Orders seen on the board.
+    pub orders: Vec<String>,
+    pub state: SpecialOrderState,
+}
+
+impl SpecialOrdersFormState {
+    pub fn build(&self) -> Option<SpecialOrdersObservation> {
+        // A board shows at most two orders.
+        if self.orders.is_empty() || self.orders.len() > 2 {
+            return None;
+        }
+        Some(SpecialOrdersObservation {
+            board: self.board?,
+            orders: self.orders.clone(),
+            state: self.state.clone(),
+        })
+    }
+}
+
+fn board_options() -> Vec<(String, String)> {
+    OrderBoard::ALL
+        .iter()
+        .map(|board| (board.key().to_string(), board.label().to_string()))
+        .collect()
+}
+
+fn order_options(data: &'static GameData, board: OrderBoard) -> Vec<(String, String)> {
+    board_order_keys(data, board)
+        .into_iter()
+        .map(|key| {
+            let requester = data
+                .special_orders
+                .iter()
+                .find(|order| order.key == key)
+                .map_or("", |order| order.requester);
+            let label = if requester.is_empty() || requester == key {
+                key.to_string()
+            } else {
+                format!("{key} ({requester})")
+            };
+            (key.to_string(), label)
+        })
+        .collect()
+}
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct SpecialOrdersFormProps {
+    pub data: &'static GameData,
+    pub state: SpecialOrdersFormState,
+    pub on_change: Callback<SpecialOrdersFormState>,
+}
+
+#[component]
+pub fn SpecialOrdersForm(props: &SpecialOrdersFormProps) -> Html {
+    let s = &props.state;
+    let on_change = props.on_change.clone();
+
+    if props.data.special_orders.is_empty() {
+        return html! {
+            <span class="tag is-warning is-light">
+                { "This build has no special order data (assets/SpecialOrders.json)" }
+            </span>
+        };
+    }
+
+    // ---- Board ----
+    let on_board = {
+        let s = s.clone();
+        let on_change = on_change.clone();
+        Callback::from(move |key: String| {
+            let mut ns = s.clone();
+            let board = OrderBoard::from_key(&key);
+            if ns.board != board {
+                ns.orders.clear();
+                ns.state.completed.clear();
+            }
+            ns.board = board;
+            on_change.emit(ns);
+        })
+    };
+
+    // ---- Orders ----
+    let on_orders = {
+        let s = s.clone();
+        let on_change = on_change.clone();
+        Callback::from(move |orders: Vec<String>| {
+            let mut ns = s.clone();
+            ns.orders = orders;
+            on_change.emit(ns);
+        })
+    };
+
+    // ---- Completed orders ----
+    let on_completed = {
+        let s = s.clone();
+        let on_change = on_change.clone();
+        Callback::from(move |completed: Vec<String>| {
+            let mut ns = s.clone();
+            ns.state.completed = completed;
+            on_change.emit(ns);
+        })
+    };
+
+    let on_toggle_island = {
+        let s = s.clone();
+        let on_change = on_change.clone();
+        Callback::from(move |_: MouseEvent| {
+            let mut ns = s.clone();
+            ns.state.island_unlocked = !ns.state.island_unlocked;
+            on_change.emit(ns);
+        })
+    };
+
+    let order_section: Html = match s.board {
+        Some(board) => html! {
+            <>
+                <MultiSelect
+                    options={order_options(props.data, board)}
+                    selected={s.orders.clone()}
+                    placeholder="Orders"
+                    on_change={on_orders}
+                />
+                <MultiSelect
+                    options={order_options(props.data, board)}
+                    selected={s.state.completed.clone()}
+                    placeholder="Completed before"
+                    on_change={on_completed}
+                />
+            </>
+        },
+        None => html! {},
+    };
+
+    html! {
+        <>
+            <DropdownSelect
+                options={board_options()}
+                selected={s.board.map(|b| b.key().to_string())}
+                placeholder="Board"
+                on_select={on_board}
+            />
+            { order_section }
+            <button
+                class={ if s.state.island_unlocked { "button is-info is-light" } else { "button is-light" } }
+                title="Click if Ginger Island was unlocked"
+                onclick={on_toggle_island}
+            >
+                { if s.state.island_unlocked { "Island unlocked" } else { "No island yet" } }
+            </button>
+        </>
+    }
+}
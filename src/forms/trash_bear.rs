@@ -0,0 +1,107 @@
+use yew::prelude::*;
+
+use crate::codegen::GameData;
+use crate::dropdown::DropdownSelect;
+use crate::geodes::qualified_item_label;
+use crate::sources::TrashBearObservation;
+use crate::trash_bear::{possible_requests, TRASH_BEAR_REQUESTS};
+
+/// Form state for a Trash Bear observation.
+#[derive(Clone, PartialEq, Default)]
+pub struct TrashBearFormState {
+    /// Requests filled before this one.
+    pub done: Option<u32>,
+    pub item: Option<String>,
+}
+
+impl TrashBearFormState {
+    pub fn build(&self) -> Option<TrashBearObservation> {
+        Some(TrashBearObservation {
+            done: self.done?,
+            item: self.item.clone()?,
+        })
+    }
+}
+
+fn request_options(data: &'static GameData) -> Vec<(String, String)> {
+    (0..TRASH_BEAR_REQUESTS)
+        // Later requests need the cooking recipes.
+        .filter(|&done| done < 2 || !data.cooking_recipes.is_empty())
+        .map(|done| (done.to_string(), format!("Request {}", done + 1)))
+        .collect()
+}
+
+fn item_options(data: &'static GameData, done: u32) -> Vec<(String, String)> {
+    let mut opts: Vec<(String, String)> = possible_requests(data, done)
+        .into_iter()
+        .map(|item| {
+            let label = qualified_item_label(data, &item);
+            (item, label)
+        })
+        .collect();
+    opts.sort_by(|a, b| a.1.cmp(&b.1));
+    opts
+}
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct TrashBearFormProps {
+    pub data: &'static GameData,
+    pub state: TrashBearFormState,
+    pub on_change: Callback<TrashBearFormState>,
+}
+
+#[component]
+pub fn TrashBearForm(props: &TrashBearFormProps) -> Html {
+    let s = &props.state;
+    let on_change = props.on_change.clone();
+
+    // ---- Request ----
+    let on_request = {
+        let s = s.clone();
+        let on_change = on_change.clone();
+        Callback::from(move |key: String| {
+            let mut ns = s.clone();
+            let done = key.parse::<u32>().ok();
+            if ns.done != done {
+                ns.item = None;
+            }
+            ns.done = done;
+            on_change.emit(ns);
+        })
+    };
+
+    // ---- Item ----
+    let on_item = {
+        let s = s.clone();
+        let on_change = on_change.clone();
+        Callback::from(move |key: String| {
+            let mut ns = s.clone();
+            ns.item = Some(key);
+            on_change.emit(ns);
+        })
+    };
+
+    let item_section: Html = match s.done {
+        Some(done) => html! {
+            <DropdownSelect
+                options={item_options(props.data, done)}
+                selected={s.item.clone()}
+                placeholder="Asked for"
+                on_select={on_item}
+            />
+        },
+        None => html! {},
+    };
+
+    html! {
+        <>
+            <DropdownSelect
+                options={request_options(props.data)}
+                selected={s.done.map(|done| done.to_string())}
+                placeholder="Request"
+                on_select={on_request}
+            />
+            { item_section }
+        </>
+    }
+}
@@ -0,0 +1,55 @@
+use yew::prelude::*;
+
+use crate::codegen::GameData;
+use crate::dropdown::DropdownSelect;
+use crate::sources::WeatherObservation;
+use crate::weather::Weather;
+
+/// Form state for a weather observation.
+#[derive(Clone, PartialEq, Default)]
+pub struct WeatherFormState {
+    pub weather: Option<Weather>,
+}
+
+impl WeatherFormState {
+    pub fn build(&self) -> Option<WeatherObservation> {
+        Some(WeatherObservation {
+            weather: self.weather?,
+        })
+    }
+}
+
+fn weather_options() -> Vec<(String, String)> {
+    Weather::ALL
+        .iter()
+        .map(|weather| (weather.key().to_string(), weather.label().to_string()))
+        .collect()
+}
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct WeatherFormProps {
+    pub data: &'static GameData,
+    pub state: WeatherFormState,
+    pub on_change: Callback<WeatherFormState>,
+}
+
+#[component]
+pub fn WeatherForm(props: &WeatherFormProps) -> Html {
+    let on_weather = {
+        let on_change = props.on_change.clone();
+        Callback::from(move |key: String| {
+            on_change.emit(WeatherFormState {
+                weather: Weather::from_key(&key),
+            });
+        })
+    };
+
+    html! {
+        <DropdownSelect
+            options={weather_options()}
+            selected={props.state.weather.map(|w| w.key().to_string())}
+            placeholder="Weather"
+            on_select={on_weather}
+        />
+    }
+}
@@ -0,0 +1,477 @@
+//! What the town garbage cans hold (`GameLocation.TryGetGarbageItem`).
+//!
+//! Each can seeds `CreateDaySaveRandom(777 + hash(canId))` and burns two runs
+//! of up to 99 doubles. One roll against the can's base chance, plus the
+//! player's daily luck, decides whether it has anything. The shared
+//! `BeforeAll` entries, the can's own entries and the shared `AfterAll`
+//! entries are then tried in order. Entries marked `IgnoreBaseChance` are
+//! tried even when that roll fails. Entries come from `Data/GarbageCans`;
+//! see `build.rs`.
+//!
+//! `RANDOM` conditions draw from the can's random and are replayed, as are
+//! date conditions and the save state in `GarbageState`. Special order rules
+//! are assumed inactive. Other conditions depend on save state the cracker
+//! cannot see; both outcomes are kept. Luck is the shared daily luck, without
+//! the Special Charm or food buffs. It and the Dish of the Day depend on the
+//! step count; without one every luck value is tried and the dish is left
+//! open.
+
+use std::cell::RefCell;
+
+use serde::{Deserialize, Serialize};
+
+use crate::checker::CheckError;
+use crate::codegen::{
+    GameData, GarbageCanData, GarbageItem,
+};
+use crate::geodes::qualified_item_label;
+use crate::luck::{daily_luck, possible_luck};
+use crate::observation::{calendar_date, Platform, Season};
+use crate::prng::{create_day_save_random, deterministic_hash, Prng, SeedingMode};
+use crate::saloon::{dish_of_the_day, possible_dishes};
+
+/// The `ItemId` of entries that give the Dish of the Day.
+const DISH_OF_THE_DAY: &str = "DISH_OF_THE_DAY";
+
+/// The vanilla cans in town, with where each one is.
+pub const GARBAGE_CAN_NAMES: &[(&str, &str)] = &[
+    ("JodiAndKent", "Jodi and Kent's house"),
+    ("EmilyAndHaley", "Emily and Haley's house"),
+    ("Mayor", "Mayor's manor"),
+    ("Museum", "Museum"),
+    ("Blacksmith", "Blacksmith"),
+    ("Saloon", "Saloon"),
+    ("Evelyn", "George and Evelyn's house"),
+    ("JojaMart", "JojaMart"),
+];
+
+/// Where `can` is, e.g. "Saloon"; the raw ID for modded cans.
+pub fn can_label(can: &str) -> &str {
+    GARBAGE_CAN_NAMES
+        .iter()
+        .find(|(id, _)| *id == can)
+        .map_or(can, |(_, label)| label)
+}
+
+/// What searching a can gave, with the alternatives hidden save state allows.
+#[derive(Clone, PartialEq)]
+pub enum GarbageLoot {
+    /// Qualified item ID or unresolved item query; `None` if the can was empty.
+    Found(Option<String>),
+    /// Depends on a game state query the cracker cannot evaluate.
+    Branch {
+        condition: String,
+        if_true: Box<GarbageLoot>,
+        if_false: Box<GarbageLoot>,
+    },
+}
+
+impl GarbageLoot {
+    /// True if some value of the hidden conditions gives `item`.
+    pub fn allows(&self, data: &GameData, item: Option<&str>) -> bool {
+        match self {
+            GarbageLoot::Found(Some(found)) if found == DISH_OF_THE_DAY => {
+                item == Some(DISH_OF_THE_DAY) || item.is_some_and(|item| is_possible_dish(data, item))
+            }
+            GarbageLoot::Found(found) => found.as_deref() == item,
+            GarbageLoot::Branch {
+                if_true, if_false, ..
+            } => if_true.allows(data, item) || if_false.allows(data, item),
+        }
+    }
+
+    pub fn label(&self, data: &GameData) -> String {
+        match self {
+            GarbageLoot::Found(Some(item)) if item == DISH_OF_THE_DAY => {
+                "the Dish of the Day".to_string()
+            }
+            GarbageLoot::Found(Some(item)) => qualified_item_label(data, item),
+            GarbageLoot::Found(None) => "nothing".to_string(),
+            GarbageLoot::Branch {
+                condition,
+                if_true,
+                if_false,
+            } => format!(
+                "{} if {condition}, else {}",
+                if_true.label(data),
+                if_false.label(data)
+            ),
+        }
+    }
+}
+
+/// Save state that changes what the cans hold.
+#[derive(Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct GarbageState {
+    /// The player has read The Alleyway Buffet, which raises the base chance.
+    #[serde(default)]
+    pub read_trash_book: bool,
+    /// The player has seen Mr. Qi's plane (the `sawQiPlane` mail).
+    #[serde(default)]
+    pub saw_qi_plane: bool,
+    /// `stats.StepsTaken` when the day started, which fixes the daily luck
+    /// and the Dish of the Day.
+    #[serde(default)]
+    pub steps_taken: Option<u32>,
+}
+
+pub fn garbage_can_data(data: &'static GameData, can: &str) -> Option<&'static GarbageCanData> {
+    data.garbage_cans.iter().find(|can_data| can_data.id == can)
+}
+
+/// What searching `can` on `days_played` gives.
+pub fn garbage_loot(
+    data: &'static GameData,
+    platform: Platform,
+    seeding: SeedingMode,
+    id: u64,
+    days_played: u32,
+    can: &str,
+    state: GarbageState,
+) -> Result<GarbageLoot, CheckError> {
+    if data.garbage_cans.is_empty() {
+        return Err(CheckError::MissingGameData("GarbageCans"));
+    }
+    let lucks: Vec<f64> = match state.steps_taken {
+        Some(steps) => vec![daily_luck(platform, seeding, id, days_played, steps)?],
+        None => possible_luck().collect(),
+    };
+    let mut search = Search {
+        platform,
+        seeding,
+        id,
+        days_played,
+        game_data: data,
+        can_data: garbage_can_data(data, can),
+        can,
+        state,
+        luck: lucks[0],
+        comparisons: RefCell::new(Vec::new()),
+    };
+
+    // Walk up through the luck values, replaying only where a roll compared
+    // against luck would come out differently.
+    let mut segments: Vec<(f64, GarbageLoot)> = Vec::new();
+    let mut next = Some(0);
+    while let Some(index) = next {
+        search.luck = lucks[index];
+        search.comparisons.borrow_mut().clear();
+        let loot = search.loot(&mut Vec::new())?;
+        if segments.last().is_none_or(|(_, last)| *last != loot) {
+            segments.push((search.luck, loot));
+        }
+        let comparisons = search.comparisons.borrow();
+        next = (index + 1..lucks.len()).find(|&i| {
+            comparisons
+                .iter()
+                .any(|c| c.passes(lucks[i]) != c.passes(search.luck))
+        });
+    }
+
+    let (mut from, mut loot) = segments.pop().expect("at least one luck value is searched");
+    while let Some((start, below)) = segments.pop() {
+        loot = GarbageLoot::Branch {
+            condition: format!("daily luck ≥ {from:+.3}"),
+            if_true: Box::new(loot),
+            if_false: Box::new(below),
+        };
+        from = start;
+    }
+    Ok(loot)
+}
+
+struct Search<'a> {
+    platform: Platform,
+    seeding: SeedingMode,
+    id: u64,
+    days_played: u32,
+    game_data: &'static GameData,
+    can_data: Option<&'static GarbageCanData>,
+    can: &'a str,
+    state: GarbageState,
+    luck: f64,
+    /// Rolls compared against a chance plus luck since the last reset.
+    comparisons: RefCell<Vec<LuckComparison>>,
+}
+
+/// A roll compared against a chance raised by the daily luck.
+enum LuckComparison {
+    /// The can's base chance, then luck, then `bonus`, added up in `f32` as
+    /// the game does.
+    BaseChance { roll: f64, chance: f32, bonus: f32 },
+    /// A `RANDOM <chance> @addDailyLuck` query.
+    Random { roll: f64, chance: f64 },
+}
+
+impl LuckComparison {
+    fn passes(&self, luck: f64) -> bool {
+        match *self {
+            LuckComparison::BaseChance {
+                roll,
+                chance,
+                bonus,
+            } => roll < (chance + luck as f32 + bonus) as f64,
+            LuckComparison::Random { roll, chance } => roll < chance + luck,
+        }
+    }
+}
+
+/// How many hidden conditions are split on before the rest are assumed false.
+const MAX_BRANCHES: usize = 4;
+
+impl Search<'_> {
+    /// Replays the search with `assumed` values for hidden conditions, and
+    /// splits on the first hidden condition that is not yet assumed.
+    fn loot(&self, assumed: &mut Vec<(&'static str, bool)>) -> Result<GarbageLoot, CheckError> {
+        let (found, hidden) = self.search(assumed)?;
+        let Some(condition) = hidden.filter(|_| assumed.len() < MAX_BRANCHES) else {
+            return Ok(GarbageLoot::Found(found));
+        };
+        assumed.push((condition, true));
+        let if_true = self.loot(assumed)?;
+        assumed.last_mut().unwrap().1 = false;
+        let if_false = self.loot(assumed)?;
+        assumed.pop();
+        if if_true == if_false {
+            return Ok(if_true);
+        }
+        Ok(GarbageLoot::Branch {
+            condition: condition.to_string(),
+            if_true: Box::new(if_true),
+            if_false: Box::new(if_false),
+        })
+    }
+
+    /// One replay. Hidden conditions without an assumed value count as
+    /// false; the first one met is returned.
+    fn search(
+        &self,
+        assumed: &[(&'static str, bool)],
+    ) -> Result<(Option<String>, Option<&'static str>), CheckError> {
+        let hash = 777i32.wrapping_add(deterministic_hash(self.can));
+        let mut rng = create_day_save_random(
+            self.platform,
+            self.seeding,
+            self.days_played,
+            self.id,
+            hash as f64,
+            0.0,
+            0.0,
+        )?;
+        for _ in 0..2 {
+            let prewarm = rng.gen_range(0..100)?;
+            for _ in 0..prewarm {
+                rng.gen_float()?;
+            }
+        }
+
+        let base_chance = match self.can_data {
+            Some(can_data) if can_data.base_chance > 0.0 => can_data.base_chance,
+            _ => *self.game_data.garbage_default_base_chance,
+        };
+        let base_chance_passed = self.compare(LuckComparison::BaseChance {
+            roll: rng.gen_float()?,
+            chance: base_chance,
+            bonus: if self.state.read_trash_book { 0.2 } else { 0.0 },
+        });
+
+        let own: &[GarbageItem] = self.can_data.map_or(&[], |can_data| can_data.items);
+        let mut hidden = None;
+        for entry in self
+            .game_data
+            .garbage_before_all
+            .iter()
+            .chain(own)
+            .chain(self.game_data.garbage_after_all)
+        {
+            if entry.id.is_empty() || (!base_chance_passed && !entry.ignore_base_chance) {
+                continue;
+            }
+            let condition = match entry.condition {
+                Some(condition) => self.check_conditions(condition, rng.as_mut(), assumed)?,
+                None => Condition::True,
+            };
+            match condition {
+                Condition::True => {}
+                Condition::False => continue,
+                Condition::Hidden(query) => {
+                    hidden.get_or_insert(query);
+                    continue;
+                }
+            }
+            if let Some(item) = self.resolve(entry, rng.as_mut())? {
+                return Ok((Some(item), hidden));
+            }
+        }
+        Ok((None, hidden))
+    }
+
+    /// Whether `comparison` passes at the luck being replayed, noting it so
+    /// the caller can tell which other luck values behave the same.
+    fn compare(&self, comparison: LuckComparison) -> bool {
+        let passes = comparison.passes(self.luck);
+        self.comparisons.borrow_mut().push(comparison);
+        passes
+    }
+
+    /// `GameStateQuery.CheckConditions`: comma-separated queries, stopping at
+    /// the first that fails.
+    fn check_conditions(
+        &self,
+        conditions: &'static str,
+        rng: &mut dyn Prng,
+        assumed: &[(&'static str, bool)],
+    ) -> Result<Condition, CheckError> {
+        for query in conditions.split(',').map(str::trim) {
+            let (negated, body) = match query.strip_prefix('!') {
+                Some(body) => (true, body),
+                None => (false, query),
+            };
+            let holds = match self.evaluate(body, rng)? {
+                Some(holds) => holds != negated,
+                None => match assumed.iter().find(|(q, _)| *q == query) {
+                    Some(&(_, holds)) => holds,
+                    None => return Ok(Condition::Hidden(query)),
+                },
+            };
+            if !holds {
+                return Ok(Condition::False);
+            }
+        }
+        Ok(Condition::True)
+    }
+
+    /// The queries the cracker can evaluate; `None` for any other.
+    fn evaluate(&self, query: &str, rng: &mut dyn Prng) -> Result<Option<bool>, CheckError> {
+        let words: Vec<&str> = query.split_whitespace().collect();
+        let (day, season, year) = calendar_date(self.days_played);
+        Ok(match words.as_slice() {
+            ["RANDOM", chance, flags @ ..] => {
+                let Ok(chance) = chance.parse::<f64>() else {
+                    return Ok(None);
+                };
+                let roll = rng.gen_float()?;
+                if flags.contains(&"@addDailyLuck") {
+                    Some(self.compare(LuckComparison::Random { roll, chance }))
+                } else {
+                    Some(roll < chance)
+                }
+            }
+            ["SEASON", seasons @ ..] => Some(
+                seasons
+                    .iter()
+                    .any(|s| Season::from_key(&s.to_ascii_lowercase()) == Some(season)),
+            ),
+            ["DAY_OF_MONTH", days @ ..] => Some(days.iter().any(|d| d.parse() == Ok(day))),
+            ["DAY_OF_WEEK", days @ ..] => Some(days.iter().any(|d| is_weekday(d, day))),
+            ["YEAR", min] => min.parse::<u32>().ok().map(|min| year >= min),
+            ["PLAYER_HAS_MAIL", _, "sawQiPlane"] => Some(self.state.saw_qi_plane),
+            ["PLAYER_SPECIAL_ORDER_RULE_ACTIVE", ..] => Some(false),
+            _ => None,
+        })
+    }
+
+    /// The entry's item: a pick from `RandomItemId`, the Dish of the Day, or
+    /// the item ID as written. Other item queries are kept as text.
+    fn resolve(
+        &self,
+        entry: &GarbageItem,
+        rng: &mut dyn Prng,
+    ) -> Result<Option<String>, CheckError> {
+        if !entry.random_item_ids.is_empty() {
+            let ids = entry.random_item_ids;
+            return Ok(Some(
+                ids[rng.gen_range(0..ids.len() as i32)? as usize].to_string(),
+            ));
+        }
+        Ok(match entry.item_id {
+            Some(DISH_OF_THE_DAY) => match self.state.steps_taken {
+                Some(steps) => {
+                    let (dish, _) = dish_of_the_day(
+                        self.platform,
+                        self.seeding,
+                        self.id,
+                        self.days_played,
+                        steps,
+                    )?;
+                    Some(format!("(O){dish}"))
+                }
+                None => Some(DISH_OF_THE_DAY.to_string()),
+            },
+            Some(item) if item.chars().all(|c| c.is_ascii_digit()) => Some(format!("(O){item}")),
+            Some(item) => Some(item.to_string()),
+            None => None,
+        })
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Condition {
+    True,
+    False,
+    Hidden(&'static str),
+}
+
+/// `DAY_OF_WEEK` takes a name, a three-letter name or a number (Sunday is 0).
+fn is_weekday(query: &str, day: u8) -> bool {
+    const DAYS: [&str; 7] = [
+        "Sunday",
+        "Monday",
+        "Tuesday",
+        "Wednesday",
+        "Thursday",
+        "Friday",
+        "Saturday",
+    ];
+    let weekday = (day % 7) as usize;
+    query.parse::<usize>() == Ok(weekday)
+        || query.eq_ignore_ascii_case(DAYS[weekday])
+        || query.eq_ignore_ascii_case(&DAYS[weekday][..3])
+}
+
+/// Whether `item` is a qualified ID the Dish of the Day can be.
+fn is_possible_dish(data: &GameData, item: &str) -> bool {
+    item.strip_prefix("(O)")
+        .and_then(|id| id.parse::<u32>().ok())
+        .is_some_and(|id| possible_dishes(data).contains(&id))
+}
+
+/// Every item `can` can give, in the order they are tried.
+pub fn possible_loot(data: &'static GameData, can: &str) -> Vec<String> {
+    let own: &[GarbageItem] = garbage_can_data(data, can).map_or(&[], |can_data| can_data.items);
+    let mut items: Vec<String> = Vec::new();
+    for entry in data
+        .garbage_before_all
+        .iter()
+        .chain(own)
+        .chain(data.garbage_after_all)
+    {
+        let ids = entry.item_id.iter().chain(entry.random_item_ids);
+        for id in ids {
+            let item = if id.chars().all(|c| c.is_ascii_digit()) {
+                format!("(O){id}")
+            } else {
+                id.to_string()
+            };
+            if !items.contains(&item) {
+                items.push(item);
+            }
+        }
+    }
+    items
+}
+
+/// Rough chance that a random ID gives `item` (or nothing) from `can`: the
+/// base chance, split evenly over what the can gives.
+pub fn garbage_pass_rate(data: &'static GameData, can: &str, item: Option<&str>) -> f64 {
+    let base_chance = match garbage_can_data(data, can) {
+        Some(can_data) if can_data.base_chance > 0.0 => can_data.base_chance,
+        _ => *data.garbage_default_base_chance,
+    } as f64;
+    let items = possible_loot(data, can);
+    match item {
+        None => 1.0 - base_chance,
+        Some(item) if items.iter().any(|i| i == item) => base_chance / items.len() as f64,
+        Some(_) => 0.0,
+    }
+}
@@ -0,0 +1,262 @@
+//! What Clint finds when he cracks a geode (`Utility.getTreasureFromGeode`).
+//!
+//! Every geode seeds `CreateRandom(GeodesCracked, uniqueIDForThisGame / 2)`,
+//! where `GeodesCracked` already counts the geode being opened, and burns two
+//! runs of one to nine doubles. Geodes with `GeodeDropsDefaultItems` then flip
+//! a coin between their `GeodeDrops` and the hardcoded ore and stone table.
+//! Item lists come from `Data/Objects`; see `build.rs`.
+
+use crate::checker::CheckError;
+use crate::codegen::{GameData, GeodeData, GeodeDrop};
+use crate::explain::item_label;
+use crate::mystery_boxes::REWARD_NAMES;
+use crate::observation::Platform;
+use crate::prng::{create_random, Prng, SeedingMode};
+
+/// What one geode gave.
+#[derive(Clone, PartialEq)]
+pub struct GeodeTreasure {
+    /// Qualified item ID, e.g. "(O)538".
+    pub item: String,
+    pub stack: u32,
+    /// What the geode gives instead when a save-state condition the cracker
+    /// cannot see does not hold: (the condition, the fallback).
+    pub otherwise: Option<(&'static str, Box<GeodeTreasure>)>,
+}
+
+impl GeodeTreasure {
+    fn item(item: impl Into<String>, stack: u32) -> Self {
+        GeodeTreasure {
+            item: item.into(),
+            stack,
+            otherwise: None,
+        }
+    }
+
+    /// True if the geode gives `item` whether or not the hidden conditions hold.
+    pub fn allows(&self, item: &str) -> bool {
+        self.item == item
+            || self
+                .otherwise
+                .as_ref()
+                .is_some_and(|(_, fallback)| fallback.allows(item))
+    }
+
+    pub fn label(&self, data: &GameData) -> String {
+        let mut label = qualified_item_label(data, &self.item);
+        if self.stack > 1 {
+            label.push_str(&format!(" ×{}", self.stack));
+        }
+        if let Some((condition, fallback)) = &self.otherwise {
+            label.push_str(&format!(" if {condition}, else {}", fallback.label(data)));
+        }
+        label
+    }
+}
+
+/// Every geode type, in `Data/Objects` order.
+pub fn geodes(data: &'static GameData) -> &'static [GeodeData] {
+    data.geodes
+}
+
+pub fn geode_data(data: &'static GameData, geode_id: &str) -> Option<&'static GeodeData> {
+    data.geodes.iter().find(|geode| geode.id == geode_id)
+}
+
+/// The item from cracking `geode_id` as geode number `index` (1-based, over
+/// every geode cracked on the save). `None` if `geode_id` is not a geode.
+pub fn geode_treasure(
+    data: &'static GameData,
+    platform: Platform,
+    seeding: SeedingMode,
+    id: u64,
+    geode_id: &str,
+    index: u32,
+) -> Result<Option<GeodeTreasure>, CheckError> {
+    let Some(geode) = geode_data(data, geode_id) else {
+        return Ok(None);
+    };
+    let mut rng = create_random(platform, seeding, &[index as f64, (id / 2) as f64])?;
+    for _ in 0..2 {
+        let prewarm = rng.gen_range(1..10)?;
+        for _ in 0..prewarm {
+            rng.gen_float()?;
+        }
+    }
+    // Qi Beans while the "DROP_QI_BEANS" special order rule is active; the
+    // roll is made either way and the rule is assumed off.
+    rng.gen_float()?;
+
+    if !geode.drops.is_empty() && (!geode.default_items || rng.gen_float()? < 0.5) {
+        if let Some(treasure) = treasure_from_drops(rng.as_mut(), geode.drops, index)? {
+            return Ok(Some(treasure));
+        }
+    }
+    Ok(Some(default_treasure(rng.as_mut(), geode.id)?))
+}
+
+/// The first drop whose chance and condition pass, in precedence order.
+fn treasure_from_drops(
+    rng: &mut dyn Prng,
+    drops: &[GeodeDrop],
+    index: u32,
+) -> Result<Option<GeodeTreasure>, CheckError> {
+    let Some((drop, rest)) = drops.split_first() else {
+        return Ok(None);
+    };
+    // `NextBool(chance)` only draws for chances below 1.
+    let passes = drop.chance >= 1.0 || rng.gen_float()? < drop.chance;
+    let condition = drop.condition.map(|query| evaluate_condition(query, index));
+    if !passes || condition == Some(Condition::False) {
+        return treasure_from_drops(rng, rest, index);
+    }
+
+    let item = match (drop.item_id, drop.random_item_ids) {
+        (_, ids) if !ids.is_empty() => ids[rng.gen_range(0..ids.len() as i32)? as usize],
+        (Some(item), _) => item,
+        (None, _) => return treasure_from_drops(rng, rest, index),
+    };
+    let mut treasure = GeodeTreasure::item(item, 1);
+    if let Some(Condition::Unknown(description)) = condition {
+        // The fallback continues from the same random state.
+        if let Some(fallback) = treasure_from_drops(rng, rest, index)? {
+            treasure.otherwise = Some((description, Box::new(fallback)));
+        }
+    }
+    Ok(Some(treasure))
+}
+
+/// The hardcoded stone, clay, crystal and ore table.
+fn default_treasure(rng: &mut dyn Prng, geode_id: &str) -> Result<GeodeTreasure, CheckError> {
+    let mut stack = (rng.gen_range(0..3)? * 2 + 1) as u32;
+    if rng.gen_float()? < 0.1 {
+        stack = 10;
+    }
+    if rng.gen_float()? < 0.01 {
+        stack = 20;
+    }
+
+    if rng.gen_float()? < 0.5 {
+        return Ok(match rng.gen_range(0..4)? {
+            0 | 1 => GeodeTreasure::item("(O)390", stack),
+            2 => GeodeTreasure::item("(O)330", 1),
+            _ => {
+                let crystal = match geode_id {
+                    "749" => 82 + rng.gen_range(0..3)? * 2,
+                    "535" => 86,
+                    "536" => 84,
+                    _ => 82,
+                };
+                GeodeTreasure::item(format!("(O){crystal}"), 1)
+            }
+        });
+    }
+
+    let ore = |id: &str| GeodeTreasure::item(id, stack);
+    // Better ore needs the player to have been deep enough in the mines.
+    let deeper = |condition: &'static str, deep: &str, shallow: &str| GeodeTreasure {
+        otherwise: Some((condition, Box::new(ore(shallow)))),
+        ..ore(deep)
+    };
+    Ok(match geode_id {
+        "535" => match rng.gen_range(0..3)? {
+            0 => ore("(O)378"),
+            1 => deeper("mine level 26 reached", "(O)380", "(O)378"),
+            _ => ore("(O)382"),
+        },
+        "536" => match rng.gen_range(0..4)? {
+            0 => ore("(O)378"),
+            1 => ore("(O)380"),
+            2 => ore("(O)382"),
+            _ => deeper("mine level 76 reached", "(O)384", "(O)380"),
+        },
+        _ => match rng.gen_range(0..5)? {
+            0 => ore("(O)378"),
+            1 => ore("(O)380"),
+            2 => ore("(O)382"),
+            3 => ore("(O)384"),
+            _ => GeodeTreasure::item("(O)386", stack / 2 + 1),
+        },
+    })
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Condition {
+    True,
+    False,
+    /// Depends on save state; described for the player.
+    Unknown(&'static str),
+}
+
+/// The game state queries vanilla geode drops use.
+fn evaluate_condition(query: &'static str, index: u32) -> Condition {
+    let words: Vec<&str> = query.split_whitespace().collect();
+    match words.as_slice() {
+        ["PLAYER_STAT", "Current", "GeodesCracked", min] => match min.parse::<u32>() {
+            Ok(min) if index >= min => Condition::True,
+            Ok(_) => Condition::False,
+            Err(_) => Condition::Unknown(query),
+        },
+        ["!PLAYER_HAS_MAIL", "Current", "goldenCoconutHat"] => {
+            Condition::Unknown("the hat has not been found yet")
+        }
+        _ => Condition::Unknown(query),
+    }
+}
+
+/// Every item `geode_id` can give, in table order.
+pub fn possible_treasures(data: &'static GameData, geode_id: &str) -> Vec<&'static str> {
+    let Some(geode) = geode_data(data, geode_id) else {
+        return Vec::new();
+    };
+    let mut items: Vec<&'static str> = Vec::new();
+    for drop in geode.drops {
+        for &item in drop.item_id.iter().chain(drop.random_item_ids) {
+            if !items.contains(&item) {
+                items.push(item);
+            }
+        }
+    }
+    if geode.default_items || geode.drops.is_empty() {
+        let crystals: &[&'static str] = match geode_id {
+            "749" => &["(O)82", "(O)84", "(O)86"],
+            "535" => &["(O)86"],
+            "536" => &["(O)84"],
+            _ => &["(O)82"],
+        };
+        let ores: &[&'static str] = match geode_id {
+            "535" => &["(O)378", "(O)380", "(O)382"],
+            "536" => &["(O)378", "(O)380", "(O)382", "(O)384"],
+            _ => &["(O)378", "(O)380", "(O)382", "(O)384", "(O)386"],
+        };
+        for &item in ["(O)390", "(O)330"].iter().chain(crystals).chain(ores) {
+            if !items.contains(&item) {
+                items.push(item);
+            }
+        }
+    }
+    items
+}
+
+/// Rough chance that a random ID gives `item` from `geode_id`: uniform over
+/// the items it can give.
+pub fn geode_pass_rate(data: &'static GameData, geode_id: &str, item: &str) -> f64 {
+    let items = possible_treasures(data, geode_id);
+    if items.contains(&item) {
+        1.0 / items.len() as f64
+    } else {
+        0.0
+    }
+}
+
+/// "Amethyst (66)" for objects, the name of other known rewards, and the raw
+/// ID otherwise.
+pub fn qualified_item_label(data: &GameData, item: &str) -> String {
+    if let Some(id) = item.strip_prefix("(O)").and_then(|id| id.parse::<u32>().ok()) {
+        return item_label(data, id);
+    }
+    match REWARD_NAMES.iter().find(|(id, _)| *id == item) {
+        Some((_, name)) => name.to_string(),
+        None => item.to_string(),
+    }
+}
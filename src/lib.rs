@@ -1,9 +1,29 @@
 // Core logic — always compiled, public so native binaries can use them.
+pub mod artifact_spots;
+pub mod bookseller;
+pub mod checker;
 pub mod codegen;
+pub mod cracker;
+pub mod desert_trader;
+pub mod explain;
 pub mod game_data;
+pub mod garbage_cans;
+pub mod geodes;
+pub mod luck;
+pub mod mines;
+pub mod mystery_boxes;
+pub mod night_events;
+pub mod npcs;
 pub mod observation;
 pub mod prng;
 pub mod quest_checker;
+pub mod remixed_bundles;
+pub mod saloon;
+pub mod sources;
+pub mod special_orders;
+pub mod trash_bear;
+pub mod weather;
+pub mod winter_star;
 
 // UI / WASM — only compiled when targeting wasm32.
 #[cfg(target_arch = "wasm32")]
@@ -13,6 +33,10 @@ pub mod app;
 #[cfg(target_arch = "wasm32")]
 mod dropdown;
 #[cfg(target_arch = "wasm32")]
+mod forms;
+#[cfg(target_arch = "wasm32")]
+mod multi_select;
+#[cfg(target_arch = "wasm32")]
 mod observation_row;
 #[cfg(target_arch = "wasm32")]
 mod platform_component;
@@ -0,0 +1,119 @@
+//! Daily luck and the TV fortune teller that reports it.
+//!
+//! Each morning the shared daily luck is `Next(-100, 101) / 1000` (capped at
+//! 0.1), drawn right after the Dish of the Day from the same random (see
+//! `saloon.rs`), so it depends on the step count too. The fortune teller only
+//! shows which of a handful of ranges it falls in.
+//!
+//! Like the dish, the fortune forecasts a known ID but cannot help crack an
+//! unknown one, and the web app has no form for it.
+
+use serde::{Deserialize, Serialize};
+
+use crate::checker::CheckError;
+use crate::observation::Platform;
+use crate::prng::SeedingMode;
+use crate::saloon::{draw_dish, new_day_random};
+
+/// What the fortune teller says, from worst to best luck.
+#[derive(Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Fortune {
+    /// luck < -0.07
+    VeryDispleased,
+    /// -0.07 ≤ luck < -0.02
+    SomewhatAnnoyed,
+    /// -0.02 ≤ luck ≤ 0.02, but not exactly 0.
+    Neutral,
+    /// luck == 0
+    AbsolutelyNeutral,
+    /// 0.02 < luck ≤ 0.07
+    GoodHumor,
+    /// luck > 0.07
+    VeryHappy,
+}
+
+impl Fortune {
+    pub const ALL: [Fortune; 6] = [
+        Fortune::VeryDispleased,
+        Fortune::SomewhatAnnoyed,
+        Fortune::Neutral,
+        Fortune::AbsolutelyNeutral,
+        Fortune::GoodHumor,
+        Fortune::VeryHappy,
+    ];
+
+    /// Mirrors the thresholds in `TV.getFortuneForecast`.
+    pub fn from_luck(luck: f64) -> Self {
+        if luck < -0.07 {
+            Fortune::VeryDispleased
+        } else if luck < -0.02 {
+            Fortune::SomewhatAnnoyed
+        } else if luck > 0.07 {
+            Fortune::VeryHappy
+        } else if luck > 0.02 {
+            Fortune::GoodHumor
+        } else if luck == 0.0 {
+            Fortune::AbsolutelyNeutral
+        } else {
+            Fortune::Neutral
+        }
+    }
+
+    /// The start of the fortune teller's line, as shown on the TV.
+    pub fn label(self) -> &'static str {
+        match self {
+            Fortune::VeryDispleased => "The spirits are very displeased today.",
+            Fortune::SomewhatAnnoyed => "The spirits are somewhat annoyed today.",
+            Fortune::Neutral => "The spirits feel neutral today.",
+            Fortune::AbsolutelyNeutral => "The spirits feel absolutely neutral today.",
+            Fortune::GoodHumor => "The spirits are in good humor today.",
+            Fortune::VeryHappy => "The spirits are very happy today!",
+        }
+    }
+
+    pub fn key(self) -> &'static str {
+        match self {
+            Fortune::VeryDispleased => "very-displeased",
+            Fortune::SomewhatAnnoyed => "somewhat-annoyed",
+            Fortune::Neutral => "neutral",
+            Fortune::AbsolutelyNeutral => "absolutely-neutral",
+            Fortune::GoodHumor => "good-humor",
+            Fortune::VeryHappy => "very-happy",
+        }
+    }
+
+    pub fn from_key(s: &str) -> Option<Self> {
+        Fortune::ALL.into_iter().find(|fortune| fortune.key() == s)
+    }
+
+    /// Fraction of the 201 equally likely rolls that give this fortune.
+    pub fn pass_rate(self) -> f64 {
+        let rolls = (-100..=100)
+            .filter(|&n| Fortune::from_luck(luck_from_roll(n)) == self)
+            .count();
+        rolls as f64 / 201.0
+    }
+}
+
+/// The shared daily luck on `days_played`, with `steps_taken` as in
+/// `new_day_random`.
+pub fn daily_luck(
+    platform: Platform,
+    seeding: SeedingMode,
+    id: u64,
+    days_played: u32,
+    steps_taken: u32,
+) -> Result<f64, CheckError> {
+    let mut rng = new_day_random(platform, seeding, id, days_played, steps_taken)?;
+    draw_dish(rng.as_mut())?;
+    Ok(luck_from_roll(rng.gen_range(-100..101)?))
+}
+
+/// Every value the daily luck can take, from lowest to highest.
+pub fn possible_luck() -> impl DoubleEndedIterator<Item = f64> {
+    (-100..=100).map(luck_from_roll)
+}
+
+pub fn luck_from_roll(n: i32) -> f64 {
+    (n as f64 / 1000.0).min(0.1)
+}
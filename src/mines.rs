@@ -0,0 +1,171 @@
+//! Special floors of the Mines (`MineShaft.loadLevel`).
+//!
+//! Each time a floor loads, the game seeds `CreateDaySaveRandom(level * 100)`
+//! and rolls for a monster or slime infestation, then for a quarry floor, then
+//! for a mushroom floor. Floors that are multiples of five never change. The
+//! Skull Cavern rolls its map first and its treasure rooms depend on luck
+//! buffs, so it is not covered.
+
+use serde::{Deserialize, Serialize};
+
+use crate::checker::CheckError;
+use crate::observation::Platform;
+use crate::prng::{create_day_save_random, SeedingMode};
+
+/// The deepest regular Mines floor; 120 is the bottom.
+pub const MINE_FLOORS: u32 = 119;
+
+/// What a Mines floor looked like on one day.
+#[derive(Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum MineFeature {
+    Normal,
+    MonsterInfested,
+    SlimeInfested,
+    /// Quarry floor; only once the Crafts Room is done and the Quarry Mine has
+    /// been visited.
+    Quarry,
+    /// Rainbow lights and purple mushrooms.
+    Mushroom,
+}
+
+impl MineFeature {
+    pub const ALL: [MineFeature; 5] = [
+        MineFeature::Normal,
+        MineFeature::MonsterInfested,
+        MineFeature::SlimeInfested,
+        MineFeature::Quarry,
+        MineFeature::Mushroom,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            MineFeature::Normal => "Normal",
+            MineFeature::MonsterInfested => "Monster infested",
+            MineFeature::SlimeInfested => "Slime infested",
+            MineFeature::Quarry => "Quarry",
+            MineFeature::Mushroom => "Mushroom",
+        }
+    }
+
+    pub fn key(self) -> &'static str {
+        match self {
+            MineFeature::Normal => "normal",
+            MineFeature::MonsterInfested => "monster",
+            MineFeature::SlimeInfested => "slime",
+            MineFeature::Quarry => "quarry",
+            MineFeature::Mushroom => "mushroom",
+        }
+    }
+
+    pub fn from_key(s: &str) -> Option<Self> {
+        MineFeature::ALL
+            .into_iter()
+            .find(|feature| feature.key() == s)
+    }
+}
+
+/// Floors with fixed layouts: elevator floors and the bottom.
+fn fixed_floor(floor: u32) -> bool {
+    floor.is_multiple_of(5) || floor > MINE_FLOORS
+}
+
+fn can_be_infested(floor: u32) -> bool {
+    let depth = floor % 40;
+    depth > 5 && depth < 30 && depth != 19
+}
+
+fn can_be_quarry(floor: u32) -> bool {
+    floor % 40 > 1
+}
+
+fn can_be_mushroom(floor: u32) -> bool {
+    floor > 80
+}
+
+/// The feature of `floor` on `days_played`. `quarry_unlocked` is whether the
+/// save can get quarry floors.
+pub fn mine_floor(
+    platform: Platform,
+    seeding: SeedingMode,
+    id: u64,
+    days_played: u32,
+    floor: u32,
+    quarry_unlocked: bool,
+) -> Result<MineFeature, CheckError> {
+    if fixed_floor(floor) {
+        return Ok(MineFeature::Normal);
+    }
+    let mut rng = create_day_save_random(
+        platform,
+        seeding,
+        days_played,
+        id,
+        floor as f64 * 100.0,
+        0.0,
+        0.0,
+    )?;
+
+    // Each roll comes first in its condition, so it is made even when the
+    // floor cannot have the feature.
+    if rng.gen_float()? < 0.044 && can_be_infested(floor) {
+        return Ok(if rng.gen_float()? < 0.5 {
+            MineFeature::MonsterInfested
+        } else {
+            MineFeature::SlimeInfested
+        });
+    }
+    if rng.gen_float()? < 0.044 && quarry_unlocked && can_be_quarry(floor) {
+        return Ok(MineFeature::Quarry);
+    }
+    if rng.gen_float()? < 0.035 && can_be_mushroom(floor) {
+        return Ok(MineFeature::Mushroom);
+    }
+    Ok(MineFeature::Normal)
+}
+
+/// Every floor with a feature on `days_played`, in floor order.
+pub fn special_floors(
+    platform: Platform,
+    seeding: SeedingMode,
+    id: u64,
+    days_played: u32,
+    quarry_unlocked: bool,
+) -> Result<Vec<(u32, MineFeature)>, CheckError> {
+    let mut floors = Vec::new();
+    for floor in 1..=MINE_FLOORS {
+        let feature = mine_floor(platform, seeding, id, days_played, floor, quarry_unlocked)?;
+        if feature != MineFeature::Normal {
+            floors.push((floor, feature));
+        }
+    }
+    Ok(floors)
+}
+
+/// Chance that a random ID gives `feature` on `floor`.
+pub fn mine_floor_pass_rate(floor: u32, feature: MineFeature, quarry_unlocked: bool) -> f64 {
+    if fixed_floor(floor) {
+        return if feature == MineFeature::Normal {
+            1.0
+        } else {
+            0.0
+        };
+    }
+    let infested = if can_be_infested(floor) { 0.044 } else { 0.0 };
+    let quarry = if quarry_unlocked && can_be_quarry(floor) {
+        (1.0 - infested) * 0.044
+    } else {
+        0.0
+    };
+    let mushroom = if can_be_mushroom(floor) {
+        (1.0 - infested - quarry) * 0.035
+    } else {
+        0.0
+    };
+
+    match feature {
+        MineFeature::Normal => 1.0 - infested - quarry - mushroom,
+        MineFeature::MonsterInfested | MineFeature::SlimeInfested => infested / 2.0,
+        MineFeature::Quarry => quarry,
+        MineFeature::Mushroom => mushroom,
+    }
+}
@@ -0,0 +1,145 @@
+use yew::prelude::*;
+
+use crate::dropdown::multiline;
+
+/// A Bulma-styled dropdown that lets the user tick any number of options.
+///
+/// Sized the same way as `DropdownSelect` (see there for the sizer technique).
+/// Clicking an option toggles it without closing the menu: the mousedown
+/// default is prevented so the trigger button keeps focus.
+///
+/// The trigger shows the selected option's label when exactly one is ticked,
+/// and `placeholder (n)` when several are.
+#[derive(Clone, PartialEq, Properties)]
+pub struct MultiSelectProps {
+    /// `(key, label)` pairs. Keys are opaque strings used to identify a selection.
+    pub options: Vec<(String, String)>,
+    /// Keys of the currently ticked options, in the order they were ticked.
+    pub selected: Vec<String>,
+    /// Text shown in the button when nothing is ticked.
+    pub placeholder: String,
+    /// Emits the full set of ticked keys after every toggle.
+    pub on_change: Callback<Vec<String>>,
+}
+
+#[component]
+pub fn MultiSelect(props: &MultiSelectProps) -> Html {
+    let open = use_state(|| false);
+
+    let current_label_str: String = match props.selected.as_slice() {
+        [] => props.placeholder.clone(),
+        [only] => props
+            .options
+            .iter()
+            .find(|(key, _)| key == only)
+            .map(|(_, label)| label.clone())
+            .unwrap_or_else(|| props.placeholder.clone()),
+        many => format!("{} ({})", props.placeholder, many.len()),
+    };
+
+    // Width sizer: longest *single line* across all labels (white-space:nowrap).
+    let sizer_width = props
+        .options
+        .iter()
+        .flat_map(|(_, label)| label.split('\n'))
+        .chain(props.placeholder.split('\n'))
+        .max_by_key(|s| s.len())
+        .unwrap_or("");
+
+    // Height sizer: the label with the most lines, rendered via multiline().
+    let sizer_tallest = props
+        .options
+        .iter()
+        .map(|(_, label)| label.as_str())
+        .chain(std::iter::once(props.placeholder.as_str()))
+        .max_by_key(|s| s.matches('\n').count())
+        .unwrap_or("");
+
+    let on_focus = {
+        let open = open.clone();
+        Callback::from(move |_: FocusEvent| open.set(true))
+    };
+    let on_blur = {
+        let open = open.clone();
+        Callback::from(move |_: FocusEvent| open.set(false))
+    };
+
+    let items: Html = props
+        .options
+        .iter()
+        .map(|(key, label)| {
+            let ticked = props.selected.contains(key);
+            let key = key.clone();
+            let label = label.clone();
+            let selected = props.selected.clone();
+            let on_change = props.on_change.clone();
+            html! {
+                <a class={ if ticked { "dropdown-item is-active" } else { "dropdown-item" } }
+                   style="display:flex; gap:0.5rem"
+                   onmousedown={Callback::from(move |e: MouseEvent| {
+                       // Keep focus on the trigger so the menu stays open.
+                       e.prevent_default();
+                       let mut next = selected.clone();
+                       if ticked {
+                           next.retain(|k| k != &key);
+                       } else {
+                           next.push(key.clone());
+                       }
+                       on_change.emit(next);
+                   })}>
+                    <span class="material-symbols-outlined">
+                        { if ticked { "check_box" } else { "check_box_outline_blank" } }
+                    </span>
+                    <span>{ multiline(&label) }</span>
+                </a>
+            }
+        })
+        .collect();
+
+    html! {
+        <div class={ if *open { "dropdown is-active" } else { "dropdown" } }>
+            <div class="dropdown-trigger" style="display:inline-grid">
+                <span style="
+                    grid-area: 1/1;
+                    visibility: hidden;
+                    overflow: hidden;
+                    pointer-events: none;
+                    white-space: nowrap;
+                    padding: calc(.5em - 1px) 2.75em calc(.5em - 1px) calc(1em - 1px);
+                    border: 1px solid transparent;
+                    line-height: 1.5;
+                    box-sizing: border-box;
+                ">
+                    { sizer_width }
+                </span>
+                <span style="
+                    grid-area: 1/1;
+                    visibility: hidden;
+                    overflow: hidden;
+                    pointer-events: none;
+                    white-space: nowrap;
+                    padding: calc(.5em - 1px) 2.75em calc(.5em - 1px) calc(1em - 1px);
+                    border: 1px solid transparent;
+                    line-height: 1.5;
+                    box-sizing: border-box;
+                ">
+                    { multiline(sizer_tallest) }
+                </span>
+                <button
+                    class="button is-justify-content-space-between"
+                    style="grid-area:1/1; width:100%; height:100%"
+                    onfocus={on_focus}
+                    onblur={on_blur}
+                >
+                    <span>{ multiline(&current_label_str) }</span>
+                    <span class="material-symbols-outlined">{ "expand_more" }</span>
+                </button>
+            </div>
+            <div class="dropdown-menu" style="min-width:100%">
+                <div class="dropdown-content" style="max-height:60vh; overflow-y:auto">
+                    { items }
+                </div>
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,314 @@
+//! What a Mystery Box or Golden Mystery Box holds (`Utility.getTreasureFromGeode`).
+//!
+//! Boxes use the geode code path, but seed `CreateRandom(MysteryBoxesOpened,
+//! uniqueIDForThisGame / 2)` from the number of boxes of either kind opened,
+//! this one included, and then burn the same two runs of doubles. Once more
+//! than ten boxes have been opened, and always for golden boxes, a chain of
+//! rare rolls comes first. After that comes the good table, which golden
+//! boxes always reach and plain boxes reach one time in ten. Everything else
+//! comes from the common table. The tables are hardcoded in the game.
+//!
+//! Crops and seeds of the current season are chosen with the game's shared
+//! random, not with the box's random, so only the kind of reward is
+//! predicted for them.
+
+use crate::checker::CheckError;
+use crate::geodes::GeodeTreasure;
+use crate::observation::Platform;
+use crate::prng::{create_random, Prng, SeedingMode};
+
+/// Stands for whichever low-grade crop of the season the box gives.
+pub const SEASONAL_CROP: &str = "SeasonalCrop";
+/// Stands for whichever seeds of the season the box gives.
+pub const SEASONAL_SEEDS: &str = "SeasonalSeeds";
+
+/// Names of rewards that are not numeric objects.
+pub const REWARD_NAMES: &[(&str, &str)] = &[
+    (SEASONAL_CROP, "Low-grade crop of the season"),
+    (SEASONAL_SEEDS, "Seeds of the season"),
+    ("(O)GoldenAnimalCracker", "Golden Animal Cracker"),
+    ("(O)Book_Mystery", "Book of Mysteries"),
+    ("(O)PurpleBook", "Book Of Stars"),
+    ("(O)MysteryBox", "Mystery Box"),
+    ("(O)MixedFlowerSeeds", "Mixed Flower Seeds"),
+    ("(O)SkillBook_0", "Stardew Valley Almanac"),
+    ("(O)SkillBook_1", "Bait And Bobber"),
+    ("(O)SkillBook_2", "Woodcutter's Weekly"),
+    ("(O)SkillBook_3", "Mining Monthly"),
+    ("(O)SkillBook_4", "Combat Quarterly"),
+    ("(H)MysteryHat", "Mystery Hat"),
+    ("(S)MysteryShirt", "Mystery Shirt"),
+    ("(WP)MoreWalls:11", "Mystery wallpaper"),
+    ("(W)60", "Ossified Blade"),
+];
+
+/// Which kind of box was opened.
+pub fn box_name(golden: bool) -> &'static str {
+    if golden {
+        "Golden Mystery Box"
+    } else {
+        "Mystery Box"
+    }
+}
+
+/// The reward from opening box number `index` (1-based, over every box of
+/// either kind opened on the save).
+pub fn mystery_box_reward(
+    platform: Platform,
+    seeding: SeedingMode,
+    id: u64,
+    golden: bool,
+    index: u32,
+) -> Result<GeodeTreasure, CheckError> {
+    let mut reward = box_reward(platform, seeding, id, golden, index, false)?;
+    if !golden {
+        return Ok(reward);
+    }
+    // With a skill mastered, golden boxes roll for a Golden Animal Cracker
+    // before anything else, which shifts every later roll.
+    let mastered = box_reward(platform, seeding, id, golden, index, true)?;
+    if mastered != reward {
+        append_otherwise(&mut reward, "no skill mastered yet", mastered);
+    }
+    Ok(reward)
+}
+
+/// Adds a fallback after the last one already in `treasure`'s chain.
+fn append_otherwise(
+    treasure: &mut GeodeTreasure,
+    condition: &'static str,
+    fallback: GeodeTreasure,
+) {
+    match &mut treasure.otherwise {
+        Some((_, next)) => append_otherwise(next, condition, fallback),
+        None => treasure.otherwise = Some((condition, Box::new(fallback))),
+    }
+}
+
+fn box_reward(
+    platform: Platform,
+    seeding: SeedingMode,
+    id: u64,
+    golden: bool,
+    index: u32,
+    mastered: bool,
+) -> Result<GeodeTreasure, CheckError> {
+    let mut rng = create_random(platform, seeding, &[index as f64, (id / 2) as f64])?;
+    for _ in 0..2 {
+        let prewarm = rng.gen_range(1..10)?;
+        for _ in 0..prewarm {
+            rng.gen_float()?;
+        }
+    }
+
+    if index > 10 || golden {
+        if let Some(reward) = rare_reward(rng.as_mut(), golden, index, mastered)? {
+            return Ok(reward);
+        }
+        if rng.gen_float()? < 0.1 || golden {
+            return good_reward(rng.as_mut());
+        }
+    }
+    common_reward(rng.as_mut())
+}
+
+/// The chain of rare rolls; golden boxes have twice the odds.
+fn rare_reward(
+    rng: &mut dyn Prng,
+    golden: bool,
+    index: u32,
+    mastered: bool,
+) -> Result<Option<GeodeTreasure>, CheckError> {
+    let rare_mod = if golden { 2.0 } else { 1.0 };
+    if golden && mastered && rng.gen_float()? < 0.005 {
+        return Ok(Some(reward("(O)GoldenAnimalCracker", 1)));
+    }
+    for (item, chance) in [("(O)279", 0.002), ("(O)74", 0.004), ("(O)166", 0.008)] {
+        if rng.gen_float()? < chance * rare_mod {
+            return Ok(Some(reward(item, 1)));
+        }
+    }
+
+    // Until the Book of Mysteries is found its odds grow with every box, and
+    // the first hit always gives it.
+    let roll = rng.gen_float()?;
+    let base = 0.01 * rare_mod;
+    let growing = base + 0.0004 * index as f64;
+    if roll < growing {
+        let once_found = if roll < base {
+            Some(reward(
+                choose(rng, &["(O)PurpleBook", "(O)Book_Mystery"])?,
+                1,
+            ))
+        } else {
+            rare_reward_after_books(rng, rare_mod)?
+        };
+        let mut first = reward("(O)Book_Mystery", 1);
+        if let Some(once_found) = once_found.filter(|r| *r != first) {
+            first.otherwise = Some((
+                "the Book of Mysteries has not been found yet",
+                Box::new(once_found),
+            ));
+        }
+        return Ok(Some(first));
+    }
+    rare_reward_after_books(rng, rare_mod)
+}
+
+fn rare_reward_after_books(
+    rng: &mut dyn Prng,
+    rare_mod: f64,
+) -> Result<Option<GeodeTreasure>, CheckError> {
+    if rng.gen_float()? < 0.01 * rare_mod {
+        return Ok(Some(reward(choose(rng, &["(O)797", "(O)373"])?, 1)));
+    }
+    for item in ["(H)MysteryHat", "(S)MysteryShirt", "(WP)MoreWalls:11"] {
+        if rng.gen_float()? < 0.01 * rare_mod {
+            return Ok(Some(reward(item, 1)));
+        }
+    }
+    Ok(None)
+}
+
+fn good_reward(rng: &mut dyn Prng) -> Result<GeodeTreasure, CheckError> {
+    Ok(match rng.gen_range(0..15)? {
+        0 => reward("(O)288", 5),
+        1 => reward("(O)253", 3),
+        2 => {
+            // Tackle needs fishing level 6; the coin is only flipped then.
+            if rng.gen_float()? < 0.5 {
+                GeodeTreasure {
+                    otherwise: Some(("fishing level 6 reached", Box::new(reward("(O)242", 2)))),
+                    ..reward(choose(rng, &["(O)687", "(O)695"])?, 1)
+                }
+            } else {
+                reward("(O)242", 2)
+            }
+        }
+        3 => reward("(O)204", 2),
+        4 => reward("(O)369", 20),
+        5 => reward("(O)466", 20),
+        6 => reward("(O)773", 2),
+        7 => reward("(O)688", 3),
+        8 => reward(format!("(O){}", rng.gen_range(628..634)?), 1),
+        9 => reward(SEASONAL_CROP, 20),
+        10 => {
+            if rng.gen_float()? < 0.5 {
+                reward("(W)60", 1)
+            } else {
+                reward(choose(rng, &["(O)533", "(O)534"])?, 1)
+            }
+        }
+        11 => reward("(O)621", 1),
+        12 => reward("(O)MysteryBox", rng.gen_range(3..5)? as u32),
+        13 => reward(format!("(O)SkillBook_{}", rng.gen_range(0..5)?), 1),
+        _ => reward(SEASONAL_SEEDS, 8),
+    })
+}
+
+fn common_reward(rng: &mut dyn Prng) -> Result<GeodeTreasure, CheckError> {
+    Ok(match rng.gen_range(0..14)? {
+        0 => reward("(O)395", 3),
+        1 => reward("(O)287", 5),
+        2 => reward(SEASONAL_CROP, 8),
+        3 => reward(format!("(O){}", rng.gen_range(727..734)?), 1),
+        4 => reward(format!("(O){}", rng.gen_range(194..240)?), 1),
+        5 => reward("(O)709", 10),
+        6 => reward("(O)369", 10),
+        7 => reward("(O)466", 10),
+        8 => reward("(O)688", 1),
+        9 => reward("(O)689", 1),
+        10 => reward("(O)770", 10),
+        11 => reward("(O)MixedFlowerSeeds", 10),
+        12 => reward(SEASONAL_SEEDS, 4),
+        _ => reward("(O)MysteryBox", 2),
+    })
+}
+
+fn reward(item: impl Into<String>, stack: u32) -> GeodeTreasure {
+    GeodeTreasure {
+        item: item.into(),
+        stack,
+        otherwise: None,
+    }
+}
+
+/// C# `Random.Choose`.
+fn choose<'a>(rng: &mut dyn Prng, items: &[&'a str]) -> Result<&'a str, CheckError> {
+    Ok(items[rng.gen_range(0..items.len() as i32)? as usize])
+}
+
+/// Every reward a box of this kind can give, rare ones first.
+pub fn possible_rewards(golden: bool) -> Vec<String> {
+    let mut items: Vec<String> = Vec::new();
+    let mut add = |item: String| {
+        if !items.contains(&item) {
+            items.push(item);
+        }
+    };
+    let rare = [
+        "(O)279",
+        "(O)74",
+        "(O)166",
+        "(O)Book_Mystery",
+        "(O)PurpleBook",
+        "(O)797",
+        "(O)373",
+        "(H)MysteryHat",
+        "(S)MysteryShirt",
+        "(WP)MoreWalls:11",
+    ];
+    let good = [
+        "(O)288",
+        "(O)253",
+        "(O)687",
+        "(O)695",
+        "(O)242",
+        "(O)204",
+        "(O)369",
+        "(O)466",
+        "(O)773",
+        "(O)688",
+        "(W)60",
+        "(O)533",
+        "(O)534",
+        "(O)621",
+        "(O)MysteryBox",
+    ];
+    if golden {
+        add("(O)GoldenAnimalCracker".to_string());
+    }
+    rare.iter()
+        .chain(&good)
+        .for_each(|item| add(item.to_string()));
+    (628..634).for_each(|id| add(format!("(O){id}")));
+    (0..5).for_each(|skill| add(format!("(O)SkillBook_{skill}")));
+    add(SEASONAL_CROP.to_string());
+    add(SEASONAL_SEEDS.to_string());
+    if !golden {
+        let common = [
+            "(O)395",
+            "(O)287",
+            "(O)709",
+            "(O)689",
+            "(O)770",
+            "(O)MixedFlowerSeeds",
+        ];
+        common.iter().for_each(|item| add(item.to_string()));
+        (727..734)
+            .chain(194..240)
+            .for_each(|id| add(format!("(O){id}")));
+    }
+    items
+}
+
+/// Rough chance that a random ID gives `item`: uniform over the rewards the
+/// box can give.
+pub fn mystery_box_pass_rate(golden: bool, item: &str) -> f64 {
+    let items = possible_rewards(golden);
+    if items.iter().any(|i| i == item) {
+        1.0 / items.len() as f64
+    } else {
+        0.0
+    }
+}
@@ -0,0 +1,147 @@
+//! Overnight farm events (`Utility.pickFarmEvent`).
+//!
+//! The night before each day the game seeds `CreateDaySaveRandom()` with the
+//! new day, discards ten doubles, then rolls for each random event in turn,
+//! taking the first that fires and whose conditions hold. Weddings and
+//! Community Center world changes take precedence and are not modelled.
+
+use serde::{Deserialize, Serialize};
+
+use crate::checker::CheckError;
+use crate::observation::{calendar_date, Platform, Season};
+use crate::prng::{create_day_save_random, SeedingMode};
+
+/// The night of the earthquake that opens the railroad (Summer 3, year 1).
+const EARTHQUAKE_DAYS_PLAYED: u32 = 31;
+
+/// What happened overnight, as seen the next morning.
+#[derive(Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum NightEvent {
+    None,
+    Fairy,
+    Witch,
+    Meteorite,
+    Owl,
+    Capsule,
+    Earthquake,
+}
+
+impl NightEvent {
+    pub const ALL: [NightEvent; 7] = [
+        NightEvent::None,
+        NightEvent::Fairy,
+        NightEvent::Witch,
+        NightEvent::Meteorite,
+        NightEvent::Owl,
+        NightEvent::Capsule,
+        NightEvent::Earthquake,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            NightEvent::None => "No event",
+            NightEvent::Fairy => "Crop fairy",
+            NightEvent::Witch => "Witch",
+            NightEvent::Meteorite => "Meteorite",
+            NightEvent::Owl => "Stone owl",
+            NightEvent::Capsule => "Strange capsule",
+            NightEvent::Earthquake => "Earthquake",
+        }
+    }
+
+    pub fn key(self) -> &'static str {
+        match self {
+            NightEvent::None => "none",
+            NightEvent::Fairy => "fairy",
+            NightEvent::Witch => "witch",
+            NightEvent::Meteorite => "meteorite",
+            NightEvent::Owl => "owl",
+            NightEvent::Capsule => "capsule",
+            NightEvent::Earthquake => "earthquake",
+        }
+    }
+
+    pub fn from_key(s: &str) -> Option<Self> {
+        NightEvent::ALL.into_iter().find(|event| event.key() == s)
+    }
+}
+
+/// The event on the night before `days_played`. `capsule_seen` is whether the
+/// strange capsule has already landed on this save (it only comes once).
+pub fn night_event(
+    platform: Platform,
+    seeding: SeedingMode,
+    id: u64,
+    days_played: u32,
+    capsule_seen: bool,
+) -> Result<NightEvent, CheckError> {
+    // A new save starts in the morning; there is no night before day 1.
+    if days_played <= 1 {
+        return Ok(NightEvent::None);
+    }
+    if days_played == EARTHQUAKE_DAYS_PLAYED {
+        return Ok(NightEvent::Earthquake);
+    }
+
+    let (_, season, year) = calendar_date(days_played);
+    let mut rng = create_day_save_random(platform, seeding, days_played, id, 0.0, 0.0, 0.0)?;
+    for _ in 0..10 {
+        rng.gen_float()?;
+    }
+
+    // Each roll is made even when its conditions fail.
+    if rng.gen_float()? < 0.01 && season != Season::Winter {
+        return Ok(NightEvent::Fairy);
+    }
+    if rng.gen_float()? < 0.01 {
+        return Ok(NightEvent::Witch);
+    }
+    if rng.gen_float()? < 0.01 {
+        return Ok(NightEvent::Meteorite);
+    }
+    if rng.gen_float()? < 0.005 {
+        return Ok(NightEvent::Owl);
+    }
+    if rng.gen_float()? < 0.008 && year > 1 && !capsule_seen {
+        return Ok(NightEvent::Capsule);
+    }
+    Ok(NightEvent::None)
+}
+
+/// Chance that a random ID gives `event` on the night before `days_played`.
+pub fn night_event_pass_rate(event: NightEvent, days_played: u32, capsule_seen: bool) -> f64 {
+    if days_played <= 1 {
+        return if event == NightEvent::None { 1.0 } else { 0.0 };
+    }
+    if days_played == EARTHQUAKE_DAYS_PLAYED {
+        return if event == NightEvent::Earthquake { 1.0 } else { 0.0 };
+    }
+
+    let (_, season, year) = calendar_date(days_played);
+    let fairy = if season == Season::Winter { 0.0 } else { 0.01 };
+    let capsule = if year > 1 && !capsule_seen { 0.008 } else { 0.0 };
+
+    // Probability that every earlier roll missed, then this one fired.
+    let mut remaining = 1.0;
+    let mut chance_of = |p: f64| {
+        let rate = remaining * p;
+        remaining -= rate;
+        rate
+    };
+    let rates = [
+        (NightEvent::Fairy, chance_of(fairy)),
+        (NightEvent::Witch, chance_of(0.01)),
+        (NightEvent::Meteorite, chance_of(0.01)),
+        (NightEvent::Owl, chance_of(0.005)),
+        (NightEvent::Capsule, chance_of(capsule)),
+    ];
+
+    match event {
+        NightEvent::None => 1.0 - rates.iter().map(|(_, rate)| rate).sum::<f64>(),
+        NightEvent::Earthquake => 0.0,
+        _ => rates
+            .iter()
+            .find(|(e, _)| *e == event)
+            .map_or(0.0, |(_, rate)| *rate),
+    }
+}
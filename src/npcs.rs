@@ -0,0 +1,55 @@
+//! The NPC table generated from `Data/Characters`, in data order.
+//!
+//! Festival logic that picks villagers walks this list, so the order matters
+//! as much as the entries. A `CanSocialize` query is only understood when it
+//! is a plain `TRUE` or `FALSE`; anything else needs save state the cracker
+//! does not have and is treated as met.
+//!
+//! Item-delivery and socialize quests pick from the player's friendship list
+//! in the save, in the order the villagers were met, rather than from this
+//! table. Their targets are out of scope: the table only records who may be
+//! one (`item_delivery_quests`).
+
+use crate::codegen::{GameData, NpcData};
+
+/// The NPC with internal name `name`.
+pub fn npc<'a>(data: &'a GameData, name: &str) -> Option<&'a NpcData> {
+    data.npcs.iter().find(|npc| npc.name == name)
+}
+
+/// Display name for `name`, or `name` itself if it is not in the table.
+pub fn npc_label<'a>(data: &'a GameData, name: &'a str) -> &'a str {
+    npc(data, name).map_or(name, |npc| npc.display_name)
+}
+
+/// Whether the NPC has a friendship level the player can raise.
+fn can_socialize(npc: &NpcData) -> bool {
+    npc.can_socialize
+        .is_none_or(|query| !query.trim().eq_ignore_ascii_case("FALSE"))
+}
+
+/// Villagers the player can befriend, in data order.
+pub fn social_npcs(data: &'static GameData) -> impl Iterator<Item = &'static NpcData> {
+    data.npcs.iter().filter(|npc| can_socialize(npc))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn table_matches_characters_json() {
+        let data = GameData::default_set();
+        let abigail = npc(data, "Abigail").unwrap();
+        // Fall 13; seasons are stored as indices from Spring = 0.
+        assert_eq!(abigail.birthday, Some((2, 13)));
+        assert_eq!(abigail.home_region, "Town");
+        assert!(abigail.can_receive_gifts);
+        assert_eq!(npc(data, "Krobus").unwrap().birthday, Some((3, 1)));
+        assert_eq!(npc_label(data, "Wizard"), "Wizard");
+        assert_eq!(npc_label(data, "Nobody"), "Nobody");
+        // The game's data order starts with Abigail, Caroline, Clint.
+        let first: Vec<&str> = data.npcs.iter().take(3).map(|npc| npc.name).collect();
+        assert_eq!(first, ["Abigail", "Caroline", "Clint"]);
+    }
+}
@@ -1,5 +1,10 @@
+use std::ops::Range;
+
 use serde::{Deserialize, Serialize};
 
+use crate::codegen::GameData;
+use crate::sources::{ObservationSource, SourceObservation};
+
 // Platform moved here from traveling_merchant.rs.
 // Switch uses Jkiss as the backing Random; PC uses MsCorLibRandom.
 #[derive(Clone, Copy, PartialEq, Serialize, Deserialize)]
@@ -8,6 +13,18 @@ pub enum Platform {
     Switch,
 }
 
+impl Platform {
+    /// Every supported platform, for cracks where the player's platform is unknown.
+    pub const ALL: [Platform; 2] = [Platform::PC, Platform::Switch];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Platform::PC => "PC",
+            Platform::Switch => "Switch",
+        }
+    }
+}
+
 #[derive(Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum Season {
     Spring,
@@ -37,6 +54,24 @@ impl Season {
         }
     }
 
+    pub fn from_index(index: u32) -> Self {
+        match index % 4 {
+            0 => Season::Spring,
+            1 => Season::Summer,
+            2 => Season::Fall,
+            _ => Season::Winter,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Season::Spring => "Spring",
+            Season::Summer => "Summer",
+            Season::Fall => "Fall",
+            Season::Winter => "Winter",
+        }
+    }
+
     pub fn from_key(s: &str) -> Option<Self> {
         match s {
             "spring" => Some(Season::Spring),
@@ -48,22 +83,64 @@ impl Season {
     }
 }
 
+/// The NPC who posted a FishingQuest.
+#[derive(Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum FishingNpc {
+    Demetrius,
+    Willy,
+}
+
+impl FishingNpc {
+    /// next_bool() returning false selects Demetrius (sub_pool=false),
+    /// and next_bool() returning true selects Willy (sub_pool=true).
+    pub fn from_sub_pool(sub_pool: bool) -> Self {
+        if sub_pool {
+            FishingNpc::Willy
+        } else {
+            FishingNpc::Demetrius
+        }
+    }
+
+    /// Internal name, as in the NPC table.
+    pub fn name(self) -> &'static str {
+        match self {
+            FishingNpc::Demetrius => "Demetrius",
+            FishingNpc::Willy => "Willy",
+        }
+    }
+
+    /// Stable string key used to round-trip through `DropdownSelect`.
+    pub fn key(self) -> &'static str {
+        match self {
+            FishingNpc::Demetrius => "demetrius",
+            FishingNpc::Willy => "willy",
+        }
+    }
+
+    pub fn from_key(s: &str) -> Option<Self> {
+        match s {
+            "demetrius" => Some(FishingNpc::Demetrius),
+            "willy" => Some(FishingNpc::Willy),
+            _ => None,
+        }
+    }
+}
+
 /// Content for a FishingQuest observation.
+/// Each field is a set of allowed values; the content matches if the generated
+/// NPC and fish are both in their respective sets.
 #[derive(Clone, Serialize, Deserialize)]
 pub struct FishingContent {
-    /// true = Demetrius pool, false = Willy pool.
-    /// Note: next_bool() returning false selects Demetrius (sub_pool=false),
-    /// and next_bool() returning true selects Willy (sub_pool=true).
-    pub demetrius: bool,
-    pub fish_id: u32,
+    pub npcs: Vec<FishingNpc>,
+    pub fish_ids: Vec<u32>,
 }
 
 /// Content for a ResourceCollectionQuest observation.
 #[derive(Clone, Serialize, Deserialize)]
 pub struct ResourceContent {
-    /// The item ID observed (e.g. 378=Copper Ore, 380=Iron Ore, 382=Coal,
-    /// 384=Gold Ore, 388=Wood, 390=Stone).
-    pub item_id: u32,
+    /// The item IDs the quest may have asked for (e.g. 378=Copper Ore, 380=Iron Ore,
+    /// 382=Coal, 384=Gold Ore, 388=Wood, 390=Stone).
+    pub item_ids: Vec<u32>,
 }
 
 #[derive(Clone, Serialize, Deserialize)]
@@ -89,10 +166,58 @@ pub struct Observation {
     /// Day within the season (1–28).
     pub day_of_month: u8,
     pub season: Season,
-    pub quest_content: QuestContent,
+    /// What was seen, from any registered source.
+    pub source: SourceObservation,
+}
+
+/// Total days played (1-indexed) for a calendar date.
+pub fn days_played(day_of_month: u8, season: Season, year: u32) -> u32 {
+    (year - 1) * 112 + season.index() * 28 + day_of_month as u32
+}
+
+/// Latest year a date or an unknown-date group bound may name.
+pub const MAX_YEAR: u32 = 100;
+
+/// The first `days_played` of `year`, or `None` if the year is 0 or after
+/// `MAX_YEAR`.
+pub fn year_first_day(year: u32) -> Option<u32> {
+    year_last_day(year)?.checked_sub(111)
+}
+
+/// The last `days_played` of `year`, or `None` if the year is 0 or after
+/// `MAX_YEAR`.
+pub fn year_last_day(year: u32) -> Option<u32> {
+    (1..=MAX_YEAR).contains(&year).then(|| year * 112)
+}
+
+/// Inverse of `days_played`: `(day_of_month, season, year)`.
+pub fn calendar_date(days_played: u32) -> (u8, Season, u32) {
+    let zero_based = days_played.saturating_sub(1);
+    (
+        (zero_based % 28 + 1) as u8,
+        Season::from_index(zero_based / 28),
+        zero_based / 112 + 1,
+    )
+}
+
+/// Human-readable date, e.g. "Spring 5, Year 1".
+pub fn describe_date(days_played: u32) -> String {
+    let (day, season, year) = calendar_date(days_played);
+    format!("{} {}, Year {}", season.name(), day, year)
 }
 
 impl Observation {
+    /// Builds an observation for the given day, deriving the calendar fields.
+    pub fn on_day(days_played: u32, source: SourceObservation) -> Self {
+        let (day_of_month, season, _) = calendar_date(days_played);
+        Observation {
+            days_played,
+            day_of_month,
+            season,
+            source,
+        }
+    }
+
     /// Mondays are days 1, 8, 15, 22 of each season.
     pub fn is_monday(&self) -> bool {
         matches!(self.day_of_month, 1 | 8 | 15 | 22)
@@ -100,14 +225,100 @@ impl Observation {
 
     /// Estimated fraction of candidate IDs that PASS this observation's filter.
     /// Lower = more discriminating = should be checked first in the pipeline.
+    pub fn pass_rate(&self, data: &'static GameData) -> f64 {
+        self.source.pass_rate(data, self.days_played)
+    }
+}
+
+impl QuestContent {
+    /// The quest-type roll `d` this content needs, as in `check_type`.
+    pub fn roll_range(&self) -> Range<f64> {
+        match self {
+            QuestContent::None => 0.08..0.5,
+            QuestContent::Fishing(_) => 0.5..0.6,
+            QuestContent::ResourceCollection(_) => 0.0..0.08,
+            QuestContent::ItemDelivery => 0.6..1.0,
+            QuestContent::Socialize => 0.6..0.66,
+            QuestContent::SlayMonster => 0.08..0.2,
+        }
+    }
+
     pub fn pass_rate(&self) -> f64 {
-        match &self.quest_content {
+        match self {
             QuestContent::None => 0.42,           // d in [0.08, 0.5) = 42% of range
-            QuestContent::Fishing(_) => 0.10 / 9.0, // ~10% type rate × ~1/9 fish pool
-            QuestContent::ResourceCollection(_) => 0.08 / 6.0, // ~8% type × 1/6 resource
+            // ~10% type rate × ~1/9 per allowed fish/NPC pair
+            QuestContent::Fishing(c) => {
+                (0.10 / 9.0 * (c.npcs.len() * c.fish_ids.len()) as f64).min(0.10)
+            }
+            // ~8% type rate × 1/6 per allowed resource
+            QuestContent::ResourceCollection(c) => {
+                (0.08 / 6.0 * c.item_ids.len() as f64).min(0.08)
+            }
             QuestContent::ItemDelivery => 0.40,   // d in [0.6, 1.0)
             QuestContent::Socialize => 0.015,     // d in [0.6, 0.66) × ~1/4 Mondays
             QuestContent::SlayMonster => 0.12,    // d in [0.08, 0.2)
         }
     }
 }
+
+/// Default upper bound for a group's start day when none is given: five in-game years.
+pub const DEFAULT_MAX_GROUP_START: u32 = 5 * 112;
+
+/// Last day any group observation may fall on: the end of `MAX_YEAR`.
+pub const MAX_GROUP_DAY: u32 = MAX_YEAR * 112;
+
+/// An observation whose date is only known relative to the rest of its group.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct FloatingObservation {
+    /// Days after the group's start day (the start itself is offset 0).
+    pub offset: u32,
+    pub source: SourceObservation,
+}
+
+/// Observations with known spacing but an unknown absolute date. The group's
+/// start `days_played` is searched together with the game ID.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ObservationGroup {
+    pub observations: Vec<FloatingObservation>,
+    /// Earliest start day to try (days_played, ≥1).
+    pub min_start: u32,
+    /// Latest start day to try; `None` means `DEFAULT_MAX_GROUP_START`.
+    pub max_start: Option<u32>,
+}
+
+impl ObservationGroup {
+    /// Every start day the search will try, never past `MAX_GROUP_DAY`.
+    pub fn starts(&self) -> std::ops::RangeInclusive<u32> {
+        let max_start = self.max_start.unwrap_or(DEFAULT_MAX_GROUP_START);
+        self.min_start.max(1)..=max_start.min(MAX_GROUP_DAY)
+    }
+
+    /// The day the group's last observation falls on if it starts on
+    /// `max_start`, or `None` if that does not fit in a `u32`.
+    pub fn last_day(&self) -> Option<u32> {
+        let max_offset = self.observations.iter().map(|o| o.offset).max().unwrap_or(0);
+        self.max_start
+            .unwrap_or(DEFAULT_MAX_GROUP_START)
+            .checked_add(max_offset)
+    }
+
+    /// The group's observations placed on concrete days, assuming it starts on `start`.
+    pub fn at(&self, start: u32) -> Vec<Observation> {
+        self.observations
+            .iter()
+            .map(|o| Observation::on_day(start.saturating_add(o.offset), o.source.clone()))
+            .collect()
+    }
+
+    /// Estimated fraction of candidate IDs for which at least one start passes.
+    /// Day 1 stands in for the unknown start.
+    pub fn pass_rate(&self, data: &'static GameData) -> f64 {
+        let per_start: f64 = self
+            .observations
+            .iter()
+            .map(|o| o.source.pass_rate(data, 1 + o.offset))
+            .product();
+        let starts = self.starts().count() as f64;
+        (per_start * starts).min(1.0)
+    }
+}
@@ -1,78 +1,10 @@
 use web_sys::HtmlInputElement;
 use yew::prelude::*;
 
-use crate::codegen::OBJECTS;
+use crate::codegen::GameData;
 use crate::dropdown::DropdownSelect;
-use crate::observation::{FishingContent, Observation, QuestContent, ResourceContent, Season};
-
-// All fish IDs that appear in any season/NPC pool.
-const FISH_IDS: &[u32] = &[
-    128, 129, 130, 131, 136, 137, 138, 139, 141, 142, 143, 144, 145, 146, 147, 149, 150, 151, 699,
-    702, 705,
-];
-
-// Ore items requested by Clint: "– [X]g on delivery  – Keep the ores after Clint inspects them."
-const ORE_ITEMS: &[(u32, &str)] = &[
-    (378, "Copper Ore"),
-    (380, "Iron Ore"),
-    (382, "Coal"),
-    (384, "Gold Ore"),
-];
-
-// Wood/stone items requested by Robin: "– [X]g on delivery  (only)"
-const WOOD_STONE_ITEMS: &[(u32, &str)] = &[(388, "Wood"), (390, "Stone")];
-
-/// UI-facing quest type — named after the in-game bullet-point patterns the
-/// user sees on the notice board, not the internal quest class names.
-#[derive(Clone, Copy, PartialEq)]
-pub enum QuestTypeUI {
-    NoQuest,
-    Fishing,
-    MonsterHunt,
-    OreGathering,
-    WoodStoneGathering,
-    ItemDelivery,
-    GreetEveryone,
-}
-
-impl QuestTypeUI {
-    fn label(self) -> &'static str {
-        match self {
-            QuestTypeUI::NoQuest => "No quest",
-            QuestTypeUI::Fishing => "- [..]g reward\n- You get to keep the fish.",
-            QuestTypeUI::MonsterHunt => "- [...]g reward.",
-            QuestTypeUI::OreGathering => "- [...]g on delivery.\n- You can keep the ores [...].",
-            QuestTypeUI::WoodStoneGathering => "- [...]g on delivery.",
-            QuestTypeUI::ItemDelivery => "- [...]g on delivery.\n- [...] happy/thankful/pleased.",
-            QuestTypeUI::GreetEveryone => "- Everyone will like you a little more.",
-        }
-    }
-
-    fn key(self) -> &'static str {
-        match self {
-            QuestTypeUI::NoQuest => "no-quest",
-            QuestTypeUI::Fishing => "fishing",
-            QuestTypeUI::MonsterHunt => "monster-hunt",
-            QuestTypeUI::OreGathering => "ore-gathering",
-            QuestTypeUI::WoodStoneGathering => "wood-stone",
-            QuestTypeUI::ItemDelivery => "item-delivery",
-            QuestTypeUI::GreetEveryone => "greet-everyone",
-        }
-    }
-
-    fn from_key(s: &str) -> Option<Self> {
-        match s {
-            "no-quest" => Some(QuestTypeUI::NoQuest),
-            "fishing" => Some(QuestTypeUI::Fishing),
-            "monster-hunt" => Some(QuestTypeUI::MonsterHunt),
-            "ore-gathering" => Some(QuestTypeUI::OreGathering),
-            "wood-stone" => Some(QuestTypeUI::WoodStoneGathering),
-            "item-delivery" => Some(QuestTypeUI::ItemDelivery),
-            "greet-everyone" => Some(QuestTypeUI::GreetEveryone),
-            _ => None,
-        }
-    }
-}
+use crate::forms::{source_kind_options, FormState, SourceForm, SourceKind};
+use crate::observation::{days_played, year_last_day, FloatingObservation, Observation, Season};
 
 /// Data state for a single observation row — stored in the parent App so it
 /// survives re-renders (adding / removing rows).
@@ -83,48 +15,72 @@ pub struct RowDisplayState {
     pub season: Option<Season>,
     pub year_value: String,
     pub year: Option<u32>,
-    pub quest_type: Option<QuestTypeUI>,
-    pub fish_npc_demetrius: Option<bool>,
-    pub fish_id: Option<u32>,
-    pub resource_item_id: Option<u32>,
+    /// The absolute date is unknown; only `offset` relative to the other
+    /// unknown-date rows is.
+    pub date_unknown: bool,
+    /// Which unknown-date group the row belongs to, 0-based.
+    pub group: usize,
+    pub offset_value: String,
+    pub offset: Option<u32>,
+    /// What was observed, in the form of the row's selected source kind.
+    pub form: FormState,
+}
+
+/// A complete row, either pinned to a date or floating within an unknown-date
+/// group (given by index).
+pub enum RowObservation {
+    Dated(Observation),
+    Undated(usize, FloatingObservation),
 }
 
-pub fn build_observation(s: &RowDisplayState) -> Option<Observation> {
+pub fn build_observation(s: &RowDisplayState) -> Option<RowObservation> {
+    let source = s.form.build()?;
+
+    // Fixed at save creation; any day will do.
+    if !s.form.kind().dated() {
+        return Some(RowObservation::Dated(Observation::on_day(1, source)));
+    }
+
+    if s.date_unknown {
+        return Some(RowObservation::Undated(
+            s.group,
+            FloatingObservation {
+                offset: s.offset?,
+                source,
+            },
+        ));
+    }
+
     let day = s.day?;
     let season = s.season?;
     let year = s.year?;
-    let quest_type = s.quest_type?;
-
-    let days_played = (year - 1) * 112 + season.index() * 28 + day as u32;
-
-    let quest_content = match quest_type {
-        QuestTypeUI::NoQuest => QuestContent::None,
-        QuestTypeUI::ItemDelivery => QuestContent::ItemDelivery,
-        QuestTypeUI::GreetEveryone => QuestContent::Socialize,
-        QuestTypeUI::MonsterHunt => QuestContent::SlayMonster,
-        QuestTypeUI::Fishing => {
-            let demetrius = s.fish_npc_demetrius?;
-            let fish_id = s.fish_id?;
-            QuestContent::Fishing(FishingContent { demetrius, fish_id })
-        }
-        QuestTypeUI::OreGathering | QuestTypeUI::WoodStoneGathering => {
-            let item_id = s.resource_item_id?;
-            QuestContent::ResourceCollection(ResourceContent { item_id })
-        }
-    };
 
-    Some(Observation {
-        days_played,
+    Some(RowObservation::Dated(Observation {
+        days_played: days_played(day, season, year),
         day_of_month: day,
         season,
-        quest_content,
-    })
+        source,
+    }))
 }
 
 // ---------------------------------------------------------------------------
 // Static option lists
 // ---------------------------------------------------------------------------
 
+/// Label shown for unknown-date group `index`: A, B, C, ...
+pub fn group_label(index: usize) -> String {
+    match u8::try_from(index).ok().filter(|&i| i < 26) {
+        Some(i) => char::from(b'A' + i).to_string(),
+        None => (index + 1).to_string(),
+    }
+}
+
+fn group_options(count: usize) -> Vec<(String, String)> {
+    (0..count)
+        .map(|i| (i.to_string(), format!("Group {}", group_label(i))))
+        .collect()
+}
+
 fn season_options() -> Vec<(String, String)> {
     vec![
         ("spring".into(), "Spring".into()),
@@ -134,62 +90,17 @@ fn season_options() -> Vec<(String, String)> {
     ]
 }
 
-fn quest_type_options() -> Vec<(String, String)> {
-    [
-        QuestTypeUI::NoQuest,
-        QuestTypeUI::Fishing,
-        QuestTypeUI::OreGathering,
-        QuestTypeUI::ItemDelivery,
-        QuestTypeUI::WoodStoneGathering,
-        QuestTypeUI::MonsterHunt,
-        QuestTypeUI::GreetEveryone,
-    ]
-    .iter()
-    .map(|qt| (qt.key().to_string(), qt.label().to_string()))
-    .collect()
-}
-
-fn fish_npc_options() -> Vec<(String, String)> {
-    vec![
-        ("demetrius".into(), "Demetrius".into()),
-        ("willy".into(), "Willy".into()),
-    ]
-}
-
-fn fish_options() -> Vec<(String, String)> {
-    let mut opts: Vec<(String, String)> = FISH_IDS
-        .iter()
-        .filter_map(|&id| {
-            OBJECTS
-                .get(&id)
-                .map(|obj| (id.to_string(), obj.name.to_string()))
-        })
-        .collect();
-    opts.sort_by(|a, b| a.1.cmp(&b.1));
-    opts
-}
-
-fn ore_options() -> Vec<(String, String)> {
-    ORE_ITEMS
-        .iter()
-        .map(|&(id, label)| (id.to_string(), label.to_string()))
-        .collect()
-}
-
-fn wood_stone_options() -> Vec<(String, String)> {
-    WOOD_STONE_ITEMS
-        .iter()
-        .map(|&(id, label)| (id.to_string(), label.to_string()))
-        .collect()
-}
-
 // ---------------------------------------------------------------------------
 // Component
 // ---------------------------------------------------------------------------
 
 #[derive(Clone, PartialEq, Properties)]
 pub struct ObservationRowProps {
+    /// Data set the row's form offers choices from.
+    pub data: &'static GameData,
     pub display_state: RowDisplayState,
+    /// Unknown-date groups the row can join: every group in use plus a new one.
+    pub group_count: usize,
     pub on_change: Callback<RowDisplayState>,
     pub on_delete: Callback<()>,
 }
@@ -206,7 +117,7 @@ pub fn ObservationRow(props: &ObservationRowProps) -> Html {
         Callback::from(move |e: InputEvent| {
             let v = e.target_unchecked_into::<HtmlInputElement>().value();
             let mut ns = s.clone();
-            ns.day = v.parse::<u8>().ok().filter(|&d| d >= 1 && d <= 28);
+            ns.day = v.parse::<u8>().ok().filter(|d| (1..=28).contains(d));
             ns.day_value = v;
             on_change.emit(ns);
         })
@@ -230,102 +141,158 @@ pub fn ObservationRow(props: &ObservationRowProps) -> Html {
         Callback::from(move |e: InputEvent| {
             let v = e.target_unchecked_into::<HtmlInputElement>().value();
             let mut ns = s.clone();
-            ns.year = v.parse::<u32>().ok().filter(|&y| y >= 1);
+            ns.year = v.parse::<u32>().ok().filter(|&y| year_last_day(y).is_some());
             ns.year_value = v;
             on_change.emit(ns);
         })
     };
 
-    // ---- Quest type ----
-    let on_quest_type = {
+    // ---- Unknown date toggle ----
+    let on_toggle_date = {
         let s = s.clone();
         let on_change = on_change.clone();
-        Callback::from(move |key: String| {
+        Callback::from(move |_: MouseEvent| {
             let mut ns = s.clone();
-            ns.quest_type = QuestTypeUI::from_key(&key);
-            // Reset content fields when quest type changes.
-            ns.fish_npc_demetrius = None;
-            ns.fish_id = None;
-            ns.resource_item_id = None;
+            ns.date_unknown = !ns.date_unknown;
             on_change.emit(ns);
         })
     };
 
-    // ---- Fish NPC ----
-    let on_fish_npc = {
+    // ---- Group ----
+    let on_group = {
         let s = s.clone();
         let on_change = on_change.clone();
         Callback::from(move |key: String| {
-            let mut ns = s.clone();
-            ns.fish_npc_demetrius = match key.as_str() {
-                "demetrius" => Some(true),
-                "willy" => Some(false),
-                _ => None,
-            };
-            on_change.emit(ns);
+            if let Ok(group) = key.parse::<usize>() {
+                let mut ns = s.clone();
+                ns.group = group;
+                on_change.emit(ns);
+            }
         })
     };
 
-    // ---- Fish ----
-    let on_fish = {
+    // ---- Offset ----
+    let on_offset_input = {
         let s = s.clone();
         let on_change = on_change.clone();
-        Callback::from(move |key: String| {
+        Callback::from(move |e: InputEvent| {
+            let v = e.target_unchecked_into::<HtmlInputElement>().value();
             let mut ns = s.clone();
-            ns.fish_id = key.parse::<u32>().ok();
+            ns.offset = v.parse::<u32>().ok();
+            ns.offset_value = v;
             on_change.emit(ns);
         })
     };
 
-    // ---- Resource item ----
-    let on_resource = {
+    // ---- Source kind ----
+    let on_kind = {
         let s = s.clone();
         let on_change = on_change.clone();
         Callback::from(move |key: String| {
+            let Some(kind) = SourceKind::from_key(&key) else {
+                return;
+            };
+            if kind != s.form.kind() {
+                let mut ns = s.clone();
+                ns.form = FormState::new(kind);
+                on_change.emit(ns);
+            }
+        })
+    };
+
+    // ---- Source form ----
+    let on_form = {
+        let s = s.clone();
+        let on_change = on_change.clone();
+        Callback::from(move |form: FormState| {
             let mut ns = s.clone();
-            ns.resource_item_id = key.parse::<u32>().ok();
+            ns.form = form;
             on_change.emit(ns);
         })
     };
 
-    // ---- Content section (quest-type-specific fields) ----
-    let content_section: Html = match s.quest_type {
-        Some(QuestTypeUI::Fishing) => html! {
+    // ---- Date section: calendar date, or group and offset within it ----
+    let date_section: Html = if s.date_unknown {
+        html! {
             <>
-                <DropdownSelect
-                    options={fish_npc_options()}
-                    selected={s.fish_npc_demetrius.map(|d| if d { "demetrius".to_string() } else { "willy".to_string() })}
-                    placeholder="NPC"
-                    on_select={on_fish_npc}
+                if props.group_count > 1 {
+                    <DropdownSelect
+                        options={group_options(props.group_count)}
+                        selected={Some(s.group.to_string())}
+                        placeholder="Group"
+                        on_select={on_group}
+                    />
+                }
+                <span class="tag is-light">{ "Start +" }</span>
+                <input
+                    class="input"
+                    type="text"
+                    inputmode="numeric"
+                    pattern="[0-9]*"
+                    placeholder="Days"
+                    style="width:4.5rem"
+                    value={s.offset_value.clone()}
+                    oninput={on_offset_input}
+                />
+            </>
+        }
+    } else {
+        html! {
+            <>
+                <input
+                    class="input"
+                    type="text"
+                    inputmode="numeric"
+                    pattern="[0-9]*"
+                    placeholder="Day"
+                    style="width:4rem"
+                    value={s.day_value.clone()}
+                    oninput={on_day_input}
                 />
                 <DropdownSelect
-                    options={fish_options()}
-                    selected={s.fish_id.map(|id| id.to_string())}
-                    placeholder="Fish"
-                    on_select={on_fish}
+                    options={season_options()}
+                    selected={s.season.map(|s| s.key().to_string())}
+                    placeholder="Season"
+                    on_select={on_season}
+                />
+                <input
+                    class="input"
+                    type="text"
+                    inputmode="numeric"
+                    pattern="[0-9]*"
+                    placeholder="Year"
+                    style="width:4.5rem"
+                    value={s.year_value.clone()}
+                    oninput={on_year_input}
                 />
             </>
-        },
-
-        Some(QuestTypeUI::OreGathering) => html! {
-            <DropdownSelect
-                options={ore_options()}
-                selected={s.resource_item_id.map(|id| id.to_string())}
-                placeholder="Ore"
-                on_select={on_resource}
-            />
-        },
+        }
+    };
 
-        Some(QuestTypeUI::WoodStoneGathering) => html! {
-            <DropdownSelect
-                options={wood_stone_options()}
-                selected={s.resource_item_id.map(|id| id.to_string())}
-                placeholder="Material"
-                on_select={on_resource}
-            />
-        },
+    let date_toggle_title = if s.date_unknown {
+        "Date unknown — click if you know the exact date"
+    } else {
+        "Click if you only know this row's spacing from the other unknown-date rows"
+    };
 
-        _ => html! {},
+    // Date toggle and fields, or a note for kinds that do not depend on the day.
+    let date_fields: Html = if s.form.kind().dated() {
+        html! {
+            <>
+                <button
+                    class={ if s.date_unknown { "button is-warning is-light" } else { "button is-light" } }
+                    title={date_toggle_title}
+                    onclick={on_toggle_date}
+                >
+                    <span class="material-symbols-outlined">
+                        { if s.date_unknown { "event_busy" } else { "event" } }
+                    </span>
+                </button>
+                { date_section }
+            </>
+        }
+    } else {
+        html! { <span class="tag is-light">{ "Any day" }</span> }
     };
 
     let on_delete = {
@@ -339,40 +306,15 @@ pub fn ObservationRow(props: &ObservationRowProps) -> Html {
             <div style="display:flex; align-items:center; gap:0.5rem">
                 // Inner flex: all fields, allowed to wrap on narrow screens.
                 <div style="display:flex; flex:1; flex-wrap:wrap; align-items:center; gap:0.5rem">
-                    <input
-                        class="input"
-                        type="text"
-                        inputmode="numeric"
-                        pattern="[0-9]*"
-                        placeholder="Day"
-                        style="width:4rem"
-                        value={s.day_value.clone()}
-                        oninput={on_day_input}
-                    />
-                    <DropdownSelect
-                        options={season_options()}
-                        selected={s.season.map(|s| s.key().to_string())}
-                        placeholder="Season"
-                        on_select={on_season}
-                    />
-                    <input
-                        class="input"
-                        type="text"
-                        inputmode="numeric"
-                        pattern="[0-9]*"
-                        placeholder="Year"
-                        style="width:4.5rem"
-                        value={s.year_value.clone()}
-                        oninput={on_year_input}
-                    />
+                    { date_fields }
                     <span class="tag is-light">{ "—" }</span>
                     <DropdownSelect
-                        options={quest_type_options()}
-                        selected={s.quest_type.map(|qt| qt.key().to_string())}
-                        placeholder="Select quest..."
-                        on_select={on_quest_type}
+                        options={source_kind_options()}
+                        selected={Some(s.form.kind().key().to_string())}
+                        placeholder="Observation"
+                        on_select={on_kind}
                     />
-                    { content_section }
+                    <SourceForm data={props.data} state={s.form.clone()} on_change={on_form} />
                 </div>
                 // Delete button — outside the wrapping flex, always pinned to the right.
                 <button
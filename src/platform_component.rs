@@ -4,7 +4,8 @@ use crate::observation::Platform;
 
 #[derive(Clone, PartialEq, Properties)]
 pub struct PlatformProperties {
-    pub callback: Callback<Option<Platform>>,
+    /// Emits the platforms to crack against: one, or all of them when unknown.
+    pub callback: Callback<Option<Vec<Platform>>>,
 }
 
 #[component]
@@ -26,7 +27,7 @@ pub fn PlatformComponent(props: &PlatformProperties) -> Html {
         let callback = props.callback.clone();
         Callback::from(move |_| {
             label.set("PC");
-            callback.emit(Some(Platform::PC));
+            callback.emit(Some(vec![Platform::PC]));
         })
     };
     let on_switch = {
@@ -34,7 +35,15 @@ pub fn PlatformComponent(props: &PlatformProperties) -> Html {
         let callback = props.callback.clone();
         Callback::from(move |_| {
             label.set("Switch");
-            callback.emit(Some(Platform::Switch));
+            callback.emit(Some(vec![Platform::Switch]));
+        })
+    };
+    let on_unknown = {
+        let label = label.clone();
+        let callback = props.callback.clone();
+        Callback::from(move |_| {
+            label.set("Unknown (try PC and Switch)");
+            callback.emit(Some(Platform::ALL.to_vec()));
         })
     };
 
@@ -53,6 +62,7 @@ pub fn PlatformComponent(props: &PlatformProperties) -> Html {
                     <div class="dropdown-content">
                         <a class="dropdown-item" onmousedown={on_pc}>{ "PC" }</a>
                         <a class="dropdown-item" onmousedown={on_switch}>{ "Switch" }</a>
+                        <a class="dropdown-item" onmousedown={on_unknown}>{ "Unknown (try PC and Switch)" }</a>
                     </div>
                 </div>
             </div>
@@ -2,6 +2,7 @@ use std::num::Wrapping;
 use std::ops::Range;
 
 use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
 
 use crate::observation::Platform;
 
@@ -12,6 +13,11 @@ pub trait Prng {
     fn gen_range(&mut self, range: Range<i32>) -> Result<i32>;
     fn gen_float(&mut self) -> Result<f64>;
 
+    /// `Next()`: a sample in `0..i32::MAX`.
+    fn next_sample(&mut self) -> Result<i32> {
+        self.gen_range(0..i32::MAX)
+    }
+
     /// Equivalent to Next(2) == 1  (i.e. a fair coin flip).
     fn next_bool(&mut self) -> Result<bool> {
         Ok(self.gen_range(0..2)? == 1)
@@ -175,6 +181,11 @@ impl Prng for MsCorLibRandom {
     fn gen_float(&mut self) -> Result<f64> {
         Ok(self.gen() as f64 * (1f64 / i32::MAX as f64))
     }
+
+    /// `Next()` returns `InternalSample()` unscaled.
+    fn next_sample(&mut self) -> Result<i32> {
+        Ok(self.gen())
+    }
 }
 
 pub fn get_prng(platform: Platform, seed: i32) -> Result<Box<dyn Prng>> {
@@ -213,13 +224,13 @@ fn xxhash32(data: &[u8]) -> u32 {
         let mut v4: u32 = 0u32.wrapping_sub(XXHASH_PRIME1);
 
         while pos + 16 <= len {
-            v1 = v1.wrapping_add(lane(&data, pos).wrapping_mul(XXHASH_PRIME2)).rotate_left(13).wrapping_mul(XXHASH_PRIME1);
+            v1 = v1.wrapping_add(lane(data, pos).wrapping_mul(XXHASH_PRIME2)).rotate_left(13).wrapping_mul(XXHASH_PRIME1);
             pos += 4;
-            v2 = v2.wrapping_add(lane(&data, pos).wrapping_mul(XXHASH_PRIME2)).rotate_left(13).wrapping_mul(XXHASH_PRIME1);
+            v2 = v2.wrapping_add(lane(data, pos).wrapping_mul(XXHASH_PRIME2)).rotate_left(13).wrapping_mul(XXHASH_PRIME1);
             pos += 4;
-            v3 = v3.wrapping_add(lane(&data, pos).wrapping_mul(XXHASH_PRIME2)).rotate_left(13).wrapping_mul(XXHASH_PRIME1);
+            v3 = v3.wrapping_add(lane(data, pos).wrapping_mul(XXHASH_PRIME2)).rotate_left(13).wrapping_mul(XXHASH_PRIME1);
             pos += 4;
-            v4 = v4.wrapping_add(lane(&data, pos).wrapping_mul(XXHASH_PRIME2)).rotate_left(13).wrapping_mul(XXHASH_PRIME1);
+            v4 = v4.wrapping_add(lane(data, pos).wrapping_mul(XXHASH_PRIME2)).rotate_left(13).wrapping_mul(XXHASH_PRIME1);
             pos += 4;
         }
 
@@ -235,7 +246,7 @@ fn xxhash32(data: &[u8]) -> u32 {
 
     while pos + 4 <= len {
         h32 = h32
-            .wrapping_add(lane(&data, pos).wrapping_mul(XXHASH_PRIME3))
+            .wrapping_add(lane(data, pos).wrapping_mul(XXHASH_PRIME3))
             .rotate_left(17)
             .wrapping_mul(XXHASH_PRIME4);
         pos += 4;
@@ -259,24 +270,49 @@ fn xxhash32(data: &[u8]) -> u32 {
     h32
 }
 
-/// CreateRandom(params double[] seeds) — hash seeds → platform RNG.
+/// How `Utility.CreateRandomSeed` turns seed values into a Random seed.
+/// Selected per save by the "Use legacy randomization" advanced option.
+#[derive(Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub enum SeedingMode {
+    /// xxHash32 of the seeds (the 1.6 default).
+    #[default]
+    Hashed,
+    /// Sum of the seeds modulo `i32::MAX` (pre-1.6 behaviour, opt-in).
+    Legacy,
+}
+
+/// CreateRandom(params double[] seeds) — seeds → platform RNG.
 ///
 /// Stardew Valley 1.6 converts each double seed to i32 via `(seed % i32::MAX) as i32`,
 /// packs those as LE bytes, and feeds them to xxHash32.  The game does NOT hash the raw
 /// f64 bytes.  See: Utility.CreateRandomSeed / HashUtility.GetDeterministicHashCode.
-pub fn create_random(platform: Platform, seeds: &[f64]) -> Result<Box<dyn Prng>> {
-    let data: Vec<u8> = seeds
-        .iter()
-        .flat_map(|&s| ((s % (i32::MAX as f64)) as i32).to_le_bytes())
-        .collect();
-    let hash = xxhash32(&data) as i32;
-    get_prng(platform, hash)
+/// With legacy randomization the seeds are instead summed modulo `i32::MAX`.
+pub fn create_random(
+    platform: Platform,
+    seeding: SeedingMode,
+    seeds: &[f64],
+) -> Result<Box<dyn Prng>> {
+    let seed = match seeding {
+        SeedingMode::Hashed => {
+            let data: Vec<u8> = seeds
+                .iter()
+                .flat_map(|&s| ((s % (i32::MAX as f64)) as i32).to_le_bytes())
+                .collect();
+            xxhash32(&data) as i32
+        }
+        SeedingMode::Legacy => {
+            let sum: f64 = seeds.iter().map(|&s| s % (i32::MAX as f64)).sum();
+            (sum % (i32::MAX as f64)) as i32
+        }
+    };
+    get_prng(platform, seed)
 }
 
 /// CreateDaySaveRandom(seedA, seedB, seedC) =
 ///   CreateRandom(DaysPlayed, uniqueIDForThisGame / 2, seedA, seedB, seedC)
 pub fn create_day_save_random(
     platform: Platform,
+    seeding: SeedingMode,
     days_played: u32,
     unique_id: u64,
     a: f64,
@@ -285,6 +321,7 @@ pub fn create_day_save_random(
 ) -> Result<Box<dyn Prng>> {
     create_random(
         platform,
+        seeding,
         &[days_played as f64, (unique_id / 2) as f64, a, b, c],
     )
 }
@@ -292,8 +329,49 @@ pub fn create_day_save_random(
 /// CreateInitializationRandom() = CreateRandom(uniqueIDForThisGame, DaysPlayed)
 pub fn create_initialisation_random(
     platform: Platform,
+    seeding: SeedingMode,
     unique_id: u64,
     days_played: u32,
 ) -> Result<Box<dyn Prng>> {
-    create_random(platform, &[unique_id as f64, days_played as f64])
+    create_random(platform, seeding, &[unique_id as f64, days_played as f64])
+}
+
+/// `HashUtility.GetDeterministicHashCode(string)`: xxHash32 of the UTF-8 bytes.
+/// Keys of synced randoms (e.g. `SYNCED_RANDOM day location_weather`) are
+/// turned into seeds this way.
+pub fn deterministic_hash(key: &str) -> i32 {
+    xxhash32(key.as_bytes()) as i32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mscorlib_matches_dotnet() {
+        // `new Random(0).Next()` and `new Random(1).Next()` on .NET Framework.
+        assert_eq!(MsCorLibRandom::from_seed(0).unwrap().gen(), 1559595546);
+        assert_eq!(MsCorLibRandom::from_seed(1).unwrap().gen(), 534011718);
+        let d = MsCorLibRandom::from_seed(0).unwrap().gen_float().unwrap();
+        assert!((d - 0.7262432699679598).abs() < 1e-15);
+    }
+
+    #[test]
+    fn xxhash32_reference_vectors() {
+        assert_eq!(xxhash32(b""), 0x02CC5D05);
+        assert_eq!(xxhash32(b"a"), 0x550D7456);
+        assert_eq!(xxhash32(b"abc"), 0x32D153FF);
+        // Long enough for the four-lane path.
+        assert_eq!(
+            xxhash32(b"Nobody inspects the spammish repetition"),
+            0xE2293B2F
+        );
+    }
+
+    #[test]
+    fn legacy_seeding_sums_seeds() {
+        let mut legacy = create_random(Platform::PC, SeedingMode::Legacy, &[2.0, 3.0]).unwrap();
+        let mut direct = get_prng(Platform::PC, 5).unwrap();
+        assert_eq!(legacy.gen_float().unwrap(), direct.gen_float().unwrap());
+    }
 }
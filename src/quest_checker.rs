@@ -1,141 +1,175 @@
-use crate::game_data::{resource_type_to_items, FISHING_POOLS};
-use crate::observation::{FishingContent, Observation, Platform, QuestContent, ResourceContent};
-use crate::prng::{create_day_save_random, create_initialisation_random};
-
-/// Returns true if `id` is consistent with every observation in the slice.
-/// Observations should be pre-sorted by `pass_rate()` ascending for best performance.
-pub fn check_all(platform: Platform, id: u64, observations: &[Observation]) -> bool {
-    observations
-        .iter()
-        .all(|obs| check_observation(platform, id, obs))
-}
-
-/// Returns true if candidate `id` is consistent with a single observation.
-pub fn check_observation(platform: Platform, id: u64, obs: &Observation) -> bool {
-    check_type(platform, id, obs) && check_content(platform, id, obs)
+use crate::checker::CheckError;
+use crate::codegen::GameData;
+use crate::game_data::resource_type_to_items;
+use crate::observation::{
+    calendar_date, FishingContent, FishingNpc, Platform, QuestContent, ResourceContent,
+};
+use crate::prng::{create_day_save_random, create_initialisation_random, SeedingMode};
+
+/// Returns true if any of the allowed quests on `days_played` matches both in
+/// type and in content.
+pub fn check_quests(
+    data: &'static GameData,
+    platform: Platform,
+    seeding: SeedingMode,
+    id: u64,
+    days_played: u32,
+    quest_contents: &[QuestContent],
+) -> Result<bool, CheckError> {
+    let d = quest_roll(platform, seeding, id, days_played)?;
+
+    for content in quest_contents {
+        if check_type(d, days_played, content)
+            && check_content(data, platform, seeding, id, days_played, content)?
+        {
+            return Ok(true);
+        }
+    }
+    Ok(false)
 }
 
-fn check_type(platform: Platform, id: u64, obs: &Observation) -> bool {
-    let mut rng = match create_day_save_random(
+/// The quest-type roll `d` for the given day.
+pub fn quest_roll(
+    platform: Platform,
+    seeding: SeedingMode,
+    id: u64,
+    days_played: u32,
+) -> Result<f64, CheckError> {
+    let mut rng = create_day_save_random(
         platform,
-        obs.days_played,
+        seeding,
+        days_played,
         id,
         100.0,
-        obs.days_played as f64 * 777.0,
+        days_played as f64 * 777.0,
         0.0,
-    ) {
-        Ok(r) => r,
-        Err(_) => return false,
-    };
+    )?;
+
+    Ok(rng.gen_float()?)
+}
 
-    let d = match rng.gen_float() {
-        Ok(v) => v,
-        Err(_) => return false,
-    };
+/// Returns true if the roll `d` allows `content`'s quest type on `days_played`.
+pub fn check_type(d: f64, days_played: u32, content: &QuestContent) -> bool {
+    let (day_of_month, _, _) = calendar_date(days_played);
+    // Mondays are days 1, 8, 15, 22 of each season.
+    let is_monday = matches!(day_of_month, 1 | 8 | 15 | 22);
 
-    match &obs.quest_content {
+    let in_range = content.roll_range().contains(&d);
+    match content {
         // d in [0.08, 0.5): conservative — doesn't assume mineEntered.
         // If mine was entered and d in [0.08, 0.2), it may have been SlayMonster,
         // but without mine state we accept the whole [0.08, 0.5) range for None.
-        QuestContent::None => d >= 0.08 && d < 0.5,
+        QuestContent::None => in_range,
 
         // d in [0.0, 0.08)
-        QuestContent::ResourceCollection(_) => d < 0.08,
+        QuestContent::ResourceCollection(_) => in_range,
 
         // d in [0.5, 0.6)
-        QuestContent::Fishing(_) => d >= 0.5 && d < 0.6,
+        QuestContent::Fishing(_) => in_range,
 
         // d in [0.6, 0.66) on a Monday (no active SocializeQuest)
-        QuestContent::Socialize => d >= 0.6 && d < 0.66 && obs.is_monday(),
+        QuestContent::Socialize => in_range && is_monday,
 
         // d in [0.6, 1.0) — conservative: accepts both Monday variants.
         // On Monday with d in [0.6, 0.66), it would be Socialize if no SocializeQuest
         // was active, or ItemDelivery if one was. We accept both here.
-        QuestContent::ItemDelivery => d >= 0.6,
+        QuestContent::ItemDelivery => in_range,
 
         // d in [0.08, 0.2) and DaysPlayed > 5 (mineEntered implied, unknown)
-        QuestContent::SlayMonster => d >= 0.08 && d < 0.2 && obs.days_played > 5,
+        QuestContent::SlayMonster => in_range && days_played > 5,
     }
 }
 
-fn check_content(platform: Platform, id: u64, obs: &Observation) -> bool {
-    match &obs.quest_content {
+fn check_content(
+    data: &'static GameData,
+    platform: Platform,
+    seeding: SeedingMode,
+    id: u64,
+    days_played: u32,
+    content: &QuestContent,
+) -> Result<bool, CheckError> {
+    match content {
         QuestContent::None
         | QuestContent::ItemDelivery
         | QuestContent::Socialize
-        | QuestContent::SlayMonster => true,
+        | QuestContent::SlayMonster => Ok(true),
 
-        QuestContent::Fishing(c) => check_fishing(platform, id, obs, c),
-        QuestContent::ResourceCollection(c) => check_resource(platform, id, obs, c),
+        QuestContent::Fishing(c) => check_fishing(data, platform, seeding, id, days_played, c),
+        QuestContent::ResourceCollection(c) => {
+            check_resource(platform, seeding, id, days_played, c)
+        }
     }
 }
 
 fn check_fishing(
+    data: &'static GameData,
     platform: Platform,
+    seeding: SeedingMode,
     id: u64,
-    obs: &Observation,
+    days_played: u32,
     content: &FishingContent,
-) -> bool {
-    let mut rng = match create_initialisation_random(platform, id, obs.days_played) {
-        Ok(r) => r,
-        Err(_) => return false,
-    };
+) -> Result<bool, CheckError> {
+    let (npc, fish_id) = fishing_quest(data, platform, seeding, id, days_played)?;
+    Ok(content.npcs.contains(&npc) && content.fish_ids.contains(&fish_id))
+}
+
+fn check_resource(
+    platform: Platform,
+    seeding: SeedingMode,
+    id: u64,
+    days_played: u32,
+    content: &ResourceContent,
+) -> Result<bool, CheckError> {
+    let (_, possible) = resource_quest(platform, seeding, id, days_played)?;
+    // Check if any allowed item matches any possible item for this resource_type
+    Ok(possible.iter().any(|item| content.item_ids.contains(item)))
+}
+
+/// The NPC and fish a FishingQuest on `days_played` would ask for.
+pub fn fishing_quest(
+    data: &'static GameData,
+    platform: Platform,
+    seeding: SeedingMode,
+    id: u64,
+    days_played: u32,
+) -> Result<(FishingNpc, u32), CheckError> {
+    let mut rng = create_initialisation_random(platform, seeding, id, days_played)?;
 
     // sub_pool: false = Demetrius pool, true = Willy pool
-    let sub_pool = match rng.next_bool() {
-        Ok(v) => v,
-        Err(_) => return false,
-    };
-
-    // content.demetrius == true means Demetrius was observed (sub_pool == false)
-    if sub_pool == content.demetrius {
-        // sub_pool=true → Willy, content.demetrius=true → mismatch
-        // sub_pool=false → Demetrius, content.demetrius=false → mismatch
-        return false;
-    }
+    let sub_pool = rng.next_bool()?;
 
-    let season_idx = obs.season.index() as usize;
-    let pool = FISHING_POOLS[season_idx][sub_pool as usize];
+    let (_, season, _) = calendar_date(days_played);
+    let season_idx = season.index() as usize;
+    let pool = data.fishing_pools[season_idx][sub_pool as usize];
     let pool_len = pool.len() as i32;
 
-    let fish_idx = match rng.gen_range(0..pool_len) {
-        Ok(v) => v as usize,
-        Err(_) => return false,
-    };
+    let fish_idx = rng.gen_range(0..pool_len)? as usize;
 
-    pool[fish_idx] == content.fish_id
+    Ok((FishingNpc::from_sub_pool(sub_pool), pool[fish_idx]))
 }
 
-fn check_resource(
+/// The resource type and possible items a ResourceCollectionQuest on
+/// `days_played` would ask for.
+pub fn resource_quest(
     platform: Platform,
+    seeding: SeedingMode,
     id: u64,
-    obs: &Observation,
-    content: &ResourceContent,
-) -> bool {
-    let mut rng = match create_initialisation_random(platform, id, obs.days_played) {
-        Ok(r) => r,
-        Err(_) => return false,
-    };
+    days_played: u32,
+) -> Result<(i32, &'static [u32]), CheckError> {
+    let mut rng = create_initialisation_random(platform, seeding, id, days_played)?;
 
     // resourceType = Next(6) * 2 → value in {0, 2, 4, 6, 8, 10}
-    let resource_type = match rng.gen_range(0..6) {
-        Ok(v) => v * 2,
-        Err(_) => return false,
-    };
+    let resource_type = rng.gen_range(0..6)? * 2;
 
     // Consume the dummy loop: dummyCount = Next(1, 100), then Next() × dummyCount
-    let dummy_count = match rng.gen_range(1..100) {
-        Ok(v) => v,
-        Err(_) => return false,
-    };
+    let dummy_count = rng.gen_range(1..100)?;
     for _ in 0..dummy_count {
         // Bare Next() call — generates an internal sample in [0, i32::MAX)
-        if rng.gen_range(0..i32::MAX).is_err() {
-            return false;
-        }
+        rng.gen_range(0..i32::MAX)?;
     }
 
-    // Check if observed item matches any possible item for this resource_type
-    let possible = resource_type_to_items(resource_type);
-    possible.contains(&content.item_id)
+    match resource_type_to_items(resource_type) {
+        [] => Err(CheckError::UnknownResourceType(resource_type)),
+        possible => Ok((resource_type, possible)),
+    }
 }
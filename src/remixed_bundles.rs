@@ -0,0 +1,193 @@
+//! Replays remixed Community Center bundle generation (`BundleGenerator`).
+//!
+//! With "remixed bundles" the game rolls every room's bundles once, when the
+//! save is created, from `CreateRandom(uniqueIDForThisGame * 9)`. Rooms are
+//! rolled in `Data/RandomBundles` order and share one random, so the item
+//! lists of earlier rooms (which consume rolls) shift the choices of later ones.
+
+use crate::checker::CheckError;
+use crate::codegen::{BundleTemplate, GameData, RandomBundleArea};
+use crate::observation::Platform;
+use crate::prng::{create_random, Prng, SeedingMode};
+
+/// The bundles one room was given, in slot order.
+#[derive(Clone)]
+pub struct GeneratedRoom {
+    pub room: &'static str,
+    pub bundles: Vec<&'static str>,
+}
+
+/// Every room's bundles for `id`.
+pub fn remixed_bundles(
+    data: &'static GameData,
+    platform: Platform,
+    seeding: SeedingMode,
+    id: u64,
+) -> Result<Vec<GeneratedRoom>, CheckError> {
+    generate(data, platform, seeding, id, data.random_bundle_areas.len())
+}
+
+/// The bundles of `room` for `id`, or `None` if no such room exists. Only the
+/// rooms up to and including it are generated.
+pub fn remixed_room(
+    data: &'static GameData,
+    platform: Platform,
+    seeding: SeedingMode,
+    id: u64,
+    room: &str,
+) -> Result<Option<GeneratedRoom>, CheckError> {
+    let areas = data.random_bundle_areas;
+    let Some(position) = areas.iter().position(|a| a.name == room) else {
+        if areas.is_empty() {
+            return Err(CheckError::MissingGameData("RandomBundles"));
+        }
+        return Ok(None);
+    };
+    Ok(generate(data, platform, seeding, id, position + 1)?.pop())
+}
+
+/// Room names in generation order.
+pub fn rooms(data: &'static GameData) -> impl Iterator<Item = &'static str> {
+    data.random_bundle_areas.iter().map(|area| area.name)
+}
+
+/// Every bundle that can appear in `room`, in data order without duplicates.
+pub fn room_bundles(data: &'static GameData, room: &str) -> Vec<&'static str> {
+    let mut names: Vec<&'static str> = Vec::new();
+    if let Some(area) = data.random_bundle_areas.iter().find(|a| a.name == room) {
+        for template in area.bundle_sets.iter().flat_map(|set| set.iter()).chain(area.bundles) {
+            if !names.contains(&template.name) {
+                names.push(template.name);
+            }
+        }
+    }
+    names
+}
+
+/// Rough chance that a random ID gives `room` every one of `bundles`: a set
+/// bundle costs one set choice, a pool bundle one pick among its slot's rivals.
+pub fn room_pass_rate(data: &GameData, room: &str, bundles: &[String]) -> f64 {
+    let Some(area) = data.random_bundle_areas.iter().find(|a| a.name == room) else {
+        return 1.0;
+    };
+
+    let in_set = |name: &str| {
+        area.bundle_sets
+            .iter()
+            .any(|set| set.iter().any(|t| t.name == name))
+    };
+
+    let mut rate = 1.0;
+    if bundles.iter().any(|name| in_set(name)) && !area.bundle_sets.is_empty() {
+        rate /= area.bundle_sets.len() as f64;
+    }
+    for name in bundles.iter().filter(|name| !in_set(name)) {
+        if let Some(template) = area.bundles.iter().find(|t| t.name == name) {
+            let rivals = area
+                .bundles
+                .iter()
+                .filter(|t| t.index == template.index)
+                .count();
+            rate /= rivals.max(1) as f64;
+        }
+    }
+    rate
+}
+
+fn generate(
+    data: &'static GameData,
+    platform: Platform,
+    seeding: SeedingMode,
+    id: u64,
+    rooms: usize,
+) -> Result<Vec<GeneratedRoom>, CheckError> {
+    if data.random_bundle_areas.is_empty() {
+        return Err(CheckError::MissingGameData("RandomBundles"));
+    }
+
+    let mut rng = create_random(platform, seeding, &[id as f64 * 9.0])?;
+    data.random_bundle_areas
+        .iter()
+        .take(rooms)
+        .map(|area| generate_room(rng.as_mut(), area))
+        .collect()
+}
+
+fn generate_room(
+    rng: &mut dyn Prng,
+    area: &'static RandomBundleArea,
+) -> Result<GeneratedRoom, CheckError> {
+    // Insertion order matters: item lists are parsed in the order bundles were picked.
+    let mut selected: Vec<(usize, &'static BundleTemplate)> = Vec::new();
+
+    if !area.bundle_sets.is_empty() {
+        let set = area.bundle_sets[rng.gen_range(0..area.bundle_sets.len() as i32)? as usize];
+        for template in set {
+            let slot = template.index as usize;
+            match selected.iter_mut().find(|(s, _)| *s == slot) {
+                Some(entry) => entry.1 = template,
+                None => selected.push((slot, template)),
+            }
+        }
+    }
+
+    let mut pool: Vec<&'static BundleTemplate> = area.bundles.iter().collect();
+    for slot in 0..area.slots {
+        if selected.iter().any(|(s, _)| *s == slot) {
+            continue;
+        }
+        // Bundles made for this slot first, then ones that fit any slot.
+        let mut candidates: Vec<usize> = (0..pool.len())
+            .filter(|&i| pool[i].index == slot as i64)
+            .collect();
+        if candidates.is_empty() {
+            candidates = (0..pool.len()).filter(|&i| pool[i].index == -1).collect();
+        }
+        if candidates.is_empty() {
+            continue;
+        }
+        let chosen = candidates[rng.gen_range(0..candidates.len() as i32)? as usize];
+        selected.push((slot, pool.remove(chosen)));
+    }
+
+    for (_, template) in &selected {
+        consume_item_list(rng, template)?;
+    }
+
+    selected.sort_by_key(|(slot, _)| *slot);
+    Ok(GeneratedRoom {
+        room: area.name,
+        bundles: selected.iter().map(|(_, t)| t.name).collect(),
+    })
+}
+
+/// Replays the rolls `ParseItemList` makes: one per random tag, then one per
+/// item dropped to get down to `pick`.
+fn consume_item_list(rng: &mut dyn Prng, template: &BundleTemplate) -> Result<(), CheckError> {
+    let items = parse_random_tags(rng, template.items)?;
+    let mut count = items.split(',').filter(|item| !item.trim().is_empty()).count();
+    let pick = if template.pick < 0 {
+        count
+    } else {
+        template.pick as usize
+    };
+    while count > pick {
+        rng.gen_range(0..count as i32)?;
+        count -= 1;
+    }
+    Ok(())
+}
+
+/// `ParseRandomTags`: resolves `[a|b|c]` tags innermost-last first.
+fn parse_random_tags(rng: &mut dyn Prng, data: &str) -> Result<String, CheckError> {
+    let mut data = data.to_string();
+    while let Some(open) = data.rfind('[') {
+        let Some(close) = data[open..].find(']').map(|i| open + i) else {
+            break;
+        };
+        let options: Vec<&str> = data[open + 1..close].split('|').collect();
+        let value = options[rng.gen_range(0..options.len() as i32)? as usize].to_string();
+        data.replace_range(open..=close, &value);
+    }
+    Ok(data)
+}
@@ -0,0 +1,96 @@
+//! Gus's Dish of the Day at the Stardust Saloon.
+//!
+//! Each morning `_newDayAfterFade` seeds
+//! `CreateRandom(uniqueIDForThisGame / 100, DaysPlayed * 10 + 1, StepsTaken)`,
+//! burns one `Next()` per day of the month, then draws a cooked dish from IDs
+//! 194–239, skipping what the Saloon always sells, and its stock. The daily
+//! luck is drawn next from the same random; see `luck.rs`.
+//!
+//! This seed and draw order are recalled from the game's day-start code and
+//! have not been checked against a save.
+//!
+//! The step count is only readable from the save file, which also holds
+//! `uniqueIDForThisGame`, so the dish cannot help crack an unknown ID. It is
+//! for forecasting a known one, and the web app has no form for it.
+
+use crate::checker::CheckError;
+use crate::codegen::GameData;
+use crate::observation::{calendar_date, Platform};
+use crate::prng::{create_random, Prng, SeedingMode};
+
+/// `Utility.IsForbiddenDishOfTheDay`: the Saloon's regular menu (and the
+/// unused ID 217).
+const FORBIDDEN_DISHES: &[u32] = &[346, 196, 216, 224, 206, 395, 217];
+
+/// The random `_newDayAfterFade` draws from on `days_played`, with the
+/// per-day-of-month burn done. `steps_taken` is `stats.StepsTaken` when the
+/// day starts.
+pub fn new_day_random(
+    platform: Platform,
+    seeding: SeedingMode,
+    id: u64,
+    days_played: u32,
+    steps_taken: u32,
+) -> Result<Box<dyn Prng>, CheckError> {
+    let seeds = [
+        (id / 100) as f64,
+        (days_played as f64) * 10.0 + 1.0,
+        steps_taken as f64,
+    ];
+    let mut rng = create_random(platform, seeding, &seeds)?;
+    let (day_of_month, _, _) = calendar_date(days_played);
+    for _ in 0..day_of_month {
+        rng.gen_range(0..i32::MAX)?;
+    }
+    Ok(rng)
+}
+
+/// The item ID and stock of the dish on `days_played`.
+pub fn dish_of_the_day(
+    platform: Platform,
+    seeding: SeedingMode,
+    id: u64,
+    days_played: u32,
+    steps_taken: u32,
+) -> Result<(u32, u32), CheckError> {
+    let mut rng = new_day_random(platform, seeding, id, days_played, steps_taken)?;
+    draw_dish(rng.as_mut())
+}
+
+/// Draws the dish and its stock from a `new_day_random`.
+pub fn draw_dish(rng: &mut dyn Prng) -> Result<(u32, u32), CheckError> {
+    let dish = loop {
+        let dish = rng.gen_range(194..240)? as u32;
+        if !FORBIDDEN_DISHES.contains(&dish) {
+            break dish;
+        }
+    };
+    // Next(1, 4 + (NextDouble() < 0.08 ? 10 : 0)): the double is drawn first.
+    let bonus = if rng.gen_float()? < 0.08 { 10 } else { 0 };
+    let quantity = rng.gen_range(1..4 + bonus)? as u32;
+
+    Ok((dish, quantity))
+}
+
+/// Every dish that can be drawn, in ID order.
+pub fn possible_dishes(data: &GameData) -> Vec<u32> {
+    (194..240)
+        .filter(|dish| !FORBIDDEN_DISHES.contains(dish) && data.objects.contains_key(dish))
+        .collect()
+}
+
+/// Chance that a random ID draws `dish` with `quantity` (if known).
+pub fn dish_pass_rate(data: &GameData, dish: u32, quantity: Option<u32>) -> f64 {
+    let dishes = possible_dishes(data);
+    if !dishes.contains(&dish) {
+        return 0.0;
+    }
+    let dish_rate = 1.0 / dishes.len() as f64;
+    let quantity_rate = match quantity {
+        None => 1.0,
+        Some(1..=3) => 0.92 / 3.0 + 0.08 / 13.0,
+        Some(4..=13) => 0.08 / 13.0,
+        Some(_) => 0.0,
+    };
+    dish_rate * quantity_rate
+}
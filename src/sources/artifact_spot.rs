@@ -0,0 +1,189 @@
+//! Artifact spots as an observation source.
+
+use serde::{Deserialize, Serialize};
+
+use super::{AlternativeVerdict, Explanation, ObservationSource};
+use crate::artifact_spots::{artifact_pass_rate, dig_artifact_spot, DigSpot};
+use crate::checker::CheckError;
+use crate::codegen::GameData;
+use crate::explain::item_label;
+use crate::observation::Platform;
+use crate::prng::SeedingMode;
+
+/// What digging one artifact spot gave on the observation's day.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ArtifactSpotObservation {
+    /// Location name, e.g. "Forest".
+    pub location: String,
+    /// Tile coordinates of the spot.
+    pub x: u32,
+    pub y: u32,
+    /// The artifact dug up, or `None` if it gave something else.
+    pub item: Option<u32>,
+    /// The player had seen Mr. Qi's plane, which adds a Mystery Box roll.
+    #[serde(default)]
+    pub saw_qi_plane: bool,
+}
+
+impl ArtifactSpotObservation {
+    fn spot(&self) -> DigSpot<'_> {
+        DigSpot {
+            location: &self.location,
+            x: self.x,
+            y: self.y,
+        }
+    }
+
+    fn describe(&self, data: &GameData, item: Option<u32>) -> String {
+        let found = item.map_or("no artifact".to_string(), |id| item_label(data, id));
+        format!("{} ({}, {}): {found}", self.location, self.x, self.y)
+    }
+}
+
+impl ObservationSource for ArtifactSpotObservation {
+    fn check(
+        &self,
+        data: &'static GameData,
+        platform: Platform,
+        seeding: SeedingMode,
+        id: u64,
+        days_played: u32,
+    ) -> Result<bool, CheckError> {
+        let item = dig_artifact_spot(
+            data,
+            platform,
+            seeding,
+            id,
+            days_played,
+            self.spot(),
+            self.saw_qi_plane,
+        )?;
+        Ok(item == self.item)
+    }
+
+    fn explain(
+        &self,
+        data: &'static GameData,
+        platform: Platform,
+        seeding: SeedingMode,
+        id: u64,
+        days_played: u32,
+    ) -> Result<Explanation, CheckError> {
+        let item = dig_artifact_spot(
+            data,
+            platform,
+            seeding,
+            id,
+            days_played,
+            self.spot(),
+            self.saw_qi_plane,
+        )?;
+        Ok(Explanation {
+            generated: self.describe(data, item),
+            alternatives: vec![AlternativeVerdict {
+                observed: self.describe(data, self.item),
+                mismatch: (item != self.item).then_some("artifact"),
+            }],
+        })
+    }
+
+    fn pass_rate(&self, data: &'static GameData, _days_played: u32) -> f64 {
+        artifact_pass_rate(data, &self.location, self.item)
+    }
+
+    /// Seeded from `uniqueIDForThisGame / 2`.
+    fn distinguishes_pairs(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::artifact_spots::{artifact_locations, location_artifacts};
+
+    const ID: u64 = 123_456_789;
+
+    #[test]
+    fn forest_has_artifacts_and_rates_sum_to_one() {
+        let data = GameData::default_set();
+        assert!(artifact_locations(data).contains(&"Forest"));
+        let artifacts = location_artifacts(data, "Forest");
+        assert!(!artifacts.is_empty());
+        let total: f64 = artifacts
+            .iter()
+            .map(|&(item, _)| artifact_pass_rate(data, "Forest", Some(item)))
+            .sum::<f64>()
+            + artifact_pass_rate(data, "Forest", None);
+        assert!((total - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn known_seed_gives_known_digs() {
+        let data = GameData::default_set();
+        for (location, expected) in [
+            (
+                "Forest",
+                &[
+                    (9, 105),
+                    (14, 103),
+                    (16, 120),
+                    (24, 120),
+                    (27, 101),
+                    (28, 115),
+                ][..],
+            ),
+            (
+                "Town",
+                &[
+                    (2, 102),
+                    (9, 103),
+                    (13, 110),
+                    (14, 100),
+                    (19, 102),
+                    (24, 126),
+                ][..],
+            ),
+        ] {
+            let spot = DigSpot {
+                location,
+                x: 20,
+                y: 40,
+            };
+            let digs: Vec<(u32, u32)> = (1..=30)
+                .filter_map(|day| {
+                    dig_artifact_spot(
+                        data,
+                        Platform::PC,
+                        SeedingMode::Hashed,
+                        ID,
+                        day,
+                        spot,
+                        false,
+                    )
+                    .unwrap()
+                    .map(|item| (day, item))
+                })
+                .collect();
+            assert_eq!(digs, expected);
+        }
+
+        let mut observation = ArtifactSpotObservation {
+            location: "Forest".to_string(),
+            x: 20,
+            y: 40,
+            item: Some(105),
+            saw_qi_plane: false,
+        };
+        assert!(observation
+            .check(data, Platform::PC, SeedingMode::Hashed, ID, 9)
+            .unwrap());
+        observation.item = None;
+        assert!(!observation
+            .check(data, Platform::PC, SeedingMode::Hashed, ID, 9)
+            .unwrap());
+        assert!(observation
+            .check(data, Platform::PC, SeedingMode::Hashed, ID, 10)
+            .unwrap());
+    }
+}
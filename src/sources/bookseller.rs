@@ -0,0 +1,131 @@
+//! The Bookseller's visits as an observation source.
+
+use serde::{Deserialize, Serialize};
+
+use super::{AlternativeVerdict, Explanation, ObservationSource};
+use crate::bookseller::{bookseller_days, bookseller_pass_rate};
+use crate::checker::CheckError;
+use crate::codegen::GameData;
+use crate::observation::{calendar_date, Platform};
+use crate::prng::SeedingMode;
+
+/// Whether the Bookseller was in town on the observation's day.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct BooksellerObservation {
+    pub present: bool,
+}
+
+fn describe(present: bool) -> &'static str {
+    if present {
+        "Bookseller in town"
+    } else {
+        "No Bookseller"
+    }
+}
+
+impl BooksellerObservation {
+    fn generated(
+        platform: Platform,
+        seeding: SeedingMode,
+        id: u64,
+        days_played: u32,
+    ) -> Result<(bool, [u8; 2]), CheckError> {
+        let (day, season, year) = calendar_date(days_played);
+        let days = bookseller_days(platform, seeding, id, season, year)?;
+        Ok((days.contains(&day), days))
+    }
+}
+
+impl ObservationSource for BooksellerObservation {
+    fn check(
+        &self,
+        _data: &'static GameData,
+        platform: Platform,
+        seeding: SeedingMode,
+        id: u64,
+        days_played: u32,
+    ) -> Result<bool, CheckError> {
+        let (present, _) = Self::generated(platform, seeding, id, days_played)?;
+        Ok(present == self.present)
+    }
+
+    fn explain(
+        &self,
+        _data: &'static GameData,
+        platform: Platform,
+        seeding: SeedingMode,
+        id: u64,
+        days_played: u32,
+    ) -> Result<Explanation, CheckError> {
+        let (present, [first, second]) = Self::generated(platform, seeding, id, days_played)?;
+        Ok(Explanation {
+            generated: format!("{} (visits on days {first} and {second})", describe(present)),
+            alternatives: vec![AlternativeVerdict {
+                observed: describe(self.present).to_string(),
+                mismatch: (present != self.present).then_some("visit"),
+            }],
+        })
+    }
+
+    fn pass_rate(&self, _data: &'static GameData, days_played: u32) -> f64 {
+        let (day, season, _) = calendar_date(days_played);
+        bookseller_pass_rate(season, day, self.present)
+    }
+
+    /// Seeded from the full ID.
+    fn distinguishes_pairs(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bookseller::possible_days;
+    use crate::observation::{days_played, Season};
+
+    const ID: u64 = 123_456_789;
+
+    #[test]
+    fn two_visits_a_season_on_listed_days() {
+        for year in 1..=3 {
+            for season in [Season::Spring, Season::Summer, Season::Fall, Season::Winter] {
+                let [first, second] =
+                    bookseller_days(Platform::PC, SeedingMode::Hashed, ID, season, year).unwrap();
+                assert_ne!(first, second);
+                assert!(
+                    possible_days(season).contains(&first)
+                        && possible_days(season).contains(&second)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn known_seed_gives_known_visits() {
+        let data = GameData::default_set();
+        let year_one: Vec<[u8; 2]> = [Season::Spring, Season::Summer, Season::Fall, Season::Winter]
+            .into_iter()
+            .map(|season| {
+                bookseller_days(Platform::PC, SeedingMode::Hashed, ID, season, 1).unwrap()
+            })
+            .collect();
+        assert_eq!(year_one, [[12, 22], [18, 27], [4, 12], [12, 24]]);
+
+        for (day, present, expected) in [
+            (12, true, true),
+            (22, true, true),
+            (11, true, false),
+            (11, false, true),
+            (12, false, false),
+        ] {
+            let seen = BooksellerObservation { present };
+            let day = days_played(day, Season::Spring, 1);
+            assert_eq!(
+                seen.check(data, Platform::PC, SeedingMode::Hashed, ID, day)
+                    .unwrap(),
+                expected
+            );
+        }
+    }
+}
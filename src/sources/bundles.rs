@@ -0,0 +1,205 @@
+//! Remixed Community Center bundles as an observation source.
+
+use serde::{Deserialize, Serialize};
+
+use super::{AlternativeVerdict, Explanation, ObservationSource};
+use crate::checker::CheckError;
+use crate::codegen::GameData;
+use crate::observation::Platform;
+use crate::prng::SeedingMode;
+use crate::remixed_bundles::{remixed_room, room_pass_rate};
+
+/// Bundles seen in one Community Center room of a remixed-bundles save. The
+/// bundles are fixed when the save is created, so the date is irrelevant.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct BundleObservation {
+    /// Room name as in `Data/RandomBundles`, e.g. "Pantry".
+    pub room: String,
+    /// Every bundle seen in the room; it need not be all of them.
+    pub bundles: Vec<String>,
+}
+
+impl BundleObservation {
+    fn describe(&self) -> String {
+        format!("{}: {}", self.room, self.bundles.join(", "))
+    }
+}
+
+impl ObservationSource for BundleObservation {
+    fn check(
+        &self,
+        data: &'static GameData,
+        platform: Platform,
+        seeding: SeedingMode,
+        id: u64,
+        _days_played: u32,
+    ) -> Result<bool, CheckError> {
+        Ok(match remixed_room(data, platform, seeding, id, &self.room)? {
+            Some(generated) => self
+                .bundles
+                .iter()
+                .all(|name| generated.bundles.contains(&name.as_str())),
+            None => false,
+        })
+    }
+
+    fn explain(
+        &self,
+        data: &'static GameData,
+        platform: Platform,
+        seeding: SeedingMode,
+        id: u64,
+        _days_played: u32,
+    ) -> Result<Explanation, CheckError> {
+        let (generated, mismatch) = match remixed_room(data, platform, seeding, id, &self.room)? {
+            Some(generated) => {
+                let missing = self
+                    .bundles
+                    .iter()
+                    .any(|name| !generated.bundles.contains(&name.as_str()));
+                (
+                    format!("{}: {}", generated.room, generated.bundles.join(", ")),
+                    missing.then_some("bundle"),
+                )
+            }
+            None => (format!("no room named {}", self.room), Some("room")),
+        };
+
+        Ok(Explanation {
+            generated,
+            alternatives: vec![AlternativeVerdict {
+                observed: self.describe(),
+                mismatch,
+            }],
+        })
+    }
+
+    fn pass_rate(&self, data: &'static GameData, _days_played: u32) -> f64 {
+        room_pass_rate(data, &self.room, &self.bundles)
+    }
+
+    /// Seeded from the full ID (`uniqueIDForThisGame * 9`).
+    fn distinguishes_pairs(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codegen::{BundleTemplate, RandomBundleArea};
+    use crate::remixed_bundles::remixed_bundles;
+
+    const ID: u64 = 123_456_789;
+
+    const fn bundle(
+        name: &'static str,
+        index: i64,
+        items: &'static str,
+        pick: i64,
+    ) -> BundleTemplate {
+        BundleTemplate {
+            name,
+            index,
+            items,
+            pick,
+        }
+    }
+
+    /// A small `Data/RandomBundles`: a room filled from a pool, then one with
+    /// fixed sets.
+    const AREAS: &[RandomBundleArea] = &[
+        RandomBundleArea {
+            name: "Crafts Room",
+            slots: 3,
+            bundle_sets: &[],
+            bundles: &[
+                bundle("Spring Foraging", 0, "16,18,20,22", 3),
+                bundle("Spring Foraging 2", 0, "[16|18],[20|22]", -1),
+                bundle("Summer Foraging", 1, "396,398,402", -1),
+                bundle("Construction", -1, "388,390,709", 2),
+                bundle("Exotic Foraging", -1, "88,90,78,420", 3),
+            ],
+        },
+        RandomBundleArea {
+            name: "Pantry",
+            slots: 2,
+            bundle_sets: &[
+                &[bundle("Spring Crops", 0, "24,188,190", -1)],
+                &[bundle("Rare Crops", 0, "[266|454],347", -1)],
+            ],
+            bundles: &[
+                bundle("Animal", 1, "186,182,174", 2),
+                bundle("Artisan", 1, "432,428,426", 2),
+                bundle("Fish Farmer", 1, "[130|131],132", -1),
+            ],
+        },
+    ];
+
+    fn fixture() -> &'static GameData {
+        Box::leak(Box::new(GameData {
+            random_bundle_areas: AREAS,
+            ..*GameData::default_set()
+        }))
+    }
+
+    #[test]
+    fn known_seeds_give_known_rooms() {
+        let data = fixture();
+        for (id, crafts_room, pantry) in [
+            (
+                ID,
+                ["Spring Foraging 2", "Summer Foraging", "Construction"],
+                ["Spring Crops", "Animal"],
+            ),
+            (
+                1,
+                ["Spring Foraging", "Summer Foraging", "Exotic Foraging"],
+                ["Spring Crops", "Fish Farmer"],
+            ),
+            (
+                3,
+                ["Spring Foraging 2", "Summer Foraging", "Construction"],
+                ["Rare Crops", "Animal"],
+            ),
+        ] {
+            let generated = remixed_bundles(data, Platform::PC, SeedingMode::Hashed, id).unwrap();
+            assert_eq!(generated[0].bundles, crafts_room);
+            assert_eq!(generated[1].bundles, pantry);
+        }
+
+        let seen = BundleObservation {
+            room: "Pantry".to_string(),
+            bundles: vec!["Animal".to_string()],
+        };
+        assert!(seen
+            .check(data, Platform::PC, SeedingMode::Hashed, ID, 1)
+            .unwrap());
+        assert!(!seen
+            .check(data, Platform::PC, SeedingMode::Hashed, 1, 1)
+            .unwrap());
+        let unknown = BundleObservation {
+            room: "No Such Room".to_string(),
+            bundles: Vec::new(),
+        };
+        assert!(!unknown
+            .check(data, Platform::PC, SeedingMode::Hashed, ID, 1)
+            .unwrap());
+    }
+
+    #[test]
+    fn rooms_need_random_bundle_data() {
+        let data: &'static GameData = Box::leak(Box::new(GameData {
+            random_bundle_areas: &[],
+            ..*GameData::default_set()
+        }));
+        let observation = BundleObservation {
+            room: "Pantry".to_string(),
+            bundles: Vec::new(),
+        };
+        assert!(matches!(
+            observation.check(data, Platform::PC, SeedingMode::Hashed, ID, 1),
+            Err(CheckError::MissingGameData("RandomBundles"))
+        ));
+    }
+}
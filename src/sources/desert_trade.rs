@@ -0,0 +1,178 @@
+//! The Desert Trader's rotating trade as an observation source.
+
+use serde::{Deserialize, Serialize};
+
+use super::{AlternativeVerdict, Explanation, ObservationSource};
+use crate::checker::CheckError;
+use crate::codegen::GameData;
+use crate::desert_trader::{desert_trade, desert_trade_pass_rate, DesertTrade};
+use crate::explain::item_label;
+use crate::geodes::qualified_item_label;
+use crate::observation::Platform;
+use crate::prng::SeedingMode;
+
+/// The rotating trade seen on a day. The request may be left out.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct DesertTradeObservation {
+    /// Object ID on offer.
+    pub offered: u32,
+    /// Qualified item asked for in return, e.g. "(O)88".
+    #[serde(default)]
+    pub requested: Option<String>,
+    #[serde(default)]
+    pub amount: Option<u32>,
+}
+
+impl DesertTradeObservation {
+    /// Checking a request needs `Data/Shops`.
+    fn request(&self, trade: &DesertTrade) -> Result<Option<(&'static str, u32)>, CheckError> {
+        if self.requested.is_none() && self.amount.is_none() {
+            return Ok(None);
+        }
+        trade.request.map(Some).ok_or(CheckError::MissingGameData("Shops"))
+    }
+
+    fn mismatch(&self, trade: &DesertTrade) -> Result<Option<&'static str>, CheckError> {
+        let request = self.request(trade)?;
+        Ok(if trade.offered != self.offered {
+            Some("offered item")
+        } else if request.is_some_and(|(item, _)| self.requested.as_deref().is_some_and(|r| r != item)) {
+            Some("requested item")
+        } else if request.is_some_and(|(_, amount)| self.amount.is_some_and(|a| a != amount)) {
+            Some("amount")
+        } else {
+            None
+        })
+    }
+}
+
+fn describe_trade(
+    data: &GameData,
+    offered: u32,
+    requested: Option<&str>,
+    amount: Option<u32>,
+) -> String {
+    let offered = item_label(data, offered);
+    match (requested, amount) {
+        (Some(requested), Some(amount)) => {
+            format!("{offered} for {} ×{amount}", qualified_item_label(data, requested))
+        }
+        (Some(requested), None) => format!("{offered} for {}", qualified_item_label(data, requested)),
+        (None, Some(amount)) => format!("{offered} for ×{amount}"),
+        (None, None) => offered,
+    }
+}
+
+impl ObservationSource for DesertTradeObservation {
+    fn check(
+        &self,
+        data: &'static GameData,
+        platform: Platform,
+        seeding: SeedingMode,
+        id: u64,
+        days_played: u32,
+    ) -> Result<bool, CheckError> {
+        let trade = desert_trade(data, platform, seeding, id, days_played)?;
+        Ok(self.mismatch(&trade)?.is_none())
+    }
+
+    fn explain(
+        &self,
+        data: &'static GameData,
+        platform: Platform,
+        seeding: SeedingMode,
+        id: u64,
+        days_played: u32,
+    ) -> Result<Explanation, CheckError> {
+        let trade = desert_trade(data, platform, seeding, id, days_played)?;
+        let (requested, amount) = trade.request.unzip();
+        Ok(Explanation {
+            generated: describe_trade(data, trade.offered, requested, amount),
+            alternatives: vec![AlternativeVerdict {
+                observed: describe_trade(data, self.offered, self.requested.as_deref(), self.amount),
+                mismatch: self.mismatch(&trade)?,
+            }],
+        })
+    }
+
+    /// The request is the same every day, so only the offer filters IDs.
+    fn pass_rate(&self, data: &'static GameData, _days_played: u32) -> f64 {
+        desert_trade_pass_rate(data, self.offered)
+    }
+
+    /// Seeded from `uniqueIDForThisGame / 2`.
+    fn distinguishes_pairs(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::desert_trader::possible_offers;
+
+    #[test]
+    fn offers_follow_the_shop_query() {
+        let data = GameData::default_set();
+        let offers = possible_offers(data);
+        // Weeds (0) is below the query's range; Prismatic Shard (74) has a price.
+        assert!(!offers.contains(&0));
+        assert!(offers.contains(&74));
+        assert!(offers.iter().all(|id| (2..=789).contains(id)));
+        let total: f64 = offers
+            .iter()
+            .map(|&id| desert_trade_pass_rate(data, id))
+            .sum();
+        assert!((total - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn requests_need_shop_data() {
+        let data = GameData::default_set();
+        let seen = DesertTradeObservation {
+            offered: 74,
+            requested: Some("(O)88".to_string()),
+            amount: Some(5),
+        };
+        let checked = seen.check(data, Platform::PC, SeedingMode::Hashed, 0, 1);
+        match data.desert_trade_request {
+            None => assert_eq!(checked.err(), Some(CheckError::MissingGameData("Shops"))),
+            Some(_) => assert!(checked.is_ok()),
+        }
+    }
+
+    #[test]
+    fn known_seed_gives_known_trades() {
+        // Shop data with a fixed request, as `build.rs` would read it.
+        let data: &'static GameData = Box::leak(Box::new(GameData {
+            desert_trade_request: &Some(("(O)88", 5)),
+            ..*GameData::default_set()
+        }));
+        let offers: Vec<u32> = (2..=6)
+            .map(|day| {
+                desert_trade(data, Platform::PC, SeedingMode::Hashed, 123_456_789, day)
+                    .unwrap()
+                    .offered
+            })
+            .collect();
+        assert_eq!(offers, [707, 139, 241, 247, 573]);
+
+        let mut seen = DesertTradeObservation {
+            offered: 707,
+            requested: Some("(O)88".to_string()),
+            amount: Some(5),
+        };
+        assert!(seen
+            .check(data, Platform::PC, SeedingMode::Hashed, 123_456_789, 2)
+            .unwrap());
+        seen.amount = Some(3);
+        assert!(!seen
+            .check(data, Platform::PC, SeedingMode::Hashed, 123_456_789, 2)
+            .unwrap());
+        seen.amount = None;
+        seen.requested = Some("(O)74".to_string());
+        assert!(!seen
+            .check(data, Platform::PC, SeedingMode::Hashed, 123_456_789, 2)
+            .unwrap());
+    }
+}
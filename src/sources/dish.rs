@@ -0,0 +1,152 @@
+//! The Saloon's Dish of the Day as an observation source.
+
+use serde::{Deserialize, Serialize};
+
+use super::{AlternativeVerdict, Explanation, ObservationSource};
+use crate::checker::CheckError;
+use crate::codegen::GameData;
+use crate::explain::item_label;
+use crate::observation::Platform;
+use crate::prng::SeedingMode;
+use crate::saloon::{dish_of_the_day, dish_pass_rate};
+
+/// The Dish of the Day seen on a day, and how many were in stock if noted.
+/// Only useful to check a known ID, since `steps_taken` comes from the save;
+/// see `saloon.rs`.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct DishObservation {
+    pub item_id: u32,
+    pub quantity: Option<u32>,
+    /// `stats.StepsTaken` when the day started, which seeds the draw.
+    pub steps_taken: u32,
+}
+
+impl DishObservation {
+    fn describe(&self, data: &GameData) -> String {
+        match self.quantity {
+            Some(quantity) => format!("{} ×{quantity}", item_label(data, self.item_id)),
+            None => item_label(data, self.item_id),
+        }
+    }
+}
+
+impl ObservationSource for DishObservation {
+    fn check(
+        &self,
+        _data: &'static GameData,
+        platform: Platform,
+        seeding: SeedingMode,
+        id: u64,
+        days_played: u32,
+    ) -> Result<bool, CheckError> {
+        let (dish, quantity) = dish_of_the_day(platform, seeding, id, days_played, self.steps_taken)?;
+        Ok(dish == self.item_id && self.quantity.is_none_or(|q| q == quantity))
+    }
+
+    fn explain(
+        &self,
+        data: &'static GameData,
+        platform: Platform,
+        seeding: SeedingMode,
+        id: u64,
+        days_played: u32,
+    ) -> Result<Explanation, CheckError> {
+        let (dish, quantity) = dish_of_the_day(platform, seeding, id, days_played, self.steps_taken)?;
+        let mismatch = if dish != self.item_id {
+            Some("dish")
+        } else if self.quantity.is_some_and(|q| q != quantity) {
+            Some("quantity")
+        } else {
+            None
+        };
+
+        Ok(Explanation {
+            generated: format!("{} ×{quantity}", item_label(data, dish)),
+            alternatives: vec![AlternativeVerdict {
+                observed: self.describe(data),
+                mismatch,
+            }],
+        })
+    }
+
+    fn pass_rate(&self, data: &'static GameData, _days_played: u32) -> f64 {
+        dish_pass_rate(data, self.item_id, self.quantity)
+    }
+
+    /// Seeded from `uniqueIDForThisGame / 100`.
+    fn distinguishes_pairs(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prng::get_prng;
+    use crate::saloon::{draw_dish, possible_dishes};
+
+    const ID: u64 = 123_456_789;
+    const STEPS: u32 = 4_321;
+
+    #[test]
+    fn known_seed_gives_known_dishes() {
+        let data = GameData::default_set();
+        let dishes: Vec<(u32, u32)> = (2..=8)
+            .map(|day| dish_of_the_day(Platform::PC, SeedingMode::Hashed, ID, day, STEPS).unwrap())
+            .collect();
+        assert_eq!(
+            dishes,
+            [
+                (235, 3),
+                (218, 3),
+                (220, 2),
+                (200, 1),
+                (234, 1),
+                (205, 2),
+                (204, 2)
+            ]
+        );
+        assert!(dishes
+            .iter()
+            .all(|(dish, _)| possible_dishes(data).contains(dish)));
+
+        // Legacy seeding adds the seeds: ID / 100, DaysPlayed * 10 + 1 and the
+        // step count, then burns one draw per day of the month.
+        let mut legacy = get_prng(Platform::PC, 1_234_567 + 21 + 4_321).unwrap();
+        legacy.gen_range(0..i32::MAX).unwrap();
+        legacy.gen_range(0..i32::MAX).unwrap();
+        assert_eq!(draw_dish(legacy.as_mut()).unwrap(), (236, 2));
+        assert_eq!(
+            dish_of_the_day(Platform::PC, SeedingMode::Legacy, ID, 2, STEPS).unwrap(),
+            (236, 2)
+        );
+
+        let seen = DishObservation {
+            item_id: 235,
+            quantity: Some(3),
+            steps_taken: STEPS,
+        };
+        assert!(seen
+            .check(data, Platform::PC, SeedingMode::Hashed, ID, 2)
+            .unwrap());
+        let wrong = DishObservation {
+            quantity: Some(2),
+            ..seen
+        };
+        assert!(!wrong
+            .check(data, Platform::PC, SeedingMode::Hashed, ID, 2)
+            .unwrap());
+    }
+
+    #[test]
+    fn steps_taken_changes_the_draw() {
+        let draws = |steps| -> Vec<(u32, u32)> {
+            (2..30)
+                .map(|day| {
+                    dish_of_the_day(Platform::PC, SeedingMode::Hashed, ID, day, steps).unwrap()
+                })
+                .collect()
+        };
+        assert_ne!(draws(STEPS), draws(STEPS + 1));
+    }
+}
@@ -0,0 +1,199 @@
+//! Garbage can finds as an observation source.
+
+use serde::{Deserialize, Serialize};
+
+use super::{AlternativeVerdict, Explanation, ObservationSource};
+use crate::checker::CheckError;
+use crate::codegen::GameData;
+use crate::garbage_cans::{can_label, garbage_loot, garbage_pass_rate, GarbageState};
+use crate::geodes::qualified_item_label;
+use crate::observation::Platform;
+use crate::prng::SeedingMode;
+
+/// What the player found searching one can on a day.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct GarbageCanObservation {
+    /// The can's ID, e.g. "Saloon".
+    pub can: String,
+    /// Qualified item ID found, or `None` if the can was empty.
+    pub item: Option<String>,
+    #[serde(default)]
+    pub state: GarbageState,
+}
+
+impl GarbageCanObservation {
+    fn describe(&self, data: &GameData) -> String {
+        let item = match &self.item {
+            Some(item) => qualified_item_label(data, item),
+            None => "nothing".to_string(),
+        };
+        format!("{} can → {item}", can_label(&self.can))
+    }
+}
+
+impl ObservationSource for GarbageCanObservation {
+    fn check(
+        &self,
+        data: &'static GameData,
+        platform: Platform,
+        seeding: SeedingMode,
+        id: u64,
+        days_played: u32,
+    ) -> Result<bool, CheckError> {
+        let loot = garbage_loot(data, platform, seeding, id, days_played, &self.can, self.state)?;
+        Ok(loot.allows(data, self.item.as_deref()))
+    }
+
+    fn explain(
+        &self,
+        data: &'static GameData,
+        platform: Platform,
+        seeding: SeedingMode,
+        id: u64,
+        days_played: u32,
+    ) -> Result<Explanation, CheckError> {
+        let loot = garbage_loot(data, platform, seeding, id, days_played, &self.can, self.state)?;
+        Ok(Explanation {
+            generated: loot.label(data),
+            alternatives: vec![AlternativeVerdict {
+                observed: self.describe(data),
+                mismatch: (!loot.allows(data, self.item.as_deref())).then_some("item"),
+            }],
+        })
+    }
+
+    fn pass_rate(&self, data: &'static GameData, _days_played: u32) -> f64 {
+        garbage_pass_rate(data, &self.can, self.item.as_deref())
+    }
+
+    /// Seeded from `uniqueIDForThisGame / 2`.
+    fn distinguishes_pairs(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codegen::{GarbageCanData, GarbageItem};
+
+    const ID: u64 = 123_456_789;
+
+    /// A small `Data/GarbageCans` with a shared entry on each side of the
+    /// Saloon's own.
+    fn fixture() -> &'static GameData {
+        Box::leak(Box::new(GameData {
+            garbage_default_base_chance: &0.2,
+            garbage_before_all: &[GarbageItem {
+                id: "Geode",
+                item_id: Some("(O)535"),
+                random_item_ids: &[],
+                condition: Some("RANDOM 0.05"),
+                ignore_base_chance: true,
+            }],
+            garbage_after_all: &[GarbageItem {
+                id: "Trash",
+                item_id: None,
+                random_item_ids: &["(O)168", "(O)167", "(O)170"],
+                condition: None,
+                ignore_base_chance: false,
+            }],
+            garbage_cans: &[GarbageCanData {
+                id: "Saloon",
+                base_chance: -1.0,
+                items: &[GarbageItem {
+                    id: "Dish",
+                    item_id: Some("DISH_OF_THE_DAY"),
+                    random_item_ids: &[],
+                    condition: Some("RANDOM 0.2 @addDailyLuck"),
+                    ignore_base_chance: false,
+                }],
+            }],
+            ..*GameData::default_set()
+        }))
+    }
+
+    #[test]
+    fn vanilla_cans_have_names() {
+        assert_eq!(can_label("JodiAndKent"), "Jodi and Kent's house");
+        assert_eq!(can_label("Evelyn"), "George and Evelyn's house");
+        assert_eq!(can_label("ModdedCan"), "ModdedCan");
+    }
+
+    #[test]
+    fn known_seed_gives_known_finds() {
+        let data = fixture();
+        let unknown_steps = GarbageState::default();
+        let known_steps = GarbageState {
+            steps_taken: Some(0),
+            ..GarbageState::default()
+        };
+        for (days_played, state, expected) in [
+            (1, unknown_steps, "nothing"),
+            (
+                8,
+                unknown_steps,
+                "Trash (168) if daily luck ≥ +0.025, else nothing",
+            ),
+            (8, known_steps, "nothing"),
+            (
+                10,
+                unknown_steps,
+                "the Dish of the Day if daily luck ≥ +0.087, else nothing",
+            ),
+            (12, unknown_steps, "the Dish of the Day"),
+            (12, known_steps, "Ice Cream (233)"),
+        ] {
+            let loot = garbage_loot(
+                data,
+                Platform::PC,
+                SeedingMode::Hashed,
+                ID,
+                days_played,
+                "Saloon",
+                state,
+            )
+            .unwrap();
+            assert_eq!(loot.label(data), expected);
+        }
+
+        for (days_played, state, item, expected) in [
+            (8, unknown_steps, Some("(O)168"), true),
+            (8, unknown_steps, None, true),
+            (8, unknown_steps, Some("(O)167"), false),
+            (12, known_steps, Some("(O)233"), true),
+            (12, known_steps, None, false),
+        ] {
+            let seen = GarbageCanObservation {
+                can: "Saloon".to_string(),
+                item: item.map(str::to_string),
+                state,
+            };
+            assert_eq!(
+                seen.check(data, Platform::PC, SeedingMode::Hashed, ID, days_played)
+                    .unwrap(),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn cans_need_garbage_data() {
+        let data: &'static GameData = Box::leak(Box::new(GameData {
+            garbage_cans: &[],
+            ..*GameData::default_set()
+        }));
+        assert!(matches!(
+            garbage_loot(
+                data,
+                Platform::PC,
+                SeedingMode::Hashed,
+                ID,
+                1,
+                "Saloon",
+                GarbageState::default()
+            ),
+            Err(CheckError::MissingGameData("GarbageCans"))
+        ));
+    }
+}
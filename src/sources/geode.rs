@@ -0,0 +1,138 @@
+//! Geode-cracking results as an observation source.
+
+use serde::{Deserialize, Serialize};
+
+use super::{AlternativeVerdict, Explanation, ObservationSource};
+use crate::checker::CheckError;
+use crate::codegen::GameData;
+use crate::geodes::{geode_data, geode_pass_rate, geode_treasure, qualified_item_label};
+use crate::observation::Platform;
+use crate::prng::SeedingMode;
+
+/// One geode Clint cracked. The result depends on how many geodes the save
+/// has cracked, not on the date.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct GeodeObservation {
+    /// Unqualified object ID of the geode, e.g. "535".
+    pub geode: String,
+    /// Which geode this was over the whole save, counting from 1.
+    pub index: u32,
+    /// Qualified item ID received, e.g. "(O)538".
+    pub item: String,
+}
+
+impl GeodeObservation {
+    fn describe(&self, data: &'static GameData) -> String {
+        let geode = geode_data(data, &self.geode).map_or(self.geode.as_str(), |geode| geode.name);
+        format!(
+            "{geode} #{} → {}",
+            self.index,
+            qualified_item_label(data, &self.item)
+        )
+    }
+}
+
+impl ObservationSource for GeodeObservation {
+    fn check(
+        &self,
+        data: &'static GameData,
+        platform: Platform,
+        seeding: SeedingMode,
+        id: u64,
+        _days_played: u32,
+    ) -> Result<bool, CheckError> {
+        let treasure = geode_treasure(data, platform, seeding, id, &self.geode, self.index)?;
+        Ok(treasure.is_some_and(|treasure| treasure.allows(&self.item)))
+    }
+
+    fn explain(
+        &self,
+        data: &'static GameData,
+        platform: Platform,
+        seeding: SeedingMode,
+        id: u64,
+        _days_played: u32,
+    ) -> Result<Explanation, CheckError> {
+        let (generated, mismatch) =
+            match geode_treasure(data, platform, seeding, id, &self.geode, self.index)? {
+                Some(treasure) => (
+                    treasure.label(data),
+                    (!treasure.allows(&self.item)).then_some("item"),
+                ),
+                None => (format!("no geode with ID {}", self.geode), Some("geode")),
+            };
+
+        Ok(Explanation {
+            generated,
+            alternatives: vec![AlternativeVerdict {
+                observed: self.describe(data),
+                mismatch,
+            }],
+        })
+    }
+
+    fn pass_rate(&self, data: &'static GameData, _days_played: u32) -> f64 {
+        geode_pass_rate(data, &self.geode, &self.item)
+    }
+
+    /// Seeded from `uniqueIDForThisGame / 2`.
+    fn distinguishes_pairs(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geodes::possible_treasures;
+
+    const ID: u64 = 123_456_789;
+
+    #[test]
+    fn geode_table_has_the_vanilla_geodes() {
+        let data = GameData::default_set();
+        for (id, name) in [
+            ("535", "Geode"),
+            ("536", "Frozen Geode"),
+            ("537", "Magma Geode"),
+            ("749", "Omni Geode"),
+        ] {
+            assert_eq!(geode_data(data, id).map(|geode| geode.name), Some(name));
+        }
+    }
+
+    #[test]
+    fn known_seed_gives_known_treasures() {
+        let data = GameData::default_set();
+        for (geode, expected) in [
+            ("535", ["556", "390", "574", "568", "571", "556"]),
+            ("536", ["559", "390", "573", "567", "572", "559"]),
+            ("537", ["554", "390", "575", "565", "575", "554"]),
+            ("749", ["565", "390", "569", "543", "541", "563"]),
+        ] {
+            let possible = possible_treasures(data, geode);
+            for (index, item) in (1..).zip(expected) {
+                let treasure =
+                    geode_treasure(data, Platform::PC, SeedingMode::Hashed, ID, geode, index)
+                        .unwrap()
+                        .unwrap();
+                assert_eq!(treasure.item, format!("(O){item}"));
+                assert_eq!(treasure.stack, if item == "390" { 5 } else { 1 });
+                assert!(possible.contains(&treasure.item.as_str()));
+            }
+        }
+
+        let seen = GeodeObservation {
+            geode: "535".to_string(),
+            index: 3,
+            item: "(O)574".to_string(),
+        };
+        assert!(seen
+            .check(data, Platform::PC, SeedingMode::Hashed, ID, 1)
+            .unwrap());
+        let wrong = GeodeObservation { index: 4, ..seen };
+        assert!(!wrong
+            .check(data, Platform::PC, SeedingMode::Hashed, ID, 1)
+            .unwrap());
+    }
+}
@@ -0,0 +1,110 @@
+//! The TV fortune teller's daily-luck reading as an observation source.
+
+use serde::{Deserialize, Serialize};
+
+use super::{AlternativeVerdict, Explanation, ObservationSource};
+use crate::checker::CheckError;
+use crate::codegen::GameData;
+use crate::luck::{daily_luck, Fortune};
+use crate::observation::Platform;
+use crate::prng::SeedingMode;
+
+/// The fortune heard on the TV on a day. Only useful to check a known ID,
+/// since `steps_taken` comes from the save; see `saloon.rs`.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct LuckObservation {
+    pub fortune: Fortune,
+    /// `stats.StepsTaken` when the day started, which seeds the luck.
+    pub steps_taken: u32,
+}
+
+impl ObservationSource for LuckObservation {
+    fn check(
+        &self,
+        _data: &'static GameData,
+        platform: Platform,
+        seeding: SeedingMode,
+        id: u64,
+        days_played: u32,
+    ) -> Result<bool, CheckError> {
+        let luck = daily_luck(platform, seeding, id, days_played, self.steps_taken)?;
+        Ok(Fortune::from_luck(luck) == self.fortune)
+    }
+
+    fn explain(
+        &self,
+        _data: &'static GameData,
+        platform: Platform,
+        seeding: SeedingMode,
+        id: u64,
+        days_played: u32,
+    ) -> Result<Explanation, CheckError> {
+        let luck = daily_luck(platform, seeding, id, days_played, self.steps_taken)?;
+        let fortune = Fortune::from_luck(luck);
+        Ok(Explanation {
+            generated: format!("luck {luck:+.3} → {}", fortune.label()),
+            alternatives: vec![AlternativeVerdict {
+                observed: self.fortune.label().to_string(),
+                mismatch: (fortune != self.fortune).then_some("fortune"),
+            }],
+        })
+    }
+
+    fn pass_rate(&self, _data: &'static GameData, _days_played: u32) -> f64 {
+        self.fortune.pass_rate()
+    }
+
+    /// Seeded from `uniqueIDForThisGame / 100`.
+    fn distinguishes_pairs(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ID: u64 = 123_456_789;
+    const STEPS: u32 = 4_321;
+
+    #[test]
+    fn fortune_thresholds_match_the_tv() {
+        assert!(Fortune::from_luck(-0.071) == Fortune::VeryDispleased);
+        assert!(Fortune::from_luck(-0.07) == Fortune::SomewhatAnnoyed);
+        assert!(Fortune::from_luck(-0.02) == Fortune::Neutral);
+        assert!(Fortune::from_luck(0.0) == Fortune::AbsolutelyNeutral);
+        assert!(Fortune::from_luck(0.02) == Fortune::Neutral);
+        assert!(Fortune::from_luck(0.021) == Fortune::GoodHumor);
+        assert!(Fortune::from_luck(0.071) == Fortune::VeryHappy);
+
+        let total: f64 = Fortune::ALL.iter().map(|f| f.pass_rate()).sum();
+        assert!((total - 1.0).abs() < 1e-12);
+        assert!((Fortune::AbsolutelyNeutral.pass_rate() - 1.0 / 201.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn known_seed_gives_known_luck() {
+        let data = GameData::default_set();
+        let luck: Vec<f64> = (2..=8)
+            .map(|day| daily_luck(Platform::PC, SeedingMode::Hashed, ID, day, STEPS).unwrap())
+            .collect();
+        assert_eq!(luck, [0.095, 0.091, 0.08, -0.024, -0.016, 0.066, -0.066]);
+
+        for (day, fortune, expected) in [
+            (2, Fortune::VeryHappy, true),
+            (5, Fortune::SomewhatAnnoyed, true),
+            (5, Fortune::Neutral, false),
+            (8, Fortune::GoodHumor, false),
+        ] {
+            let seen = LuckObservation {
+                fortune,
+                steps_taken: STEPS,
+            };
+            assert_eq!(
+                seen.check(data, Platform::PC, SeedingMode::Hashed, ID, day)
+                    .unwrap(),
+                expected
+            );
+        }
+    }
+}
@@ -0,0 +1,170 @@
+//! Special Mines floors as an observation source.
+
+use serde::{Deserialize, Serialize};
+
+use super::{AlternativeVerdict, Explanation, ObservationSource};
+use crate::checker::CheckError;
+use crate::codegen::GameData;
+use crate::mines::{mine_floor, mine_floor_pass_rate, MineFeature};
+use crate::observation::Platform;
+use crate::prng::SeedingMode;
+
+/// How one Mines floor looked on the observation's day.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct MineFloorObservation {
+    pub floor: u32,
+    pub feature: MineFeature,
+    /// The save can get quarry floors (Crafts Room done, Quarry Mine visited).
+    #[serde(default)]
+    pub quarry_unlocked: bool,
+}
+
+impl ObservationSource for MineFloorObservation {
+    fn check(
+        &self,
+        _data: &'static GameData,
+        platform: Platform,
+        seeding: SeedingMode,
+        id: u64,
+        days_played: u32,
+    ) -> Result<bool, CheckError> {
+        let feature = mine_floor(
+            platform,
+            seeding,
+            id,
+            days_played,
+            self.floor,
+            self.quarry_unlocked,
+        )?;
+        Ok(feature == self.feature)
+    }
+
+    fn explain(
+        &self,
+        _data: &'static GameData,
+        platform: Platform,
+        seeding: SeedingMode,
+        id: u64,
+        days_played: u32,
+    ) -> Result<Explanation, CheckError> {
+        let feature = mine_floor(
+            platform,
+            seeding,
+            id,
+            days_played,
+            self.floor,
+            self.quarry_unlocked,
+        )?;
+        Ok(Explanation {
+            generated: format!("Floor {}: {}", self.floor, feature.label()),
+            alternatives: vec![AlternativeVerdict {
+                observed: format!("Floor {}: {}", self.floor, self.feature.label()),
+                mismatch: (feature != self.feature).then_some("floor"),
+            }],
+        })
+    }
+
+    fn pass_rate(&self, _data: &'static GameData, _days_played: u32) -> f64 {
+        mine_floor_pass_rate(self.floor, self.feature, self.quarry_unlocked)
+    }
+
+    /// Seeded from `uniqueIDForThisGame / 2`.
+    fn distinguishes_pairs(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mines::{special_floors, MINE_FLOORS};
+
+    const ID: u64 = 123_456_789;
+
+    #[test]
+    fn special_floors_follow_the_floor_rules() {
+        for day in 2..60 {
+            for (floor, feature) in
+                special_floors(Platform::PC, SeedingMode::Hashed, ID, day, true).unwrap()
+            {
+                assert!(!floor.is_multiple_of(5), "elevator floor {floor} changed");
+                match feature {
+                    MineFeature::MonsterInfested | MineFeature::SlimeInfested => {
+                        assert!(floor % 40 > 5 && floor % 40 < 30 && floor % 40 != 19)
+                    }
+                    MineFeature::Mushroom => assert!(floor > 80),
+                    MineFeature::Quarry => assert!(floor % 40 > 1),
+                    MineFeature::Normal => unreachable!(),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn known_seed_gives_known_floors() {
+        let data = GameData::default_set();
+        let floors = |quarry_unlocked| -> Vec<(u32, &str)> {
+            special_floors(Platform::PC, SeedingMode::Hashed, ID, 10, quarry_unlocked)
+                .unwrap()
+                .into_iter()
+                .map(|(floor, feature)| (floor, feature.key()))
+                .collect()
+        };
+        assert_eq!(
+            floors(false),
+            [
+                (7, "monster"),
+                (11, "slime"),
+                (29, "monster"),
+                (66, "slime"),
+                (87, "slime"),
+                (89, "monster"),
+            ]
+        );
+        assert_eq!(
+            floors(true),
+            [
+                (7, "monster"),
+                (9, "quarry"),
+                (11, "slime"),
+                (27, "quarry"),
+                (28, "quarry"),
+                (29, "monster"),
+                (66, "slime"),
+                (87, "slime"),
+                (89, "monster"),
+                (103, "quarry"),
+            ]
+        );
+
+        for (floor, feature, quarry_unlocked, expected) in [
+            (7, MineFeature::MonsterInfested, false, true),
+            (7, MineFeature::Normal, false, false),
+            (9, MineFeature::Quarry, true, true),
+            (9, MineFeature::Normal, false, true),
+            (8, MineFeature::SlimeInfested, true, false),
+        ] {
+            let seen = MineFloorObservation {
+                floor,
+                feature,
+                quarry_unlocked,
+            };
+            assert_eq!(
+                seen.check(data, Platform::PC, SeedingMode::Hashed, ID, 10)
+                    .unwrap(),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn pass_rates_cover_every_feature() {
+        for floor in 1..=MINE_FLOORS {
+            let total: f64 = MineFeature::ALL
+                .iter()
+                .map(|&feature| mine_floor_pass_rate(floor, feature, true))
+                .sum();
+            assert!((total - 1.0).abs() < 1e-9);
+        }
+    }
+}
@@ -0,0 +1,185 @@
+//! Observation sources: every kind of in-game signal the cracker can check.
+//!
+//! A source is a type implementing `ObservationSource`. To add one, write a
+//! module here, implement the trait, and list it in `observation_sources!`
+//! below; the checker, cracker, explain API and agent messages pick it up
+//! through `SourceObservation`. The web UI has a matching form registry in
+//! `forms`.
+
+use serde::{Deserialize, Serialize};
+
+use crate::checker::CheckError;
+use crate::codegen::GameData;
+use crate::observation::Platform;
+use crate::prng::SeedingMode;
+
+pub mod artifact_spot;
+pub mod bookseller;
+pub mod bundles;
+pub mod desert_trade;
+pub mod dish;
+pub mod garbage_can;
+pub mod geode;
+pub mod luck;
+pub mod mine_floor;
+pub mod mystery_box;
+pub mod night_event;
+pub mod quest;
+pub mod secret_gift;
+pub mod special_orders;
+pub mod trash_bear;
+pub mod weather;
+
+pub use artifact_spot::ArtifactSpotObservation;
+pub use bookseller::BooksellerObservation;
+pub use bundles::BundleObservation;
+pub use desert_trade::DesertTradeObservation;
+pub use dish::DishObservation;
+pub use garbage_can::GarbageCanObservation;
+pub use geode::GeodeObservation;
+pub use luck::LuckObservation;
+pub use mine_floor::MineFloorObservation;
+pub use mystery_box::MysteryBoxObservation;
+pub use night_event::NightEventObservation;
+pub use quest::QuestObservation;
+pub use secret_gift::SecretGiftObservation;
+pub use special_orders::SpecialOrdersObservation;
+pub use trash_bear::TrashBearObservation;
+pub use weather::WeatherObservation;
+
+/// One kind of observation. Implementations hold the set of values the player
+/// may have seen; a check passes if the game generated any of them.
+pub trait ObservationSource {
+    /// Returns true if candidate `id` would have produced this observation on
+    /// `days_played`, with the tables of `data`. Sources that do not depend on
+    /// the date ignore it.
+    fn check(
+        &self,
+        data: &'static GameData,
+        platform: Platform,
+        seeding: SeedingMode,
+        id: u64,
+        days_played: u32,
+    ) -> Result<bool, CheckError>;
+
+    /// What the game generated for `id`, and which observed value disagreed.
+    fn explain(
+        &self,
+        data: &'static GameData,
+        platform: Platform,
+        seeding: SeedingMode,
+        id: u64,
+        days_played: u32,
+    ) -> Result<Explanation, CheckError>;
+
+    /// Estimated fraction of candidate IDs that PASS this observation.
+    /// Lower = more discriminating = checked first in the pipeline.
+    fn pass_rate(&self, data: &'static GameData, days_played: u32) -> f64;
+
+    /// True if the observation can tell IDs 2N and 2N+1 apart. Day-save randoms
+    /// hash `id / 2`, so only sources seeded with the full ID can.
+    fn distinguishes_pairs(&self) -> bool;
+}
+
+/// A source-independent account of one observation for one ID.
+#[derive(Clone)]
+pub struct Explanation {
+    /// What the game generated, e.g. "d=0.584882 → Fishing [Willy] → Chub (702)".
+    pub generated: String,
+    /// One verdict per value the observation allowed, in its order.
+    pub alternatives: Vec<AlternativeVerdict>,
+}
+
+#[derive(Clone)]
+pub struct AlternativeVerdict {
+    /// The observed value, e.g. "Fishing [Demetrius] → Carp (142)".
+    pub observed: String,
+    /// The field that disagreed, or `None` if this alternative matches.
+    pub mismatch: Option<&'static str>,
+}
+
+impl Explanation {
+    /// True if any alternative matches, i.e. the checker accepts the observation.
+    pub fn passed(&self) -> bool {
+        self.alternatives.iter().any(|alt| alt.mismatch.is_none())
+    }
+}
+
+/// Declares the registry: the `SourceObservation` enum with one variant per
+/// source, dispatching `ObservationSource` to the wrapped value.
+macro_rules! observation_sources {
+    ($($variant:ident($ty:ty)),* $(,)?) => {
+        /// An observation from any registered source.
+        #[derive(Clone, Serialize, Deserialize)]
+        pub enum SourceObservation {
+            $($variant($ty),)*
+        }
+
+        impl ObservationSource for SourceObservation {
+            fn check(
+                &self,
+                data: &'static GameData,
+                platform: Platform,
+                seeding: SeedingMode,
+                id: u64,
+                days_played: u32,
+            ) -> Result<bool, CheckError> {
+                match self {
+                    $(SourceObservation::$variant(o) => o.check(data, platform, seeding, id, days_played),)*
+                }
+            }
+
+            fn explain(
+                &self,
+                data: &'static GameData,
+                platform: Platform,
+                seeding: SeedingMode,
+                id: u64,
+                days_played: u32,
+            ) -> Result<Explanation, CheckError> {
+                match self {
+                    $(SourceObservation::$variant(o) => o.explain(data, platform, seeding, id, days_played),)*
+                }
+            }
+
+            fn pass_rate(&self, data: &'static GameData, days_played: u32) -> f64 {
+                match self {
+                    $(SourceObservation::$variant(o) => o.pass_rate(data, days_played),)*
+                }
+            }
+
+            fn distinguishes_pairs(&self) -> bool {
+                match self {
+                    $(SourceObservation::$variant(o) => o.distinguishes_pairs(),)*
+                }
+            }
+        }
+
+        $(
+            impl From<$ty> for SourceObservation {
+                fn from(o: $ty) -> Self {
+                    SourceObservation::$variant(o)
+                }
+            }
+        )*
+    };
+}
+
+observation_sources! {
+    Quest(QuestObservation),
+    Bundles(BundleObservation),
+    SecretGift(SecretGiftObservation),
+    Dish(DishObservation),
+    Luck(LuckObservation),
+    Weather(WeatherObservation),
+    NightEvent(NightEventObservation),
+    Geode(GeodeObservation),
+    MineFloor(MineFloorObservation),
+    SpecialOrders(SpecialOrdersObservation),
+    DesertTrade(DesertTradeObservation),
+    Bookseller(BooksellerObservation),
+    ArtifactSpot(ArtifactSpotObservation),
+    MysteryBox(MysteryBoxObservation),
+    GarbageCan(GarbageCanObservation),
+    TrashBear(TrashBearObservation),
+}
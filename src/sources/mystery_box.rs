@@ -0,0 +1,149 @@
+//! Mystery Box results as an observation source.
+
+use serde::{Deserialize, Serialize};
+
+use super::{AlternativeVerdict, Explanation, ObservationSource};
+use crate::checker::CheckError;
+use crate::codegen::GameData;
+use crate::geodes::qualified_item_label;
+use crate::mystery_boxes::{box_name, mystery_box_pass_rate, mystery_box_reward};
+use crate::observation::Platform;
+use crate::prng::SeedingMode;
+
+/// One opened Mystery Box or Golden Mystery Box. Like geodes, the result
+/// depends on how many boxes the save has opened, not on the date.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct MysteryBoxObservation {
+    #[serde(default)]
+    pub golden: bool,
+    /// Which box this was over the whole save, counting boxes of both kinds
+    /// from 1.
+    pub index: u32,
+    /// Qualified item ID received, e.g. "(O)288", or one of the seasonal
+    /// placeholders in `mystery_boxes`.
+    pub item: String,
+}
+
+impl MysteryBoxObservation {
+    fn describe(&self, data: &GameData) -> String {
+        format!(
+            "{} #{} → {}",
+            box_name(self.golden),
+            self.index,
+            qualified_item_label(data, &self.item)
+        )
+    }
+}
+
+impl ObservationSource for MysteryBoxObservation {
+    fn check(
+        &self,
+        _data: &'static GameData,
+        platform: Platform,
+        seeding: SeedingMode,
+        id: u64,
+        _days_played: u32,
+    ) -> Result<bool, CheckError> {
+        let reward = mystery_box_reward(platform, seeding, id, self.golden, self.index)?;
+        Ok(reward.allows(&self.item))
+    }
+
+    fn explain(
+        &self,
+        data: &'static GameData,
+        platform: Platform,
+        seeding: SeedingMode,
+        id: u64,
+        _days_played: u32,
+    ) -> Result<Explanation, CheckError> {
+        let reward = mystery_box_reward(platform, seeding, id, self.golden, self.index)?;
+        Ok(Explanation {
+            generated: reward.label(data),
+            alternatives: vec![AlternativeVerdict {
+                observed: self.describe(data),
+                mismatch: (!reward.allows(&self.item)).then_some("item"),
+            }],
+        })
+    }
+
+    fn pass_rate(&self, _data: &'static GameData, _days_played: u32) -> f64 {
+        mystery_box_pass_rate(self.golden, &self.item)
+    }
+
+    /// Seeded from `uniqueIDForThisGame / 2`.
+    fn distinguishes_pairs(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mystery_boxes::possible_rewards;
+
+    const ID: u64 = 123_456_789;
+
+    #[test]
+    fn mega_bombs_are_a_possible_reward() {
+        for golden in [false, true] {
+            assert!(possible_rewards(golden).iter().any(|item| item == "(O)288"));
+            assert!(mystery_box_pass_rate(golden, "(O)288") > 0.0);
+            assert_eq!(mystery_box_pass_rate(golden, "(O)0"), 0.0);
+        }
+    }
+
+    #[test]
+    fn known_seed_gives_known_rewards() {
+        let data = GameData::default_set();
+        for (golden, expected) in [
+            (
+                false,
+                [
+                    "(O)MixedFlowerSeeds",
+                    "(O)MysteryBox",
+                    "(O)215",
+                    "SeasonalCrop",
+                    "SeasonalCrop",
+                    "(O)770",
+                    "SeasonalCrop",
+                    "(O)395",
+                ],
+            ),
+            (
+                true,
+                [
+                    "(O)773",
+                    "(O)204",
+                    "(O)288",
+                    "(O)288",
+                    "(O)632",
+                    "(O)253",
+                    "(O)SkillBook_0",
+                    "(O)279",
+                ],
+            ),
+        ] {
+            let rewards: Vec<String> = (1..=8)
+                .map(|index| {
+                    mystery_box_reward(Platform::PC, SeedingMode::Hashed, ID, golden, index)
+                        .unwrap()
+                        .item
+                })
+                .collect();
+            assert_eq!(rewards, expected);
+        }
+
+        let mut observation = MysteryBoxObservation {
+            golden: true,
+            index: 3,
+            item: "(O)288".to_string(),
+        };
+        assert!(observation
+            .check(data, Platform::PC, SeedingMode::Hashed, ID, 1)
+            .unwrap());
+        observation.golden = false;
+        assert!(!observation
+            .check(data, Platform::PC, SeedingMode::Hashed, ID, 1)
+            .unwrap());
+    }
+}
@@ -0,0 +1,134 @@
+//! Overnight farm events as an observation source.
+
+use serde::{Deserialize, Serialize};
+
+use super::{AlternativeVerdict, Explanation, ObservationSource};
+use crate::checker::CheckError;
+use crate::codegen::GameData;
+use crate::night_events::{night_event, night_event_pass_rate, NightEvent};
+use crate::observation::Platform;
+use crate::prng::SeedingMode;
+
+/// What happened on the night before the observation's day.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct NightEventObservation {
+    pub event: NightEvent,
+    /// The strange capsule had already landed on an earlier night.
+    #[serde(default)]
+    pub capsule_seen: bool,
+}
+
+impl ObservationSource for NightEventObservation {
+    fn check(
+        &self,
+        _data: &'static GameData,
+        platform: Platform,
+        seeding: SeedingMode,
+        id: u64,
+        days_played: u32,
+    ) -> Result<bool, CheckError> {
+        let event = night_event(platform, seeding, id, days_played, self.capsule_seen)?;
+        Ok(event == self.event)
+    }
+
+    fn explain(
+        &self,
+        _data: &'static GameData,
+        platform: Platform,
+        seeding: SeedingMode,
+        id: u64,
+        days_played: u32,
+    ) -> Result<Explanation, CheckError> {
+        let event = night_event(platform, seeding, id, days_played, self.capsule_seen)?;
+        Ok(Explanation {
+            generated: event.label().to_string(),
+            alternatives: vec![AlternativeVerdict {
+                observed: self.event.label().to_string(),
+                mismatch: (event != self.event).then_some("event"),
+            }],
+        })
+    }
+
+    fn pass_rate(&self, _data: &'static GameData, days_played: u32) -> f64 {
+        night_event_pass_rate(self.event, days_played, self.capsule_seen)
+    }
+
+    /// Seeded from `uniqueIDForThisGame / 2`.
+    fn distinguishes_pairs(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::observation::{days_played, Season};
+
+    const ID: u64 = 123_456_789;
+
+    #[test]
+    fn earthquake_opens_the_railroad_on_summer_3() {
+        let day = days_played(3, Season::Summer, 1);
+        let event = night_event(Platform::PC, SeedingMode::Hashed, ID, day, false).unwrap();
+        assert!(event == NightEvent::Earthquake);
+    }
+
+    #[test]
+    fn known_seed_gives_known_events() {
+        let data = GameData::default_set();
+        let events: Vec<(u32, &str)> = (1..=2 * 112)
+            .map(|day| {
+                let event = night_event(Platform::PC, SeedingMode::Hashed, ID, day, false).unwrap();
+                (day, event.key())
+            })
+            .filter(|&(_, key)| key != "none")
+            .collect();
+        assert_eq!(
+            events,
+            [
+                (3, "fairy"),
+                (9, "witch"),
+                (31, "earthquake"),
+                (38, "meteorite"),
+                (55, "fairy"),
+                (101, "witch"),
+                (102, "meteorite"),
+                (132, "meteorite"),
+                (208, "capsule"),
+                (217, "meteorite"),
+            ]
+        );
+        assert!(
+            night_event(Platform::PC, SeedingMode::Legacy, ID, 39, false).unwrap()
+                == NightEvent::Owl
+        );
+
+        for (day, event, expected) in [
+            (38, NightEvent::Meteorite, true),
+            (38, NightEvent::Witch, false),
+            (39, NightEvent::None, true),
+            (39, NightEvent::Owl, false),
+        ] {
+            let seen = NightEventObservation {
+                event,
+                capsule_seen: false,
+            };
+            assert_eq!(
+                seen.check(data, Platform::PC, SeedingMode::Hashed, ID, day)
+                    .unwrap(),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn pass_rates_cover_every_event() {
+        for day in 1..=3 * 112 {
+            let total: f64 = NightEvent::ALL
+                .iter()
+                .map(|&e| night_event_pass_rate(e, day, false))
+                .sum();
+            assert!((total - 1.0).abs() < 1e-9);
+        }
+    }
+}
@@ -0,0 +1,387 @@
+//! Notice-board quests as an observation source.
+
+use serde::{Deserialize, Serialize};
+
+use super::{AlternativeVerdict, Explanation, ObservationSource};
+use crate::checker::CheckError;
+use crate::codegen::GameData;
+use crate::explain::item_label;
+use crate::npcs::npc_label;
+use crate::observation::{calendar_date, FishingNpc, Platform, QuestContent};
+use crate::prng::SeedingMode;
+use crate::quest_checker::{check_quests, check_type, fishing_quest, quest_roll, resource_quest};
+
+/// The notice-board quest seen on a day: any of `quest_contents`.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct QuestObservation {
+    pub quest_contents: Vec<QuestContent>,
+}
+
+impl ObservationSource for QuestObservation {
+    fn check(
+        &self,
+        data: &'static GameData,
+        platform: Platform,
+        seeding: SeedingMode,
+        id: u64,
+        days_played: u32,
+    ) -> Result<bool, CheckError> {
+        check_quests(data, platform, seeding, id, days_played, &self.quest_contents)
+    }
+
+    fn explain(
+        &self,
+        data: &'static GameData,
+        platform: Platform,
+        seeding: SeedingMode,
+        id: u64,
+        days_played: u32,
+    ) -> Result<Explanation, CheckError> {
+        let explanation = explain_quests(data, platform, seeding, id, days_played, &self.quest_contents)?;
+        Ok(Explanation {
+            generated: describe_generated(data, &explanation.generated),
+            alternatives: explanation
+                .alternatives
+                .iter()
+                .map(|alt| AlternativeVerdict {
+                    observed: describe_content(data, &alt.content),
+                    mismatch: alt.mismatch.map(Mismatch::label),
+                })
+                .collect(),
+        })
+    }
+
+    /// Alternatives can need overlapping rolls (no quest covers the
+    /// monster-hunt range), so the rate is taken over the union of their roll
+    /// intervals: each piece of it passes at the summed density of the
+    /// alternatives covering it, at most 1.
+    fn pass_rate(&self, _data: &'static GameData, _days_played: u32) -> f64 {
+        let mut edges: Vec<f64> = self
+            .quest_contents
+            .iter()
+            .flat_map(|content| {
+                let roll = content.roll_range();
+                [roll.start, roll.end]
+            })
+            .collect();
+        edges.sort_by(f64::total_cmp);
+        edges.dedup();
+
+        edges
+            .windows(2)
+            .map(|piece| {
+                let density: f64 = self
+                    .quest_contents
+                    .iter()
+                    .filter(|content| {
+                        let roll = content.roll_range();
+                        roll.start <= piece[0] && piece[1] <= roll.end
+                    })
+                    .map(|content| {
+                        let roll = content.roll_range();
+                        content.pass_rate() / (roll.end - roll.start)
+                    })
+                    .sum();
+                density.min(1.0) * (piece[1] - piece[0])
+            })
+            .sum()
+    }
+
+    /// Fishing and resource content use the full ID (initialisation random),
+    /// so an observation distinguishes pairs only if every alternative it allows does.
+    fn distinguishes_pairs(&self) -> bool {
+        self.quest_contents.iter().all(|content| {
+            matches!(
+                content,
+                QuestContent::Fishing(_) | QuestContent::ResourceCollection(_)
+            )
+        })
+    }
+}
+
+/// The quest type a roll implies. Some rolls are ambiguous without game state
+/// the cracker does not know (mine entered, an active socialize quest).
+#[derive(Clone, Copy, PartialEq)]
+pub enum ImpliedQuest {
+    ResourceCollection,
+    /// SlayMonster if the mine has been entered, otherwise no quest.
+    SlayMonsterOrNone,
+    None,
+    Fishing,
+    /// Socialize unless one is already active, in which case ItemDelivery.
+    SocializeOrItemDelivery,
+    ItemDelivery,
+}
+
+impl ImpliedQuest {
+    /// Mirrors the thresholds in `check_type`.
+    pub fn from_roll(d: f64, days_played: u32) -> Self {
+        let (day_of_month, _, _) = calendar_date(days_played);
+        let is_monday = matches!(day_of_month, 1 | 8 | 15 | 22);
+
+        if d < 0.08 {
+            ImpliedQuest::ResourceCollection
+        } else if d < 0.2 && days_played > 5 {
+            ImpliedQuest::SlayMonsterOrNone
+        } else if d < 0.5 {
+            ImpliedQuest::None
+        } else if d < 0.6 {
+            ImpliedQuest::Fishing
+        } else if d < 0.66 && is_monday {
+            ImpliedQuest::SocializeOrItemDelivery
+        } else {
+            ImpliedQuest::ItemDelivery
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ImpliedQuest::ResourceCollection => "ResourceCollection",
+            ImpliedQuest::SlayMonsterOrNone => "SlayMonster (or None if mine not yet entered)",
+            ImpliedQuest::None => "None",
+            ImpliedQuest::Fishing => "Fishing",
+            ImpliedQuest::SocializeOrItemDelivery => {
+                "Socialize (or ItemDelivery if already active)"
+            }
+            ImpliedQuest::ItemDelivery => "ItemDelivery",
+        }
+    }
+}
+
+/// What the game generated on an observed day.
+#[derive(Clone)]
+pub struct GeneratedQuest {
+    /// The quest-type roll.
+    pub d: f64,
+    pub implied: ImpliedQuest,
+    /// NPC and fish, when the roll implies a fishing quest.
+    pub fishing: Option<(FishingNpc, u32)>,
+    /// Resource type and its possible items, when the roll implies a resource quest.
+    pub resource: Option<(i32, &'static [u32])>,
+}
+
+/// Which part of an observed alternative disagreed with the generated quest.
+#[derive(Clone, Copy, PartialEq)]
+pub enum Mismatch {
+    QuestType,
+    Npc,
+    Item,
+}
+
+impl Mismatch {
+    pub fn label(self) -> &'static str {
+        match self {
+            Mismatch::QuestType => "quest type",
+            Mismatch::Npc => "NPC",
+            Mismatch::Item => "item",
+        }
+    }
+}
+
+/// The verdict for one alternative of an observation.
+#[derive(Clone)]
+pub struct QuestVerdict {
+    pub content: QuestContent,
+    /// `None` if this alternative matches.
+    pub mismatch: Option<Mismatch>,
+}
+
+#[derive(Clone)]
+pub struct QuestExplanation {
+    pub generated: GeneratedQuest,
+    /// One verdict per allowed alternative, in the observation's order.
+    pub alternatives: Vec<QuestVerdict>,
+}
+
+/// The typed explanation for one day's quests: the generated quest and a
+/// verdict per observed alternative.
+pub fn explain_quests(
+    data: &'static GameData,
+    platform: Platform,
+    seeding: SeedingMode,
+    id: u64,
+    days_played: u32,
+    quest_contents: &[QuestContent],
+) -> Result<QuestExplanation, CheckError> {
+    let d = quest_roll(platform, seeding, id, days_played)?;
+    let implied = ImpliedQuest::from_roll(d, days_played);
+
+    let fishing = match implied {
+        ImpliedQuest::Fishing => Some(fishing_quest(data, platform, seeding, id, days_played)?),
+        _ => None,
+    };
+    let resource = match implied {
+        ImpliedQuest::ResourceCollection => {
+            Some(resource_quest(platform, seeding, id, days_played)?)
+        }
+        _ => None,
+    };
+
+    let alternatives = quest_contents
+        .iter()
+        .map(|content| QuestVerdict {
+            content: content.clone(),
+            mismatch: mismatch(d, days_played, content, fishing, resource),
+        })
+        .collect();
+
+    Ok(QuestExplanation {
+        generated: GeneratedQuest {
+            d,
+            implied,
+            fishing,
+            resource,
+        },
+        alternatives,
+    })
+}
+
+fn mismatch(
+    d: f64,
+    days_played: u32,
+    content: &QuestContent,
+    fishing: Option<(FishingNpc, u32)>,
+    resource: Option<(i32, &'static [u32])>,
+) -> Option<Mismatch> {
+    if !check_type(d, days_played, content) {
+        return Some(Mismatch::QuestType);
+    }
+
+    match content {
+        QuestContent::Fishing(c) => {
+            // check_type passed, so the roll implies fishing and `fishing` is set.
+            let (npc, fish_id) = fishing?;
+            if !c.npcs.contains(&npc) {
+                Some(Mismatch::Npc)
+            } else if !c.fish_ids.contains(&fish_id) {
+                Some(Mismatch::Item)
+            } else {
+                None
+            }
+        }
+        QuestContent::ResourceCollection(c) => {
+            let (_, possible) = resource?;
+            if possible.iter().any(|item| c.item_ids.contains(item)) {
+                None
+            } else {
+                Some(Mismatch::Item)
+            }
+        }
+        _ => None,
+    }
+}
+
+/// One-line description of an observed alternative.
+pub fn describe_content(data: &GameData, content: &QuestContent) -> String {
+    match content {
+        QuestContent::None => "None".to_string(),
+        QuestContent::Fishing(c) => format!(
+            "Fishing [{}] → {}",
+            c.npcs
+                .iter()
+                .map(|&n| npc_label(data, n.name()))
+                .collect::<Vec<_>>()
+                .join("/"),
+            c.fish_ids
+                .iter()
+                .map(|&id| item_label(data, id))
+                .collect::<Vec<_>>()
+                .join("/"),
+        ),
+        QuestContent::ResourceCollection(c) => format!(
+            "ResourceCollection → {}",
+            c.item_ids
+                .iter()
+                .map(|&id| item_label(data, id))
+                .collect::<Vec<_>>()
+                .join("/"),
+        ),
+        QuestContent::ItemDelivery => "ItemDelivery".to_string(),
+        QuestContent::Socialize => "Socialize".to_string(),
+        QuestContent::SlayMonster => "SlayMonster".to_string(),
+    }
+}
+
+/// One-line description of what the game generated.
+pub fn describe_generated(data: &GameData, generated: &GeneratedQuest) -> String {
+    let mut out = format!("d={:.6} → {}", generated.d, generated.implied.label());
+    if let Some((npc, fish_id)) = generated.fishing {
+        out.push_str(&format!(" [{}] → {}", npc_label(data, npc.name()), item_label(data, fish_id)));
+    }
+    if let Some((resource_type, items)) = generated.resource {
+        let items: Vec<String> = items.iter().map(|&id| item_label(data, id)).collect();
+        out.push_str(&format!(" [type {resource_type}] → {}", items.join(" or ")));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::observation::FishingContent;
+
+    const ID: u64 = 123_456_789;
+
+    fn observe(contents: Vec<QuestContent>) -> QuestObservation {
+        QuestObservation {
+            quest_contents: contents,
+        }
+    }
+
+    #[test]
+    fn known_seed_posts_known_fishing_quests() {
+        let data = GameData::default_set();
+        let quests: Vec<(u32, &str, u32)> = (2..=28)
+            .filter(|&day| {
+                let d = quest_roll(Platform::PC, SeedingMode::Hashed, ID, day).unwrap();
+                ImpliedQuest::from_roll(d, day) == ImpliedQuest::Fishing
+            })
+            .map(|day| {
+                let (npc, fish) =
+                    fishing_quest(data, Platform::PC, SeedingMode::Hashed, ID, day).unwrap();
+                (day, npc.name(), fish)
+            })
+            .collect();
+        assert_eq!(
+            quests,
+            [
+                (2, "Willy", 129),
+                (7, "Willy", 142),
+                (10, "Willy", 702),
+                (12, "Willy", 147),
+                (15, "Demetrius", 145),
+            ]
+        );
+
+        let fishing = |fish_ids| {
+            QuestContent::Fishing(FishingContent {
+                npcs: vec![FishingNpc::Willy],
+                fish_ids,
+            })
+        };
+        let seen = observe(vec![fishing(vec![702])]);
+        assert!(seen
+            .check(data, Platform::PC, SeedingMode::Hashed, ID, 10)
+            .unwrap());
+        let wrong = observe(vec![fishing(vec![129])]);
+        assert!(!wrong
+            .check(data, Platform::PC, SeedingMode::Hashed, ID, 10)
+            .unwrap());
+
+        // "One of" passes if any alternative matches.
+        let one_of = observe(vec![QuestContent::ItemDelivery, fishing(vec![702])]);
+        assert!(one_of
+            .check(data, Platform::PC, SeedingMode::Hashed, ID, 10)
+            .unwrap());
+    }
+
+    #[test]
+    fn one_of_pass_rate_covers_the_union_of_rolls() {
+        let data = GameData::default_set();
+        let disjoint = observe(vec![QuestContent::None, QuestContent::ItemDelivery]);
+        assert!((disjoint.pass_rate(data, 10) - 0.82).abs() < 1e-9);
+        // Monster hunts roll inside the no-quest range, so they add nothing.
+        let nested = observe(vec![QuestContent::None, QuestContent::SlayMonster]);
+        assert!((nested.pass_rate(data, 10) - 0.42).abs() < 1e-9);
+    }
+}
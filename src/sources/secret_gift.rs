@@ -0,0 +1,128 @@
+//! Feast of the Winter Star secret gifts as an observation source.
+
+use serde::{Deserialize, Serialize};
+
+use super::{AlternativeVerdict, Explanation, ObservationSource};
+use crate::checker::CheckError;
+use crate::codegen::GameData;
+use crate::npcs::npc_label;
+use crate::observation::{calendar_date, Platform};
+use crate::prng::SeedingMode;
+use crate::winter_star::{participants, secret_gift};
+
+/// The villager who gave the player a gift at the Winter Star festival of the
+/// observation's year: any of `givers` (internal names).
+#[derive(Clone, Serialize, Deserialize)]
+pub struct SecretGiftObservation {
+    pub givers: Vec<String>,
+}
+
+impl ObservationSource for SecretGiftObservation {
+    fn check(
+        &self,
+        data: &'static GameData,
+        platform: Platform,
+        seeding: SeedingMode,
+        id: u64,
+        days_played: u32,
+    ) -> Result<bool, CheckError> {
+        let (_, _, year) = calendar_date(days_played);
+        let gift = secret_gift(data, platform, seeding, id, year)?;
+        Ok(self.givers.iter().any(|name| name == gift.giver))
+    }
+
+    fn explain(
+        &self,
+        data: &'static GameData,
+        platform: Platform,
+        seeding: SeedingMode,
+        id: u64,
+        days_played: u32,
+    ) -> Result<Explanation, CheckError> {
+        let (_, _, year) = calendar_date(days_played);
+        let gift = secret_gift(data, platform, seeding, id, year)?;
+        Ok(Explanation {
+            generated: format!(
+                "{} gives to you; you give to {}",
+                npc_label(data, gift.giver),
+                npc_label(data, gift.recipient)
+            ),
+            alternatives: self
+                .givers
+                .iter()
+                .map(|name| AlternativeVerdict {
+                    observed: format!("{} gives to you", npc_label(data, name)),
+                    mismatch: (name != gift.giver).then_some("NPC"),
+                })
+                .collect(),
+        })
+    }
+
+    /// The giver is any participant but the recipient, uniformly.
+    fn pass_rate(&self, data: &'static GameData, days_played: u32) -> f64 {
+        let (_, _, year) = calendar_date(days_played);
+        let others = participants(data, year).len().saturating_sub(1).max(1);
+        (self.givers.len() as f64 / others as f64).min(1.0)
+    }
+
+    /// Seeded from `uniqueIDForThisGame / 2`.
+    fn distinguishes_pairs(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ID: u64 = 123_456_789;
+    /// Winter 25, Year 1.
+    const FESTIVAL: u32 = 3 * 28 + 25;
+
+    #[test]
+    fn participants_follow_the_character_data() {
+        let data = GameData::default_set();
+        let year_one = participants(data, 1);
+        assert_eq!(year_one.len(), 28);
+        assert!(year_one.contains(&"Abigail"));
+        // Kent is away until year 2; Marlon and out-of-town villagers never take part.
+        for absent in ["Kent", "Marlon", "Krobus", "Wizard", "Sandy", "Leo"] {
+            assert!(!year_one.contains(&absent), "{absent}");
+        }
+        assert!(participants(data, 2).contains(&"Kent"));
+    }
+
+    #[test]
+    fn known_seed_gives_known_pairings() {
+        let data = GameData::default_set();
+        let pairings: Vec<(&str, &str)> = (1..=3)
+            .map(|year| {
+                let gift = secret_gift(data, Platform::PC, SeedingMode::Hashed, ID, year).unwrap();
+                (gift.giver, gift.recipient)
+            })
+            .collect();
+        assert_eq!(
+            pairings,
+            [
+                ("Pierre", "Willy"),
+                ("Clint", "Lewis"),
+                ("Kent", "Sebastian")
+            ]
+        );
+        let legacy = secret_gift(data, Platform::PC, SeedingMode::Legacy, ID, 1).unwrap();
+        assert_eq!((legacy.giver, legacy.recipient), ("Willy", "Sam"));
+
+        let seen = SecretGiftObservation {
+            givers: vec!["Willy".to_string(), "Pierre".to_string()],
+        };
+        assert!(seen
+            .check(data, Platform::PC, SeedingMode::Hashed, ID, FESTIVAL)
+            .unwrap());
+        let wrong = SecretGiftObservation {
+            givers: vec!["Willy".to_string()],
+        };
+        assert!(!wrong
+            .check(data, Platform::PC, SeedingMode::Hashed, ID, FESTIVAL)
+            .unwrap());
+    }
+}
@@ -0,0 +1,204 @@
+//! Special Orders boards as an observation source.
+
+use serde::{Deserialize, Serialize};
+
+use super::{AlternativeVerdict, Explanation, ObservationSource};
+use crate::checker::CheckError;
+use crate::codegen::GameData;
+use crate::observation::Platform;
+use crate::prng::SeedingMode;
+use crate::special_orders::{board_orders, board_pass_rate, OrderBoard, SpecialOrderState};
+
+/// Orders seen on one board during the observation's week.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct SpecialOrdersObservation {
+    pub board: OrderBoard,
+    /// Keys of the orders seen, e.g. "Willy"; one or both of them.
+    pub orders: Vec<String>,
+    #[serde(default)]
+    pub state: SpecialOrderState,
+}
+
+impl ObservationSource for SpecialOrdersObservation {
+    fn check(
+        &self,
+        data: &'static GameData,
+        platform: Platform,
+        seeding: SeedingMode,
+        id: u64,
+        days_played: u32,
+    ) -> Result<bool, CheckError> {
+        let generated = board_orders(data, platform, seeding, id, self.board, days_played, &self.state)?;
+        Ok(self
+            .orders
+            .iter()
+            .all(|key| generated.contains(&key.as_str())))
+    }
+
+    fn explain(
+        &self,
+        data: &'static GameData,
+        platform: Platform,
+        seeding: SeedingMode,
+        id: u64,
+        days_played: u32,
+    ) -> Result<Explanation, CheckError> {
+        let generated = board_orders(data, platform, seeding, id, self.board, days_played, &self.state)?;
+        let missing = self
+            .orders
+            .iter()
+            .any(|key| !generated.contains(&key.as_str()));
+
+        Ok(Explanation {
+            generated: format!("{}: {}", self.board.label(), generated.join(", ")),
+            alternatives: vec![AlternativeVerdict {
+                observed: format!("{}: {}", self.board.label(), self.orders.join(", ")),
+                mismatch: missing.then_some("order"),
+            }],
+        })
+    }
+
+    fn pass_rate(&self, data: &'static GameData, days_played: u32) -> f64 {
+        board_pass_rate(data, self.board, days_played, &self.state, &self.orders)
+    }
+
+    /// Seeded from the full ID.
+    fn distinguishes_pairs(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codegen::SpecialOrderData;
+    use crate::observation::{days_played, Season};
+    use crate::special_orders::refresh_day;
+
+    const ID: u64 = 123_456_789;
+
+    fn order(
+        key: &'static str,
+        order_type: &'static str,
+        duration: &'static str,
+        required_tags: &'static str,
+    ) -> SpecialOrderData {
+        SpecialOrderData {
+            key,
+            requester: key,
+            order_type,
+            duration,
+            repeatable: false,
+            required_tags,
+            condition: None,
+        }
+    }
+
+    /// A small `Data/SpecialOrders` covering the tags the board replays.
+    fn fixture() -> &'static GameData {
+        Box::leak(Box::new(GameData {
+            special_orders: Box::leak(Box::new([
+                order("Willy", "", "Week", ""),
+                order("Robin", "", "Week", "!season_winter"),
+                order("Demetrius", "", "Week", "season_summer"),
+                order("Pierre", "", "Week", ""),
+                order("Caroline", "", "Week", "island"),
+                order("Gus", "", "Month", ""),
+                order("Clint2", "", "Week", "completed_Clint"),
+                order("QiChallenge2", "Qi", "Week", ""),
+                order("QiChallenge3", "Qi", "Week", ""),
+            ])),
+            ..*GameData::default_set()
+        }))
+    }
+
+    #[test]
+    fn boards_refill_on_mondays() {
+        assert_eq!(
+            refresh_day(days_played(1, Season::Summer, 1)),
+            days_played(1, Season::Summer, 1)
+        );
+        assert_eq!(
+            refresh_day(days_played(14, Season::Fall, 2)),
+            days_played(8, Season::Fall, 2)
+        );
+        assert_eq!(
+            refresh_day(days_played(28, Season::Winter, 1)),
+            days_played(22, Season::Winter, 1)
+        );
+    }
+
+    #[test]
+    fn known_seed_gives_known_boards() {
+        let data = fixture();
+        let fresh = SpecialOrderState::default();
+        let later = SpecialOrderState {
+            island_unlocked: true,
+            completed: vec!["Clint".to_string(), "Willy".to_string()],
+        };
+        let town = |day, state| {
+            board_orders(
+                data,
+                Platform::PC,
+                SeedingMode::Hashed,
+                ID,
+                OrderBoard::Town,
+                day,
+                state,
+            )
+            .unwrap()
+        };
+        assert_eq!(town(1, &fresh), ["Pierre", "Willy"]);
+        assert_eq!(town(1, &later), ["Caroline", "Pierre"]);
+        // A Month order only starts before the 16th.
+        assert_eq!(town(8, &fresh), ["Gus", "Pierre"]);
+        assert_eq!(town(22, &later), ["Clint2", "Caroline"]);
+        assert_eq!(town(29, &later), ["Robin", "Demetrius"]);
+        assert_eq!(
+            board_orders(
+                data,
+                Platform::PC,
+                SeedingMode::Hashed,
+                ID,
+                OrderBoard::Qi,
+                29,
+                &fresh
+            )
+            .unwrap(),
+            ["QiChallenge2", "QiChallenge3"]
+        );
+
+        let seen = SpecialOrdersObservation {
+            board: OrderBoard::Town,
+            orders: vec!["Gus".to_string(), "Pierre".to_string()],
+            state: fresh,
+        };
+        // Checked against the Monday the board was refilled on.
+        assert!(seen
+            .check(data, Platform::PC, SeedingMode::Hashed, ID, 10)
+            .unwrap());
+        assert!(!seen
+            .check(data, Platform::PC, SeedingMode::Hashed, ID, 15)
+            .unwrap());
+    }
+
+    #[test]
+    fn boards_need_special_order_data() {
+        let data: &'static GameData = Box::leak(Box::new(GameData {
+            special_orders: &[],
+            ..*GameData::default_set()
+        }));
+        assert!(matches!(
+            board_orders(
+                data,
+                Platform::PC,
+                SeedingMode::Hashed,
+                ID,
+                OrderBoard::Town,
+                1,
+                &SpecialOrderState::default()
+            ),
+            Err(CheckError::MissingGameData("SpecialOrders"))
+        ));
+    }
+}
@@ -0,0 +1,147 @@
+//! The Trash Bear's requests as an observation source.
+
+use serde::{Deserialize, Serialize};
+
+use super::{AlternativeVerdict, Explanation, ObservationSource};
+use crate::checker::CheckError;
+use crate::codegen::GameData;
+use crate::geodes::qualified_item_label;
+use crate::observation::{calendar_date, Platform};
+use crate::prng::SeedingMode;
+use crate::trash_bear::{trash_bear_pass_rate, trash_bear_request};
+
+/// What the Trash Bear asked for on the observation's day.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct TrashBearObservation {
+    /// How many of his requests had been filled before, 0 to 3.
+    pub done: u32,
+    /// Qualified item ID asked for, e.g. "(O)136".
+    pub item: String,
+}
+
+impl ObservationSource for TrashBearObservation {
+    fn check(
+        &self,
+        data: &'static GameData,
+        platform: Platform,
+        seeding: SeedingMode,
+        id: u64,
+        days_played: u32,
+    ) -> Result<bool, CheckError> {
+        let (_, season, _) = calendar_date(days_played);
+        Ok(trash_bear_request(data, platform, seeding, id, season, self.done)? == self.item)
+    }
+
+    fn explain(
+        &self,
+        data: &'static GameData,
+        platform: Platform,
+        seeding: SeedingMode,
+        id: u64,
+        days_played: u32,
+    ) -> Result<Explanation, CheckError> {
+        let (_, season, _) = calendar_date(days_played);
+        let request = trash_bear_request(data, platform, seeding, id, season, self.done)?;
+        Ok(Explanation {
+            generated: qualified_item_label(data, &request),
+            alternatives: vec![AlternativeVerdict {
+                observed: format!(
+                    "request {} → {}",
+                    self.done + 1,
+                    qualified_item_label(data, &self.item)
+                ),
+                mismatch: (request != self.item).then_some("item"),
+            }],
+        })
+    }
+
+    fn pass_rate(&self, data: &'static GameData, days_played: u32) -> f64 {
+        let (_, season, _) = calendar_date(days_played);
+        trash_bear_pass_rate(data, season, self.done, &self.item)
+    }
+
+    /// Seeded from the full ID.
+    fn distinguishes_pairs(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::observation::{days_played, Season};
+    use crate::trash_bear::possible_requests;
+
+    const ID: u64 = 123_456_789;
+
+    #[test]
+    fn first_requests_are_seasonal_forage_or_fish() {
+        let data = GameData::default_set();
+        // Wild Horseradish (16) and Daffodil (18) in spring, Grape (398) in summer.
+        assert!(trash_bear_pass_rate(data, Season::Spring, 0, "(O)16") > 0.0);
+        assert!(trash_bear_pass_rate(data, Season::Spring, 1, "(O)18") > 0.0);
+        assert!(trash_bear_pass_rate(data, Season::Summer, 0, "(O)398") > 0.0);
+        assert_eq!(trash_bear_pass_rate(data, Season::Spring, 0, "(O)398"), 0.0);
+        assert!(possible_requests(data, 0).contains(&"(O)398".to_string()));
+    }
+
+    #[test]
+    fn known_seed_gives_known_requests() {
+        // The first recipes of `Data/CookingRecipes`, in data order.
+        let data: &'static GameData = Box::leak(Box::new(GameData {
+            cooking_recipes: &[
+                ("Fried Egg", "194"),
+                ("Omelet", "195"),
+                ("Salad", "196"),
+                ("Cheese Cauli.", "197"),
+            ],
+            ..*GameData::default_set()
+        }));
+        for (season, expected) in [
+            (Season::Spring, ["(O)148", "(O)132", "(O)195", "(O)195"]),
+            (Season::Summer, ["(O)398", "(O)142", "(O)195", "(O)195"]),
+            (Season::Fall, ["(O)154", "(O)136", "(O)195", "(O)195"]),
+            (Season::Winter, ["(O)151", "(O)136", "(O)195", "(O)195"]),
+        ] {
+            let requests: Vec<String> = (0..4)
+                .map(|done| {
+                    trash_bear_request(data, Platform::PC, SeedingMode::Hashed, ID, season, done)
+                        .unwrap()
+                })
+                .collect();
+            assert_eq!(requests, expected);
+        }
+
+        let day = days_played(5, Season::Summer, 1);
+        let mut seen = TrashBearObservation {
+            done: 0,
+            item: "(O)398".to_string(),
+        };
+        assert!(seen
+            .check(data, Platform::PC, SeedingMode::Hashed, ID, day)
+            .unwrap());
+        seen.done = 1;
+        assert!(!seen
+            .check(data, Platform::PC, SeedingMode::Hashed, ID, day)
+            .unwrap());
+    }
+
+    #[test]
+    fn dishes_need_cooking_recipes() {
+        let data: &'static GameData = Box::leak(Box::new(GameData {
+            cooking_recipes: &[],
+            ..*GameData::default_set()
+        }));
+        assert!(matches!(
+            trash_bear_request(
+                data,
+                Platform::PC,
+                SeedingMode::Hashed,
+                ID,
+                Season::Spring,
+                2
+            ),
+            Err(CheckError::MissingGameData("CookingRecipes"))
+        ));
+    }
+}
@@ -0,0 +1,130 @@
+//! The day's weather as an observation source.
+
+use serde::{Deserialize, Serialize};
+
+use super::{AlternativeVerdict, Explanation, ObservationSource};
+use crate::checker::CheckError;
+use crate::codegen::GameData;
+use crate::observation::Platform;
+use crate::prng::SeedingMode;
+use crate::weather::{weather, weather_pass_rate, Weather};
+
+/// The weather on a day (not the TV forecast for the next one).
+#[derive(Clone, Serialize, Deserialize)]
+pub struct WeatherObservation {
+    pub weather: Weather,
+}
+
+impl ObservationSource for WeatherObservation {
+    fn check(
+        &self,
+        _data: &'static GameData,
+        platform: Platform,
+        seeding: SeedingMode,
+        id: u64,
+        days_played: u32,
+    ) -> Result<bool, CheckError> {
+        Ok(weather(platform, seeding, id, days_played)?.allows(self.weather))
+    }
+
+    fn explain(
+        &self,
+        _data: &'static GameData,
+        platform: Platform,
+        seeding: SeedingMode,
+        id: u64,
+        days_played: u32,
+    ) -> Result<Explanation, CheckError> {
+        let generated = weather(platform, seeding, id, days_played)?;
+        Ok(Explanation {
+            generated: generated.label(),
+            alternatives: vec![AlternativeVerdict {
+                observed: self.weather.label().to_string(),
+                mismatch: (!generated.allows(self.weather)).then_some("weather"),
+            }],
+        })
+    }
+
+    fn pass_rate(&self, _data: &'static GameData, days_played: u32) -> f64 {
+        weather_pass_rate(self.weather, days_played)
+    }
+
+    /// The Green Rain day is seeded from the full ID; the daily rolls are not.
+    fn distinguishes_pairs(&self) -> bool {
+        self.weather == Weather::GreenRain
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::observation::{days_played, Season};
+    use crate::weather::{green_rain_day, GeneratedWeather};
+
+    const ID: u64 = 123_456_789;
+
+    #[test]
+    fn fixed_days_match_the_game() {
+        assert!(
+            weather(Platform::PC, SeedingMode::Hashed, ID, 3).unwrap()
+                == GeneratedWeather::Exactly(Weather::Rain)
+        );
+        for (day, season, expected) in [
+            (1, Season::Spring, Weather::Sun),
+            (13, Season::Spring, Weather::Sun),
+            (13, Season::Summer, Weather::Storm),
+            (26, Season::Summer, Weather::Storm),
+            (25, Season::Winter, Weather::Sun),
+        ] {
+            let day = days_played(day, season, 1);
+            assert!(
+                weather(Platform::PC, SeedingMode::Hashed, ID, day).unwrap()
+                    == GeneratedWeather::Exactly(expected)
+            );
+        }
+    }
+
+    #[test]
+    fn known_seed_gives_known_weather() {
+        let data = GameData::default_set();
+        let green_rain: Vec<u8> = (1..=3)
+            .map(|year| green_rain_day(Platform::PC, SeedingMode::Hashed, ID, year).unwrap())
+            .collect();
+        assert_eq!(green_rain, [15, 16, 16]);
+        assert_eq!(
+            green_rain_day(Platform::PC, SeedingMode::Legacy, ID, 1).unwrap(),
+            14
+        );
+
+        let rainy_spring: Vec<u8> = (1..=28)
+            .filter(|&day| {
+                weather(
+                    Platform::PC,
+                    SeedingMode::Hashed,
+                    ID,
+                    days_played(day, Season::Spring, 1),
+                )
+                .unwrap()
+                .allows(Weather::Rain)
+            })
+            .collect();
+        assert_eq!(rainy_spring, [3, 5, 7, 10, 12, 16]);
+
+        for (day, season, observed, expected) in [
+            (15, Season::Summer, Weather::GreenRain, true),
+            (16, Season::Summer, Weather::GreenRain, false),
+            (17, Season::Summer, Weather::Storm, true),
+            (5, Season::Spring, Weather::Rain, true),
+            (5, Season::Spring, Weather::Sun, false),
+            (6, Season::Spring, Weather::Wind, true),
+        ] {
+            let seen = WeatherObservation { weather: observed };
+            let day = days_played(day, season, 1);
+            assert_eq!(
+                seen.check(data, Platform::PC, SeedingMode::Hashed, ID, day)
+                    .unwrap(),
+                expected
+            );
+        }
+    }
+}
@@ -0,0 +1,195 @@
+//! The Special Orders boards (`SpecialOrder.UpdateAvailableSpecialOrders`).
+//!
+//! Every Monday each board is refilled with two orders drawn from
+//! `CreateRandom(uniqueIDForThisGame, DaysPlayed * 1.3)` out of the orders that
+//! can start, in `Data/SpecialOrders` order. The town board skips orders the
+//! player has completed until it runs out of others. The data file is
+//! optional; see `build.rs`.
+//!
+//! Which orders can start depends on save state. Seasons, completed orders and
+//! the unlocks in `SpecialOrderState` are evaluated; mail, event, friendship
+//! and other tags or conditions the cracker cannot see are assumed to hold.
+
+use serde::{Deserialize, Serialize};
+
+use crate::checker::CheckError;
+use crate::codegen::{GameData, SpecialOrderData};
+use crate::observation::{calendar_date, Platform, Season};
+use crate::prng::{create_random, SeedingMode};
+
+/// Which board the orders were on.
+#[derive(Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum OrderBoard {
+    /// The board outside Pierre's.
+    Town,
+    /// Mr. Qi's board in the Qi's Walnut Room.
+    Qi,
+}
+
+impl OrderBoard {
+    pub const ALL: [OrderBoard; 2] = [OrderBoard::Town, OrderBoard::Qi];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            OrderBoard::Town => "Town board",
+            OrderBoard::Qi => "Qi's board",
+        }
+    }
+
+    pub fn key(self) -> &'static str {
+        match self {
+            OrderBoard::Town => "town",
+            OrderBoard::Qi => "qi",
+        }
+    }
+
+    pub fn from_key(s: &str) -> Option<Self> {
+        OrderBoard::ALL.into_iter().find(|board| board.key() == s)
+    }
+
+    fn order_type(self) -> &'static str {
+        match self {
+            OrderBoard::Town => "",
+            OrderBoard::Qi => "Qi",
+        }
+    }
+}
+
+/// Save state that decides which orders can be offered.
+#[derive(Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SpecialOrderState {
+    /// Ginger Island is reachable (the `island` tag).
+    #[serde(default)]
+    pub island_unlocked: bool,
+    /// Keys of orders already completed, e.g. "Willy".
+    #[serde(default)]
+    pub completed: Vec<String>,
+}
+
+/// The Monday the board was last refilled on or before `days_played`.
+pub fn refresh_day(days_played: u32) -> u32 {
+    let (day, _, _) = calendar_date(days_played);
+    days_played - (day as u32 - 1) % 7
+}
+
+/// Orders that can start on `board` when it is refilled on `days_played`, in
+/// data order.
+pub fn eligible_orders(
+    data: &'static GameData,
+    board: OrderBoard,
+    days_played: u32,
+    state: &SpecialOrderState,
+) -> Vec<&'static SpecialOrderData> {
+    let (day, season, _) = calendar_date(days_played);
+    data.special_orders
+        .iter()
+        .filter(|order| order.order_type == board.order_type())
+        .filter(|order| order.repeatable || !state.completed.iter().any(|key| key == order.key))
+        .filter(|order| day < 16 || order.duration != "Month")
+        .filter(|order| {
+            order
+                .required_tags
+                .split(',')
+                .map(str::trim)
+                .filter(|tag| !tag.is_empty())
+                .all(|tag| check_tag(tag, season, state))
+        })
+        .collect()
+}
+
+/// The two orders (or fewer) on `board` in the week containing `days_played`.
+pub fn board_orders(
+    data: &'static GameData,
+    platform: Platform,
+    seeding: SeedingMode,
+    id: u64,
+    board: OrderBoard,
+    days_played: u32,
+    state: &SpecialOrderState,
+) -> Result<Vec<&'static str>, CheckError> {
+    if data.special_orders.is_empty() {
+        return Err(CheckError::MissingGameData("SpecialOrders"));
+    }
+    let days_played = refresh_day(days_played);
+    let mut including_completed: Vec<&'static str> = eligible_orders(data, board, days_played, state)
+        .iter()
+        .map(|order| order.key)
+        .collect();
+    let mut queue = including_completed.clone();
+    if board == OrderBoard::Town {
+        queue.retain(|key| !state.completed.iter().any(|done| done == key));
+    }
+
+    let mut rng = create_random(platform, seeding, &[id as f64, days_played as f64 * 1.3])?;
+    let mut orders = Vec::new();
+    for _ in 0..2 {
+        if queue.is_empty() {
+            if including_completed.is_empty() {
+                break;
+            }
+            queue = including_completed.clone();
+        }
+        let key = queue[rng.gen_range(0..queue.len() as i32)? as usize];
+        // `GetSpecialOrder(key, r.Next())` takes a generation seed.
+        rng.gen_range(0..i32::MAX)?;
+        queue.retain(|&k| k != key);
+        including_completed.retain(|&k| k != key);
+        orders.push(key);
+    }
+    Ok(orders)
+}
+
+/// Every order key that can ever appear on `board`, in data order.
+pub fn board_order_keys(data: &'static GameData, board: OrderBoard) -> Vec<&'static str> {
+    data.special_orders
+        .iter()
+        .filter(|order| order.order_type == board.order_type())
+        .map(|order| order.key)
+        .collect()
+}
+
+/// Rough chance that a random ID puts every one of `orders` on `board`.
+pub fn board_pass_rate(
+    data: &'static GameData,
+    board: OrderBoard,
+    days_played: u32,
+    state: &SpecialOrderState,
+    orders: &[String],
+) -> f64 {
+    let eligible = eligible_orders(data, board, refresh_day(days_played), state).len() as f64;
+    match orders.len() {
+        0 => 1.0,
+        _ if eligible <= 2.0 => 1.0,
+        1 => 2.0 / eligible,
+        2 => 2.0 / (eligible * (eligible - 1.0)),
+        _ => 0.0,
+    }
+}
+
+/// One `RequiredTags` entry. `!` negates it.
+fn check_tag(tag: &str, season: Season, state: &SpecialOrderState) -> bool {
+    if let Some(tag) = tag.strip_prefix('!') {
+        return match known_tag(tag, season, state) {
+            Some(holds) => !holds,
+            None => true,
+        };
+    }
+    known_tag(tag, season, state).unwrap_or(true)
+}
+
+/// The value of a tag the cracker can evaluate, or `None` if it depends on
+/// save state it does not have.
+fn known_tag(tag: &str, season: Season, state: &SpecialOrderState) -> Option<bool> {
+    if let Some(name) = tag.strip_prefix("season_") {
+        return Some(Season::from_key(name) == Some(season));
+    }
+    if let Some(key) = tag.strip_prefix("completed_") {
+        return Some(state.completed.iter().any(|done| done == key));
+    }
+    match tag {
+        "island" => Some(state.island_unlocked),
+        // Drop boxes and rules only exist while another order is active.
+        _ if tag.starts_with("dropbox_") || tag.starts_with("rule_") => Some(false),
+        _ => None,
+    }
+}
@@ -0,0 +1,88 @@
+//! The Trash Bear's requests (`TrashBear.updateItemWanted`).
+//!
+//! The Trash Bear asks for four items in a row. The first two come from
+//! `Utility.getRandomPureSeasonalItem` with `CreateRandom(uniqueIDForThisGame,
+//! 777111 + requestsDone)`: a forage item or fish of the current season, from
+//! the pools `build.rs` reads from `assets/QuestPools.json`. The
+//! last two come from the same random, but as an index into
+//! `Data/CookingRecipes`, which is optional; see `build.rs`. The request
+//! changes with the season until it is filled.
+//!
+//! The raccoon family's requests are not predicted: they come from the
+//! save's raccoon progress and the shared game random, not from a random
+//! seeded by the game ID.
+
+use crate::checker::CheckError;
+use crate::codegen::GameData;
+use crate::observation::{Platform, Season};
+use crate::prng::{create_random, SeedingMode};
+
+/// How many items the Trash Bear asks for.
+pub const TRASH_BEAR_REQUESTS: u32 = 4;
+
+/// Whether request number `done + 1` is a dish rather than a seasonal item.
+fn wants_dish(done: u32) -> bool {
+    done > 1
+}
+
+/// The qualified item the Trash Bear asks for in `season` once `done` of
+/// his requests have been filled (0 to 3).
+pub fn trash_bear_request(
+    data: &GameData,
+    platform: Platform,
+    seeding: SeedingMode,
+    id: u64,
+    season: Season,
+    done: u32,
+) -> Result<String, CheckError> {
+    let seed = 777111 + done;
+    let mut rng = create_random(platform, seeding, &[id as f64, seed as f64])?;
+    if !wants_dish(done) {
+        let items = data.pure_seasonal_items[season.index() as usize];
+        let item = items[rng.gen_range(0..items.len() as i32)? as usize];
+        return Ok(format!("(O){item}"));
+    }
+    let recipes = data.cooking_recipes;
+    if recipes.is_empty() {
+        return Err(CheckError::MissingGameData("CookingRecipes"));
+    }
+    let (_, output) = recipes[rng.gen_range(0..recipes.len() as i32)? as usize];
+    Ok(format!("(O){output}"))
+}
+
+/// What request number `done + 1` is drawn from in `season`, in table order.
+fn request_pool(data: &GameData, season: Season, done: u32) -> Vec<String> {
+    if wants_dish(done) {
+        data.cooking_recipes
+            .iter()
+            .map(|(_, output)| format!("(O){output}"))
+            .collect()
+    } else {
+        data.pure_seasonal_items[season.index() as usize]
+            .iter()
+            .map(|item| format!("(O){item}"))
+            .collect()
+    }
+}
+
+/// Every item request number `done + 1` can be, over all seasons.
+pub fn possible_requests(data: &GameData, done: u32) -> Vec<String> {
+    let mut items: Vec<String> = Vec::new();
+    for season in (0..4).map(Season::from_index) {
+        for item in request_pool(data, season, done) {
+            if !items.contains(&item) {
+                items.push(item);
+            }
+        }
+    }
+    items
+}
+
+/// Chance that a random ID asks for `item` in `season` after `done` requests.
+pub fn trash_bear_pass_rate(data: &GameData, season: Season, done: u32, item: &str) -> f64 {
+    let pool = request_pool(data, season, done);
+    if pool.is_empty() {
+        return 0.0;
+    }
+    pool.iter().filter(|i| *i == item).count() as f64 / pool.len() as f64
+}
@@ -0,0 +1,224 @@
+//! Valley weather under the 1.6 rules.
+//!
+//! A day's weather is rolled the night before (so from the previous day's
+//! date) by the `Default` location context's weather conditions, then
+//! overridden on fixed-weather days. Whether it rains comes from synced
+//! randoms keyed by name; storm-versus-rain and wind use `Game1.random`, which
+//! depends on more than the game ID, so those outcomes stay ambiguous.
+
+use serde::{Deserialize, Serialize};
+
+use crate::checker::CheckError;
+use crate::observation::{calendar_date, Platform, Season};
+use crate::prng::{create_day_save_random, create_random, deterministic_hash, SeedingMode};
+
+/// Summer days Green Rain can fall on; one per summer.
+pub const GREEN_RAIN_DAYS: [u8; 8] = [5, 6, 7, 14, 15, 16, 18, 23];
+
+/// Festival days, always sunny: (season, day of month).
+const FESTIVAL_DAYS: &[(Season, u8)] = &[
+    (Season::Spring, 13),
+    (Season::Spring, 24),
+    (Season::Summer, 11),
+    (Season::Summer, 28),
+    (Season::Fall, 16),
+    (Season::Fall, 27),
+    (Season::Winter, 8),
+    (Season::Winter, 25),
+];
+
+/// Weather as the player sees it.
+#[derive(Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Weather {
+    Sun,
+    Rain,
+    Storm,
+    Snow,
+    Wind,
+    GreenRain,
+}
+
+impl Weather {
+    pub const ALL: [Weather; 6] = [
+        Weather::Sun,
+        Weather::Rain,
+        Weather::Storm,
+        Weather::Snow,
+        Weather::Wind,
+        Weather::GreenRain,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Weather::Sun => "Sun",
+            Weather::Rain => "Rain",
+            Weather::Storm => "Storm",
+            Weather::Snow => "Snow",
+            Weather::Wind => "Wind",
+            Weather::GreenRain => "Green Rain",
+        }
+    }
+
+    pub fn key(self) -> &'static str {
+        match self {
+            Weather::Sun => "sun",
+            Weather::Rain => "rain",
+            Weather::Storm => "storm",
+            Weather::Snow => "snow",
+            Weather::Wind => "wind",
+            Weather::GreenRain => "green-rain",
+        }
+    }
+
+    pub fn from_key(s: &str) -> Option<Self> {
+        Weather::ALL.into_iter().find(|weather| weather.key() == s)
+    }
+}
+
+/// What the game ID decides about a day's weather.
+#[derive(Clone, Copy, PartialEq)]
+pub enum GeneratedWeather {
+    /// Fixed or fully determined.
+    Exactly(Weather),
+    /// It rains; a storm if `Game1.random` says so.
+    RainOrStorm,
+    /// It stays dry; windy if `Game1.random` says so.
+    SunOrWind,
+}
+
+impl GeneratedWeather {
+    pub fn allows(self, weather: Weather) -> bool {
+        match self {
+            GeneratedWeather::Exactly(w) => w == weather,
+            GeneratedWeather::RainOrStorm => matches!(weather, Weather::Rain | Weather::Storm),
+            GeneratedWeather::SunOrWind => matches!(weather, Weather::Sun | Weather::Wind),
+        }
+    }
+
+    pub fn label(self) -> String {
+        match self {
+            GeneratedWeather::Exactly(w) => w.label().to_string(),
+            GeneratedWeather::RainOrStorm => "Rain or Storm".to_string(),
+            GeneratedWeather::SunOrWind => "Sun or Wind".to_string(),
+        }
+    }
+}
+
+/// The weather on `days_played`.
+pub fn weather(
+    platform: Platform,
+    seeding: SeedingMode,
+    id: u64,
+    days_played: u32,
+) -> Result<GeneratedWeather, CheckError> {
+    let (day, season, year) = calendar_date(days_played);
+    if let Some(fixed) = fixed_weather(days_played) {
+        return Ok(GeneratedWeather::Exactly(fixed));
+    }
+    if season == Season::Summer && green_rain_day(platform, seeding, id, year)? == day {
+        return Ok(GeneratedWeather::Exactly(Weather::GreenRain));
+    }
+
+    // Rolled the night before, from the previous day's date.
+    let yesterday = days_played - 1;
+    let (yesterday_day, _, _) = calendar_date(yesterday);
+    let wet = match season {
+        Season::Summer => {
+            let chance = 0.12 + 0.003 * yesterday_day as f64;
+            synced_day_roll(platform, seeding, id, yesterday, "summer_rain_chance")? < chance
+        }
+        Season::Winter => {
+            let snow = synced_day_roll(platform, seeding, id, yesterday, "location_weather")? < 0.63;
+            return Ok(GeneratedWeather::Exactly(if snow {
+                Weather::Snow
+            } else {
+                Weather::Sun
+            }));
+        }
+        Season::Spring | Season::Fall => {
+            synced_day_roll(platform, seeding, id, yesterday, "location_weather")? < 0.183
+        }
+    };
+
+    Ok(match (wet, season) {
+        // Spring and fall storms need DaysPlayed > 27; summer rain can always storm.
+        (true, Season::Summer) => GeneratedWeather::RainOrStorm,
+        (true, _) if yesterday > 27 => GeneratedWeather::RainOrStorm,
+        (true, _) => GeneratedWeather::Exactly(Weather::Rain),
+        (false, Season::Summer) => GeneratedWeather::Exactly(Weather::Sun),
+        (false, _) => GeneratedWeather::SunOrWind,
+    })
+}
+
+/// The Green Rain day of summer in `year`: `CreateRandom(uniqueIDForThisGame, year * 777)`.
+pub fn green_rain_day(
+    platform: Platform,
+    seeding: SeedingMode,
+    id: u64,
+    year: u32,
+) -> Result<u8, CheckError> {
+    let mut rng = create_random(platform, seeding, &[id as f64, year as f64 * 777.0])?;
+    Ok(GREEN_RAIN_DAYS[rng.gen_range(0..GREEN_RAIN_DAYS.len() as i32)? as usize])
+}
+
+/// `Game1.getWeatherModificationsForDate`, minus Green Rain (which needs the ID).
+pub fn fixed_weather(days_played: u32) -> Option<Weather> {
+    let (day, season, _) = calendar_date(days_played);
+    match days_played {
+        1 | 2 | 4 => return Some(Weather::Sun),
+        3 => return Some(Weather::Rain),
+        _ => {}
+    }
+    if season == Season::Summer && day % 13 == 0 {
+        return Some(Weather::Storm);
+    }
+    if day == 1 || FESTIVAL_DAYS.contains(&(season, day)) {
+        return Some(Weather::Sun);
+    }
+    None
+}
+
+/// Rough chance that a random ID gives `observed` on `days_played`.
+pub fn weather_pass_rate(observed: Weather, days_played: u32) -> f64 {
+    if let Some(fixed) = fixed_weather(days_played) {
+        return if fixed == observed { 1.0 } else { 0.0 };
+    }
+
+    let (day, season, _) = calendar_date(days_played);
+    let (yesterday_day, _, _) = calendar_date(days_played - 1);
+    let green_rain = if season == Season::Summer && GREEN_RAIN_DAYS.contains(&day) {
+        1.0 / GREEN_RAIN_DAYS.len() as f64
+    } else {
+        0.0
+    };
+    let wet = match season {
+        Season::Summer => 0.12 + 0.003 * yesterday_day as f64,
+        Season::Winter => 0.63,
+        Season::Spring | Season::Fall => 0.183,
+    };
+
+    let rate = match (observed, season) {
+        (Weather::GreenRain, _) => return green_rain,
+        (Weather::Snow, Season::Winter) => wet,
+        (Weather::Sun, Season::Winter) => 1.0 - wet,
+        (Weather::Snow, _) | (_, Season::Winter) => 0.0,
+        (Weather::Rain | Weather::Storm, _) => wet,
+        (Weather::Sun, Season::Summer) => 1.0 - wet,
+        (Weather::Wind, Season::Summer) => 0.0,
+        (Weather::Sun | Weather::Wind, _) => 1.0 - wet,
+    };
+    rate * (1.0 - green_rain)
+}
+
+/// First `NextDouble()` of `SYNCED_RANDOM day <key>`: `CreateDaySaveRandom(hash(key))`.
+fn synced_day_roll(
+    platform: Platform,
+    seeding: SeedingMode,
+    id: u64,
+    days_played: u32,
+    key: &str,
+) -> Result<f64, CheckError> {
+    let seed = deterministic_hash(key) as f64;
+    let mut rng = create_day_save_random(platform, seeding, days_played, id, seed, 0.0, 0.0)?;
+    Ok(rng.gen_float()?)
+}
@@ -0,0 +1,80 @@
+//! Feast of the Winter Star secret-gift assignment.
+//!
+//! Each year the game seeds a random from `uniqueIDForThisGame / 2` and the
+//! year, draws the player's gift recipient from the villagers in
+//! `Data/Characters` order, then redraws until it finds a different villager
+//! to be the player's secret friend (the one who gives them a gift).
+
+use crate::checker::CheckError;
+use crate::codegen::{GameData, NpcData};
+use crate::npcs::social_npcs;
+use crate::observation::Platform;
+use crate::prng::{create_random, SeedingMode};
+
+/// The two villagers paired with the player at one Winter Star.
+#[derive(Clone, Copy)]
+pub struct SecretGift {
+    /// Who the player gives a gift to.
+    pub recipient: &'static str,
+    /// Who gives the player a gift.
+    pub giver: &'static str,
+}
+
+/// The secret-gift pairing for `year` (1-based).
+pub fn secret_gift(
+    data: &'static GameData,
+    platform: Platform,
+    seeding: SeedingMode,
+    id: u64,
+    year: u32,
+) -> Result<SecretGift, CheckError> {
+    let participants = participants(data, year);
+    // The giver is redrawn until they differ from the recipient.
+    if participants.len() < 2 {
+        return Err(CheckError::TooFewNpcs {
+            needed: 2,
+            found: participants.len(),
+        });
+    }
+    let count = participants.len() as i32;
+    let mut rng = create_random(platform, seeding, &[(id / 2) as f64, year as f64])?;
+
+    let recipient = participants[rng.gen_range(0..count)? as usize];
+    let mut giver = participants[rng.gen_range(0..count)? as usize];
+    while giver == recipient {
+        giver = participants[rng.gen_range(0..count)? as usize];
+    }
+
+    Ok(SecretGift { recipient, giver })
+}
+
+/// Villagers at the festival in `year`, in data order.
+///
+/// Without a `WinterStarParticipant` query every social Town villager takes
+/// part. The only vanilla query (Leo's, once he has moved to the valley) needs
+/// save state the cracker does not have, so those villagers are left out.
+pub fn participants(data: &'static GameData, year: u32) -> Vec<&'static str> {
+    social_npcs(data)
+        .filter(|npc| is_participant(npc, year))
+        .map(|npc| npc.name)
+        .collect()
+}
+
+fn is_participant(npc: &NpcData, year: u32) -> bool {
+    let takes_part = match npc.winter_star_participant {
+        None => npc.home_region == "Town",
+        Some(query) => query == "TRUE",
+    };
+    takes_part && unlocked_by(npc.unlock_conditions, year)
+}
+
+/// Evaluates the `YEAR <n>` unlock condition (Kent returns in year 2).
+fn unlocked_by(conditions: Option<&str>, year: u32) -> bool {
+    match conditions {
+        None => true,
+        Some(query) => match query.strip_prefix("YEAR ") {
+            Some(min) => min.trim().parse::<u32>().is_ok_and(|min| year >= min),
+            None => false,
+        },
+    }
+}